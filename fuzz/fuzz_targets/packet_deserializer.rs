@@ -0,0 +1,7 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|bytes: &[u8]| {
+    embedded_nano_mesh::fuzzing::deserialize_packet(bytes);
+});
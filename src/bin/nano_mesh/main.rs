@@ -0,0 +1,315 @@
+//! `nano-mesh` - command-line tool, which runs the node on the PC, and
+//! talks to the mesh trough the serial port or UDP multicast group.
+//! Is made for technicians, which diagnose devices of the network
+//! without writing any code.
+//!
+//! ```text
+//! nano-mesh --port /dev/ttyUSB0 --address 99 listen
+//! nano-mesh --port /dev/ttyUSB0 --address 99 ping --to 2
+//! nano-mesh --udp 239.255.77.77:47777 --address 99 --json sniff
+//! ```
+
+mod output;
+mod session;
+
+use std::net::SocketAddrV4;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use embedded_nano_mesh::{
+    ms, ExactAddressType, LifeTimeType, Node, NodeConfig, PacketDataBytes, SnifferConfig,
+    SpecialSendError,
+};
+
+use output::Output;
+use session::Session;
+
+const DEFAULT_LIFETIME: LifeTimeType = 10;
+
+/// Period of time, during which the packet shall be sent out
+/// by `send` and `broadcast` commands.
+const SEND_TIMEOUT: ms = 5000;
+
+#[derive(Parser)]
+#[command(
+    name = "nano-mesh",
+    version,
+    about = "Talks to the embedded-nano-mesh network trough the serial port or UDP multicast group"
+)]
+struct Cli {
+    /// Serial port with the radio module, like /dev/ttyUSB0 or COM3.
+    #[arg(long, required_unless_present = "udp", conflicts_with = "udp")]
+    port: Option<String>,
+
+    /// Baud rate of the serial port.
+    #[arg(long, default_value_t = 9600)]
+    baud: u32,
+
+    /// UDP multicast group, which acts as the ether, like 239.255.77.77:47777.
+    #[arg(long)]
+    udp: Option<SocketAddrV4>,
+
+    /// Address of this device in the network.
+    #[arg(long, default_value_t = ExactAddressType::new(1).unwrap())]
+    address: ExactAddressType,
+
+    /// Listen period of this device in milliseconds.
+    #[arg(long, default_value_t = 150)]
+    listen_period: ms,
+
+    /// Prints each event as the single line of JSON.
+    #[arg(long)]
+    json: bool,
+
+    /// Writes every frame, sent or received by this device, into the pcapng file.
+    #[arg(long, value_name = "FILE")]
+    capture: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prints packets, received by this device.
+    Listen {
+        /// Stops listening after this period of milliseconds.
+        #[arg(long)]
+        duration: Option<ms>,
+    },
+
+    /// Sends the message to the device with exact address.
+    Send {
+        /// Address of the receiving device.
+        #[arg(long)]
+        to: ExactAddressType,
+
+        /// Count of devices, the packet is able to pass.
+        #[arg(long, default_value_t = DEFAULT_LIFETIME)]
+        lifetime: LifeTimeType,
+
+        /// Lets other devices to forward echoes of the message.
+        #[arg(long)]
+        allow_duplicates: bool,
+
+        #[arg(value_parser = parse_message)]
+        message: PacketDataBytes,
+    },
+
+    /// Sends the message to all devices of the network.
+    Broadcast {
+        /// Count of devices, the packet is able to pass.
+        #[arg(long, default_value_t = DEFAULT_LIFETIME)]
+        lifetime: LifeTimeType,
+
+        #[arg(value_parser = parse_message)]
+        message: PacketDataBytes,
+    },
+
+    /// Sends the ping to the device and waits for the pong.
+    Ping {
+        /// Address of the pinged device.
+        #[arg(long)]
+        to: ExactAddressType,
+
+        /// Count of devices, the packet is able to pass.
+        #[arg(long, default_value_t = DEFAULT_LIFETIME)]
+        lifetime: LifeTimeType,
+
+        /// Period of milliseconds to wait for the pong.
+        #[arg(long, default_value_t = 3000)]
+        timeout: ms,
+
+        #[arg(value_parser = parse_message, default_value = "ping")]
+        message: PacketDataBytes,
+    },
+
+    /// Sends the message with transaction, so the device receives it only once.
+    Transact {
+        /// Address of the receiving device.
+        #[arg(long)]
+        to: ExactAddressType,
+
+        /// Count of devices, the packet is able to pass.
+        #[arg(long, default_value_t = DEFAULT_LIFETIME)]
+        lifetime: LifeTimeType,
+
+        /// Period of milliseconds to wait for the transaction to finish.
+        #[arg(long, default_value_t = 5000)]
+        timeout: ms,
+
+        #[arg(value_parser = parse_message)]
+        message: PacketDataBytes,
+    },
+
+    /// Prints every packet heard from the ether, including transit and duplicated ones.
+    Sniff {
+        /// Stops sniffing after this period of milliseconds.
+        #[arg(long)]
+        duration: Option<ms>,
+
+        /// Forwards packets, addressed to other devices, as the usual device does.
+        #[arg(long)]
+        forward_transit: bool,
+    },
+
+    /// Runs the node for a while and prints it's statistics.
+    Stats {
+        /// Period of milliseconds, during which the ether is observed.
+        #[arg(long, default_value_t = 5000)]
+        duration: ms,
+    },
+}
+
+fn parse_message(message: &str) -> Result<PacketDataBytes, String> {
+    PacketDataBytes::from_slice(message.as_bytes()).map_err(|_| {
+        format!(
+            "message is {} bytes long, while packet keeps up to {} bytes",
+            message.len(),
+            PacketDataBytes::new().capacity()
+        )
+    })
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli) {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("nano-mesh: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<ExitCode, String> {
+    let output = Output::new(cli.json);
+    let sniffer = match cli.command {
+        Command::Sniff {
+            forward_transit, ..
+        } => Some(SnifferConfig { forward_transit }),
+        _ => None,
+    };
+    let mut config = NodeConfig::new(cli.address, cli.listen_period);
+    config.sniffer = sniffer;
+    let node = Node::new(config);
+    let mut session = Session::open(
+        node,
+        cli.port.as_deref(),
+        cli.baud,
+        cli.udp,
+        cli.capture.as_deref(),
+    )?;
+
+    let code = match cli.command {
+        Command::Listen { duration } => {
+            session.run(duration, |session| {
+                while let Some(packet) = session.node.receive() {
+                    output.received(session.now(), &packet);
+                }
+            })?;
+            ExitCode::SUCCESS
+        }
+        Command::Send {
+            to,
+            lifetime,
+            allow_duplicates,
+            message,
+        } => {
+            session
+                .node
+                .send_to_exact(message, to, lifetime, !allow_duplicates)
+                .map_err(|error| format!("can not send: {:?}", error))?;
+            finish_send(&mut session, &output)?
+        }
+        Command::Broadcast { lifetime, message } => {
+            session
+                .node
+                .broadcast(message, lifetime)
+                .map_err(|error| format!("can not broadcast: {:?}", error))?;
+            finish_send(&mut session, &output)?
+        }
+        Command::Ping {
+            to,
+            lifetime,
+            timeout,
+            message,
+        } => {
+            let started_at = session.now();
+            let result = session.ping(message, to, lifetime, timeout);
+            let elapsed = session.now().wrapping_sub(started_at);
+            finish_special_send(&mut session, &output, "ping", to, result, elapsed)?
+        }
+        Command::Transact {
+            to,
+            lifetime,
+            timeout,
+            message,
+        } => {
+            let started_at = session.now();
+            let result = session.transact(message, to, lifetime, timeout);
+            let elapsed = session.now().wrapping_sub(started_at);
+            finish_special_send(&mut session, &output, "transaction", to, result, elapsed)?
+        }
+        Command::Sniff { duration, .. } => {
+            session.run(duration, |session| {
+                while let Some(sniffed) = session.node.sniff() {
+                    output.sniffed(&sniffed);
+                }
+                while session.node.receive().is_some() {}
+            })?;
+            ExitCode::SUCCESS
+        }
+        Command::Stats { duration } => {
+            session.run(
+                Some(duration),
+                |session| {
+                    while session.node.receive().is_some() {}
+                },
+            )?;
+            output.stats(&session.node.stats(), session.counters());
+            ExitCode::SUCCESS
+        }
+    };
+    session.close()?;
+    Ok(code)
+}
+
+/// Updates the node till the queued packet is sent out.
+fn finish_send(session: &mut Session, output: &Output) -> Result<ExitCode, String> {
+    let started_at = session.now();
+    let frames_sent = session.counters().frames_sent;
+    let is_sent = session.run_until(SEND_TIMEOUT, |session| {
+        session.counters().frames_sent > frames_sent
+    })?;
+    let elapsed = session.now().wrapping_sub(started_at);
+    output.sent(is_sent, elapsed);
+    Ok(if is_sent {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn finish_special_send(
+    session: &mut Session,
+    output: &Output,
+    kind: &str,
+    to: ExactAddressType,
+    result: Result<(), SpecialSendError>,
+    elapsed: ms,
+) -> Result<ExitCode, String> {
+    session.flush_capture()?;
+    match result {
+        Ok(()) => {
+            output.answered(kind, to, elapsed);
+            Ok(ExitCode::SUCCESS)
+        }
+        Err(SpecialSendError::Timeout) => {
+            output.timed_out(kind, to, elapsed);
+            Ok(ExitCode::FAILURE)
+        }
+        Err(error) => Err(format!("can not send the {}: {:?}", kind, error)),
+    }
+}
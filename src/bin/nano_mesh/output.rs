@@ -0,0 +1,146 @@
+use embedded_nano_mesh::{
+    ms, CapturedFrame, Direction, ExactAddressType, NodeStats, Packet, SniffedPacket,
+};
+use serde_json::{json, Map, Value};
+
+use crate::session::Counters;
+
+/// Prints events either as human readable text,
+/// or as JSON lines for scripts.
+pub struct Output {
+    json: bool,
+}
+
+impl Output {
+    pub fn new(json: bool) -> Output {
+        Output { json }
+    }
+
+    pub fn received(&self, time: ms, packet: &Packet) {
+        if self.json {
+            let mut event = packet_fields(packet);
+            event.insert("event".into(), json!("received"));
+            event.insert("time".into(), json!(time));
+            print_json(event);
+        } else {
+            println!("{}", frame(time, packet));
+        }
+    }
+
+    pub fn sniffed(&self, sniffed: &SniffedPacket) {
+        if self.json {
+            let mut event = packet_fields(&sniffed.packet);
+            event.insert("event".into(), json!("sniffed"));
+            event.insert("time".into(), json!(sniffed.heard_at));
+            event.insert("duplicate".into(), json!(sniffed.is_duplicate));
+            print_json(event);
+        } else if sniffed.is_duplicate {
+            println!("{}  duplicate", frame(sniffed.heard_at, &sniffed.packet));
+        } else {
+            println!("{}", frame(sniffed.heard_at, &sniffed.packet));
+        }
+    }
+
+    pub fn sent(&self, is_sent: bool, elapsed: ms) {
+        if self.json {
+            println!(
+                "{}",
+                json!({ "event": "sent", "sent": is_sent, "elapsed": elapsed })
+            );
+        } else if is_sent {
+            println!("sent in {} ms", elapsed);
+        } else {
+            println!("not sent in {} ms", elapsed);
+        }
+    }
+
+    pub fn answered(&self, kind: &str, to: ExactAddressType, elapsed: ms) {
+        if self.json {
+            println!(
+                "{}",
+                json!({ "event": kind, "to": to.get(), "answered": true, "elapsed": elapsed })
+            );
+        } else {
+            println!("{} to {} answered in {} ms", kind, to, elapsed);
+        }
+    }
+
+    pub fn timed_out(&self, kind: &str, to: ExactAddressType, elapsed: ms) {
+        if self.json {
+            println!(
+                "{}",
+                json!({ "event": kind, "to": to.get(), "answered": false, "elapsed": elapsed })
+            );
+        } else {
+            println!("{} to {} timed out after {} ms", kind, to, elapsed);
+        }
+    }
+
+    pub fn stats(&self, stats: &NodeStats, counters: Counters) {
+        if self.json {
+            println!(
+                "{}",
+                json!({
+                    "event": "stats",
+                    "effective_listen_period": stats.effective_listen_period,
+                    "channel_load_bytes": stats.channel_load_bytes,
+                    "channel_load_duplicates": stats.channel_load_duplicates,
+                    "remaining_airtime": stats.remaining_airtime,
+                    "address_conflicts": stats.address_conflicts,
+                    "frames_sent": counters.frames_sent,
+                    "frames_received": counters.frames_received,
+                })
+            );
+            return;
+        }
+        println!(
+            "effective listen period  {} ms",
+            stats.effective_listen_period
+        );
+        println!("channel load bytes       {}", stats.channel_load_bytes);
+        println!("channel load duplicates  {}", stats.channel_load_duplicates);
+        match stats.remaining_airtime {
+            Some(airtime) => println!("remaining airtime        {} ms", airtime),
+            None => println!("remaining airtime        unlimited"),
+        }
+        println!("address conflicts        {}", stats.address_conflicts);
+        println!("frames sent              {}", counters.frames_sent);
+        println!("frames received          {}", counters.frames_received);
+    }
+}
+
+/// Packet is printed the same way, as the captured frame.
+fn frame(time: ms, packet: &Packet) -> CapturedFrame {
+    CapturedFrame {
+        timestamp: time,
+        direction: Direction::Received,
+        interface: 0,
+        packet: packet.clone(),
+    }
+}
+
+fn packet_fields(packet: &Packet) -> Map<String, Value> {
+    let data = &packet.data[..packet.get_data_length().min(packet.data.len())];
+    let data_hex: String = data.iter().map(|byte| format!("{:02x}", byte)).collect();
+
+    let mut fields = Map::new();
+    fields.insert("from".into(), json!(packet.source_device_identifier));
+    // Broadcast packets are addressed to 0.
+    fields.insert(
+        "to".into(),
+        json!(packet.get_destination_device_identifier()),
+    );
+    fields.insert("id".into(), json!(packet.get_id()));
+    fields.insert("lifetime".into(), json!(packet.get_lifetime()));
+    fields.insert(
+        "state".into(),
+        json!(format!("{:?}", packet.get_spec_state())),
+    );
+    fields.insert("data".into(), json!(String::from_utf8_lossy(data)));
+    fields.insert("data_hex".into(), json!(data_hex));
+    fields
+}
+
+fn print_json(event: Map<String, Value>) {
+    println!("{}", Value::Object(event));
+}
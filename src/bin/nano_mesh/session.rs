@@ -0,0 +1,235 @@
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::net::SocketAddrV4;
+use std::path::Path;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use embedded_nano_mesh::{
+    ms, CaptureInterface, Direction, ExactAddressType, LifeTimeType, Node, PacketDataBytes,
+    PcapWriter, SerialInterface, SpecialSendError, UdpInterface,
+};
+
+/// Pause between updates of the node, so the CPU is not kept busy.
+/// Is short enough to read bytes of the fastest serial port in time.
+const UPDATE_PAUSE: Duration = Duration::from_micros(200);
+
+/// Time in milliseconds since the start of the program.
+fn now() -> ms {
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_millis() as ms
+}
+
+/// Counts of frames, which were sent and received trough the interface.
+#[derive(Clone, Copy)]
+pub struct Counters {
+    pub frames_sent: u32,
+    pub frames_received: u32,
+}
+
+/// The node, along with the interface it runs over.
+pub struct Session {
+    pub node: Node,
+    interface: CaptureInterface<Port, fn() -> ms>,
+    capture: Option<PcapWriter<BufWriter<File>>>,
+    counters: Counters,
+}
+
+impl Session {
+    /// Opens serial `port` or joins `udp` multicast group.
+    /// Frames are written into `capture_path` file, in case if it is set.
+    pub fn open(
+        node: Node,
+        port: Option<&str>,
+        baud_rate: u32,
+        udp: Option<SocketAddrV4>,
+        capture_path: Option<&Path>,
+    ) -> Result<Session, String> {
+        let (port, interface_name) = match (port, udp) {
+            (Some(path), _) => (
+                Port::Serial(
+                    SerialInterface::open(path, baud_rate)
+                        .map_err(|error| format!("can not open {}: {}", path, error))?,
+                ),
+                path.to_string(),
+            ),
+            (None, Some(group)) => (
+                Port::Udp(
+                    UdpInterface::new(group)
+                        .map_err(|error| format!("can not join {}: {}", group, error))?,
+                ),
+                group.to_string(),
+            ),
+            (None, None) => return Err("either --port or --udp shall be set".to_string()),
+        };
+
+        let capture = match capture_path {
+            Some(path) => {
+                let file = File::create(path)
+                    .map_err(|error| format!("can not create {}: {}", path.display(), error))?;
+                Some(
+                    PcapWriter::new(BufWriter::new(file), &[interface_name.as_str()])
+                        .map_err(|error| format!("can not write capture: {}", error))?,
+                )
+            }
+            None => None,
+        };
+
+        Ok(Session {
+            node,
+            interface: CaptureInterface::new(port, 0, now as fn() -> ms),
+            capture,
+            counters: Counters {
+                frames_sent: 0,
+                frames_received: 0,
+            },
+        })
+    }
+
+    pub fn now(&self) -> ms {
+        now()
+    }
+
+    pub fn counters(&self) -> Counters {
+        self.counters
+    }
+
+    /// Updates the node, and calls `each_update` after each update,
+    /// till the `duration` ends. Runs forever, in case if `duration` is not set.
+    pub fn run<F>(&mut self, duration: Option<ms>, mut each_update: F) -> Result<(), String>
+    where
+        F: FnMut(&mut Session),
+    {
+        let started_at = now();
+        loop {
+            self.update()?;
+            each_update(self);
+            if let Some(duration) = duration {
+                if now().wrapping_sub(started_at) >= duration {
+                    return Ok(());
+                }
+            }
+            thread::sleep(UPDATE_PAUSE);
+        }
+    }
+
+    /// Updates the node till the `condition` is met, or the `timeout` ends.
+    /// Returns true, in case if the `condition` is met.
+    pub fn run_until<F>(&mut self, timeout: ms, mut condition: F) -> Result<bool, String>
+    where
+        F: FnMut(&Session) -> bool,
+    {
+        let started_at = now();
+        while now().wrapping_sub(started_at) < timeout {
+            self.update()?;
+            if condition(self) {
+                return Ok(true);
+            }
+            thread::sleep(UPDATE_PAUSE);
+        }
+        Ok(false)
+    }
+
+    pub fn ping(
+        &mut self,
+        data: PacketDataBytes,
+        to: ExactAddressType,
+        lifetime: LifeTimeType,
+        timeout: ms,
+    ) -> Result<(), SpecialSendError> {
+        self.node
+            .send_ping_pong(data, to, lifetime, timeout, now, &mut self.interface)
+    }
+
+    pub fn transact(
+        &mut self,
+        data: PacketDataBytes,
+        to: ExactAddressType,
+        lifetime: LifeTimeType,
+        timeout: ms,
+    ) -> Result<(), SpecialSendError> {
+        self.node
+            .send_with_transaction(data, to, lifetime, timeout, now, &mut self.interface)
+    }
+
+    /// Counts captured frames, and writes them into the capture file.
+    pub fn flush_capture(&mut self) -> Result<(), String> {
+        let mut is_written = false;
+        while let Some(frame) = self.interface.take_frame() {
+            match frame.direction {
+                Direction::Sent => self.counters.frames_sent += 1,
+                Direction::Received => self.counters.frames_received += 1,
+            }
+            if let Some(capture) = self.capture.as_mut() {
+                capture
+                    .write_frame(&frame)
+                    .map_err(|error| format!("can not write capture: {}", error))?;
+                is_written = true;
+            }
+        }
+        // Is flushed right away, so the capture survives the interruption of the program.
+        if let (true, Some(capture)) = (is_written, self.capture.as_mut()) {
+            capture
+                .flush()
+                .map_err(|error| format!("can not write capture: {}", error))?;
+        }
+        Ok(())
+    }
+
+    /// Waits till all the bytes are sent out trough the port.
+    pub fn close(mut self) -> Result<(), String> {
+        self.flush_capture()?;
+        embedded_io::Write::flush(&mut self.interface)
+            .map_err(|error| format!("can not flush the port: {}", error))
+    }
+
+    fn update(&mut self) -> Result<(), String> {
+        let _ = self.node.update(&mut self.interface, now());
+        self.flush_capture()
+    }
+}
+
+/// Serial port or UDP multicast group, which is chosen by the user.
+enum Port {
+    Serial(SerialInterface),
+    Udp(UdpInterface),
+}
+
+impl embedded_io::ErrorType for Port {
+    type Error = io::Error;
+}
+
+impl embedded_io::ReadReady for Port {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        match self {
+            Port::Serial(serial) => serial.read_ready(),
+            Port::Udp(udp) => udp.read_ready(),
+        }
+    }
+}
+
+impl embedded_io::Read for Port {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        match self {
+            Port::Serial(serial) => serial.read(buf),
+            Port::Udp(udp) => udp.read(buf),
+        }
+    }
+}
+
+impl embedded_io::Write for Port {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        match self {
+            Port::Serial(serial) => serial.write(buf),
+            Port::Udp(udp) => udp.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        match self {
+            Port::Serial(serial) => serial.flush(),
+            Port::Udp(udp) => udp.flush(),
+        }
+    }
+}
@@ -1,4 +1,7 @@
 #![no_std]
+#[cfg(feature = "std")]
+extern crate std;
+
 mod mesh_lib;
 
 pub use mesh_lib::*;
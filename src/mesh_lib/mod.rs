@@ -1,6 +1,56 @@
 mod node;
+#[cfg(feature = "sim")]
+mod sim;
 
+#[cfg(feature = "fuzzing")]
+pub use node::fuzzing;
+#[cfg(feature = "fugit")]
+pub use node::FugitClock;
+#[cfg(feature = "serial")]
+pub use node::SerialInterface;
+#[cfg(feature = "std")]
 pub use node::{
-    ms, ExactAddressType, GeneralAddressType, IdType, LifeTimeType, Node, NodeConfig, NodeString,
-    NodeUpdateError, Packet, PacketDataBytes, PacketState, SendError, SpecialSendError,
+    CaptureInterface, CapturedFrame, Direction, PcapReader, PcapWriter, StdClock, TcpInterface,
+    UdpInterface,
+};
+#[cfg(feature = "sim")]
+pub use sim::{LinkConfig, Scenario, ScenarioError, Simulator};
+
+#[cfg(feature = "reliable")]
+pub use node::DeliveryStatus;
+#[cfg(feature = "link-ack")]
+pub use node::LinkAckConfig;
+#[cfg(feature = "mailbox")]
+pub use node::MailboxError;
+#[cfg(feature = "time-sync")]
+pub use node::TimeSyncConfig;
+#[cfg(feature = "addressing")]
+pub use node::{AddressAcquisitionConfig, AddressAcquisitionMode, AddressCoordinatorConfig};
+#[cfg(feature = "diagnostics")]
+pub use node::{DiagnosticsReport, PROTOCOL_VERSION};
+#[cfg(feature = "parameters")]
+pub use node::{
+    ParameterAccess, ParameterCallStatus, ParameterError, ParameterId, ParameterManagers,
+    ParameterValue, RemoteParametersConfig, CARRIER_SENSE_MAX_BACKOFF_PARAMETER,
+    CARRIER_SENSE_QUIET_WINDOW_PARAMETER, DEFAULT_LIFETIME_PARAMETER,
+    LISTEN_PERIOD_JITTER_PARAMETER, LISTEN_PERIOD_PARAMETER, TRANSIT_QUEUE_POLICY_PARAMETER,
+};
+#[cfg(feature = "pubsub")]
+pub use node::{PubSubError, Publication, TopicId, TopicPayload};
+#[cfg(feature = "rpc")]
+pub use node::{RpcArguments, RpcCallStatus, RpcError, RpcHandler, RpcMethodId, RpcPayload};
+#[cfg(feature = "sniffer")]
+pub use node::{SniffedPacket, SnifferConfig};
+
+#[cfg(feature = "adaptive-listen-period")]
+pub use node::AdaptiveListenPeriodConfig;
+#[cfg(feature = "airtime-limit")]
+pub use node::AirtimeLimitConfig;
+#[cfg(feature = "carrier-sense")]
+pub use node::CarrierSenseConfig;
+
+pub use node::{
+    ms, Clock, ExactAddressType, GeneralAddressType, IdType, Interface, LifeTimeType, Node,
+    NodeConfig, NodeStats, NodeString, NodeUpdateError, Packet, PacketDataBytes, PacketState,
+    QueuePolicy, RandomNumberGenerator, SendError, SpecialSendError, XorShiftRng,
 };
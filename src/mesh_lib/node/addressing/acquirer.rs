@@ -0,0 +1,313 @@
+use super::super::{RandomNumberGenerator, XorShiftRng};
+use super::{
+    make_conflict_packet, ms, AddressAcquisitionConfig, AddressAcquisitionMode, AddressClaim,
+    AddressLease, ExactAddressType, GeneralAddressType, Packet, PacketState,
+};
+
+enum AcquisitionState {
+    Probing {
+        probes_sent: u8,
+        last_probe_at: Option<ms>,
+    },
+    Claimed,
+    Requesting {
+        last_request_at: Option<ms>,
+    },
+    Leased {
+        leased_at: ms,
+        lease_period: ms,
+        last_request_at: Option<ms>,
+    },
+}
+
+/// Acquires the address for the device, either by probing
+/// tentative addresses, or by requesting it from the coordinator.
+pub struct AddressAcquirer {
+    config: AddressAcquisitionConfig,
+    address: ExactAddressType,
+    attempt: u32,
+    state: AcquisitionState,
+}
+
+/// Tentative addresses of devices with different hardware ids
+/// shall differ even after few conflicts.
+fn candidate_address(hardware_id: u32, attempt: u32) -> ExactAddressType {
+    let mut rng = XorShiftRng::new(hardware_id ^ attempt.wrapping_mul(0x9E37_79B9));
+    ExactAddressType::new((rng.next_u32() % 255 + 1) as u8).unwrap_or(ExactAddressType::MIN)
+}
+
+fn time_till(last_time: Option<ms>, period: ms, current_time: ms) -> ms {
+    match last_time {
+        Some(last_time) => period.saturating_sub(current_time.wrapping_sub(last_time)),
+        None => 0,
+    }
+}
+
+impl AddressAcquirer {
+    pub fn new(config: AddressAcquisitionConfig) -> AddressAcquirer {
+        let state = match config.mode {
+            AddressAcquisitionMode::SelfAssigned => AcquisitionState::Probing {
+                probes_sent: 0,
+                last_probe_at: None,
+            },
+            AddressAcquisitionMode::Leased { .. } => AcquisitionState::Requesting {
+                last_request_at: None,
+            },
+        };
+        AddressAcquirer {
+            address: candidate_address(config.hardware_id, 0),
+            attempt: 0,
+            config,
+            state,
+        }
+    }
+
+    /// Address, which the device uses now. Either tentative or assigned one.
+    pub fn current_address(&self) -> ExactAddressType {
+        self.address
+    }
+
+    /// Address, which is claimed or leased.
+    pub fn assigned_address(&self) -> Option<ExactAddressType> {
+        match self.state {
+            AcquisitionState::Claimed | AcquisitionState::Leased { .. } => Some(self.address),
+            _ => None,
+        }
+    }
+
+    fn claim(&self) -> AddressClaim {
+        AddressClaim {
+            address: self.address,
+            hardware_id: self.config.hardware_id,
+            lifetime: self.config.lifetime,
+        }
+    }
+
+    fn make_lease_request(&self) -> Option<Packet> {
+        match self.config.mode {
+            AddressAcquisitionMode::Leased { coordinator } => Some(self.claim().into_packet(
+                self.address,
+                coordinator.into(),
+                PacketState::AddressLeaseRequest,
+            )),
+            AddressAcquisitionMode::SelfAssigned => None,
+        }
+    }
+
+    fn try_next_address(&mut self) {
+        self.attempt = self.attempt.wrapping_add(1);
+        self.address = candidate_address(self.config.hardware_id, self.attempt);
+        self.state = AcquisitionState::Probing {
+            probes_sent: 0,
+            last_probe_at: None,
+        };
+    }
+
+    /// Returns the packet, which shall be sent
+    /// in order to move the acquisition further.
+    pub fn update(&mut self, current_time: ms) -> Option<Packet> {
+        let answer_timeout = self.config.answer_timeout;
+        match self.state {
+            AcquisitionState::Probing {
+                ref mut probes_sent,
+                ref mut last_probe_at,
+            } => {
+                if time_till(*last_probe_at, answer_timeout, current_time) > 0 {
+                    return None;
+                }
+                if *probes_sent >= self.config.probes_count {
+                    self.state = AcquisitionState::Claimed;
+                    return None;
+                }
+                *probes_sent += 1;
+                last_probe_at.replace(current_time);
+                Some(self.claim().into_packet(
+                    self.address,
+                    GeneralAddressType::Broadcast,
+                    PacketState::AddressProbe,
+                ))
+            }
+            AcquisitionState::Claimed => None,
+            AcquisitionState::Requesting {
+                ref mut last_request_at,
+            } => {
+                if time_till(*last_request_at, answer_timeout, current_time) > 0 {
+                    return None;
+                }
+                last_request_at.replace(current_time);
+                self.make_lease_request()
+            }
+            AcquisitionState::Leased {
+                leased_at,
+                lease_period,
+                ref mut last_request_at,
+            } => {
+                let elapsed = current_time.wrapping_sub(leased_at);
+                if elapsed >= lease_period {
+                    self.state = AcquisitionState::Requesting {
+                        last_request_at: None,
+                    };
+                    return None;
+                }
+                // Lease is renewed after the half of the period.
+                if elapsed < lease_period / 2
+                    || time_till(*last_request_at, answer_timeout, current_time) > 0
+                {
+                    return None;
+                }
+                last_request_at.replace(current_time);
+                self.make_lease_request()
+            }
+        }
+    }
+
+    /// Handles packets of address acquisition, sent by other devices.
+    /// Returns the answer, which shall be sent back.
+    pub fn handle(&mut self, packet: &Packet, current_time: ms) -> Option<Packet> {
+        match packet.get_spec_state() {
+            PacketState::AddressProbe => {
+                let claim = AddressClaim::from_packet(packet)?;
+                if claim.hardware_id == self.config.hardware_id || claim.address != self.address {
+                    return None;
+                }
+                match self.state {
+                    AcquisitionState::Claimed | AcquisitionState::Leased { .. } => {
+                        make_conflict_packet(packet, self.address)
+                    }
+                    // Simultaneous probes of the same address - the lower hardware id wins.
+                    AcquisitionState::Probing { .. }
+                        if claim.hardware_id < self.config.hardware_id =>
+                    {
+                        self.try_next_address();
+                        None
+                    }
+                    _ => None,
+                }
+            }
+            PacketState::AddressConflict => {
+                let claim = AddressClaim::from_packet(packet)?;
+                if let AcquisitionState::Probing { .. } = self.state {
+                    if claim.hardware_id == self.config.hardware_id && claim.address == self.address
+                    {
+                        self.try_next_address();
+                    }
+                }
+                None
+            }
+            PacketState::AddressLease => {
+                let lease = AddressLease::from_packet(packet)?;
+                if let AddressAcquisitionMode::Leased { .. } = self.config.mode {
+                    if lease.claim.hardware_id == self.config.hardware_id {
+                        self.address = lease.claim.address;
+                        self.state = AcquisitionState::Leased {
+                            leased_at: current_time,
+                            lease_period: lease.lease_period,
+                            last_request_at: None,
+                        };
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Period of time since `current_time`, after which
+    /// the acquisition needs to be moved further.
+    pub fn time_till_next_action(&self, current_time: ms) -> Option<ms> {
+        let answer_timeout = self.config.answer_timeout;
+        match self.state {
+            AcquisitionState::Probing { last_probe_at, .. } => {
+                Some(time_till(last_probe_at, answer_timeout, current_time))
+            }
+            AcquisitionState::Claimed => None,
+            AcquisitionState::Requesting { last_request_at } => {
+                Some(time_till(last_request_at, answer_timeout, current_time))
+            }
+            AcquisitionState::Leased {
+                leased_at,
+                lease_period,
+                last_request_at,
+            } => {
+                let till_renewal = time_till(Some(leased_at), lease_period / 2, current_time);
+                Some(till_renewal.max(time_till(last_request_at, answer_timeout, current_time)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWER_TIMEOUT: ms = 100;
+
+    fn make_acquirer(hardware_id: u32, mode: AddressAcquisitionMode) -> AddressAcquirer {
+        AddressAcquirer::new(AddressAcquisitionConfig {
+            hardware_id,
+            mode,
+            answer_timeout: ANSWER_TIMEOUT,
+            probes_count: 2,
+            lifetime: 3,
+        })
+    }
+
+    #[test]
+    fn address_is_claimed_after_unanswered_probes() {
+        let mut acquirer = make_acquirer(1, AddressAcquisitionMode::SelfAssigned);
+
+        assert!(acquirer.update(0).is_some());
+        assert!(acquirer.update(ANSWER_TIMEOUT - 1).is_none());
+        assert!(acquirer.update(ANSWER_TIMEOUT).is_some());
+        assert!(acquirer.assigned_address().is_none());
+
+        assert!(acquirer.update(2 * ANSWER_TIMEOUT).is_none());
+        assert_eq!(
+            acquirer.assigned_address(),
+            Some(acquirer.current_address())
+        );
+    }
+
+    #[test]
+    fn conflict_makes_to_probe_other_address() {
+        let mut owner = make_acquirer(1, AddressAcquisitionMode::SelfAssigned);
+        for time in 0..3 {
+            let _ = owner.update(time * ANSWER_TIMEOUT);
+        }
+
+        // Device, which happened to pick the same address.
+        let mut prober = make_acquirer(2, AddressAcquisitionMode::SelfAssigned);
+        prober.address = owner.current_address();
+
+        let probe = prober.update(0).expect("Probe shall be sent");
+        let conflict = owner
+            .handle(&probe, 0)
+            .expect("Owner shall report the conflict");
+        assert!(conflict.get_spec_state() == PacketState::AddressConflict);
+
+        assert!(prober.handle(&conflict, 0).is_none());
+        assert!(prober.current_address() != owner.current_address());
+        assert!(prober.assigned_address().is_none());
+    }
+
+    #[test]
+    fn lower_hardware_id_wins_simultaneous_probes() {
+        let mut winner = make_acquirer(1, AddressAcquisitionMode::SelfAssigned);
+        let mut loser = make_acquirer(2, AddressAcquisitionMode::SelfAssigned);
+        loser.address = winner.current_address();
+
+        let winner_probe = winner.update(0).expect("Probe shall be sent");
+        let loser_probe = loser.update(0).expect("Probe shall be sent");
+
+        assert!(winner.handle(&loser_probe, 0).is_none());
+        assert!(loser.handle(&winner_probe, 0).is_none());
+
+        assert!(loser.current_address() != winner.current_address());
+    }
+
+    #[test]
+    fn candidate_addresses_differ() {
+        assert!(candidate_address(1, 0) != candidate_address(1, 1));
+        assert!(candidate_address(1, 1) != candidate_address(2, 0));
+    }
+}
@@ -0,0 +1,176 @@
+use heapless::Vec;
+
+use super::super::constants::ADDRESS_LEASES_COUNT;
+use super::{ms, AddressClaim, AddressCoordinatorConfig, AddressLease, ExactAddressType, Packet};
+
+struct LeaseEntry {
+    hardware_id: u32,
+    address: ExactAddressType,
+    leased_at: ms,
+}
+
+/// Leases addresses from the configured range
+/// to devices, which request them.
+pub struct AddressCoordinator {
+    config: AddressCoordinatorConfig,
+    leases: Vec<LeaseEntry, ADDRESS_LEASES_COUNT>,
+}
+
+impl AddressCoordinator {
+    pub fn new(config: AddressCoordinatorConfig) -> AddressCoordinator {
+        AddressCoordinator {
+            config,
+            leases: Vec::new(),
+        }
+    }
+
+    fn is_address_free(&self, address: ExactAddressType, own_address: ExactAddressType) -> bool {
+        address >= self.config.first_address
+            && address <= self.config.last_address
+            && address != own_address
+            && !self.leases.iter().any(|lease| lease.address == address)
+    }
+
+    fn find_free_address(
+        &self,
+        requested_address: ExactAddressType,
+        own_address: ExactAddressType,
+    ) -> Option<ExactAddressType> {
+        // The device keeps the address, it already uses, if possible.
+        if self.is_address_free(requested_address, own_address) {
+            return Some(requested_address);
+        }
+        (self.config.first_address.get()..=self.config.last_address.get())
+            .filter_map(ExactAddressType::new)
+            .find(|address| self.is_address_free(*address, own_address))
+    }
+
+    /// Handles the lease request, and returns the lease
+    /// to be broadcasted. Lease of the device, which requests it again - is renewed.
+    /// Returns `None` in case if there are no free addresses left.
+    pub fn handle_request(
+        &mut self,
+        request: &Packet,
+        own_address: ExactAddressType,
+        current_time: ms,
+    ) -> Option<Packet> {
+        let claim = AddressClaim::from_packet(request)?;
+
+        let lease_period = self.config.lease_period;
+        self.leases
+            .retain(|lease| current_time.wrapping_sub(lease.leased_at) < lease_period);
+
+        let address = match self
+            .leases
+            .iter_mut()
+            .find(|lease| lease.hardware_id == claim.hardware_id)
+        {
+            Some(lease) => {
+                lease.leased_at = current_time;
+                lease.address
+            }
+            None => {
+                let address = self.find_free_address(claim.address, own_address)?;
+                self.leases
+                    .push(LeaseEntry {
+                        hardware_id: claim.hardware_id,
+                        address,
+                        leased_at: current_time,
+                    })
+                    .ok()?;
+                address
+            }
+        };
+
+        Some(
+            AddressLease {
+                claim: AddressClaim { address, ..claim },
+                lease_period,
+            }
+            .into_packet(own_address),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{AddressAcquirer, AddressAcquisitionConfig, AddressAcquisitionMode};
+    use super::*;
+
+    const LEASE_PERIOD: ms = 1000;
+
+    fn make_coordinator() -> AddressCoordinator {
+        AddressCoordinator::new(AddressCoordinatorConfig {
+            first_address: ExactAddressType::new(10).unwrap(),
+            last_address: ExactAddressType::new(11).unwrap(),
+            lease_period: LEASE_PERIOD,
+        })
+    }
+
+    fn make_acquirer(hardware_id: u32) -> AddressAcquirer {
+        AddressAcquirer::new(AddressAcquisitionConfig {
+            hardware_id,
+            mode: AddressAcquisitionMode::Leased {
+                coordinator: ExactAddressType::new(1).unwrap(),
+            },
+            answer_timeout: 100,
+            probes_count: 0,
+            lifetime: 3,
+        })
+    }
+
+    fn lease(coordinator: &mut AddressCoordinator, acquirer: &mut AddressAcquirer, time: ms) {
+        let request = acquirer.update(time).expect("Lease request shall be sent");
+        let lease = coordinator
+            .handle_request(&request, ExactAddressType::new(1).unwrap(), time)
+            .expect("Lease shall be given");
+        acquirer.handle(&lease, time);
+    }
+
+    #[test]
+    fn leases_different_addresses_from_range() {
+        let mut coordinator = make_coordinator();
+        let mut first = make_acquirer(1);
+        let mut second = make_acquirer(2);
+
+        lease(&mut coordinator, &mut first, 0);
+        lease(&mut coordinator, &mut second, 0);
+
+        let first_address = first.assigned_address().unwrap().get();
+        let second_address = second.assigned_address().unwrap().get();
+        assert!((10..=11).contains(&first_address));
+        assert!((10..=11).contains(&second_address));
+        assert_ne!(first_address, second_address);
+
+        // Range is exhausted.
+        let mut third = make_acquirer(3);
+        let request = third.update(0).unwrap();
+        assert!(coordinator
+            .handle_request(&request, ExactAddressType::new(1).unwrap(), 0)
+            .is_none());
+    }
+
+    #[test]
+    fn lease_is_renewed_and_expired_one_is_reclaimed() {
+        let mut coordinator = make_coordinator();
+        let mut first = make_acquirer(1);
+        let mut second = make_acquirer(2);
+
+        lease(&mut coordinator, &mut first, 0);
+        let address = first.assigned_address();
+
+        // Renewal after the half of the period keeps the address.
+        lease(&mut coordinator, &mut first, LEASE_PERIOD / 2);
+        assert_eq!(first.assigned_address(), address);
+
+        lease(&mut coordinator, &mut second, 0);
+
+        // Second device does not renew it's lease, so it's address is reclaimed.
+        let mut third = make_acquirer(3);
+        lease(&mut coordinator, &mut third, LEASE_PERIOD + 1);
+        assert!(third.assigned_address().is_some());
+
+        assert!(second.update(LEASE_PERIOD).is_none());
+        assert!(second.assigned_address().is_none());
+    }
+}
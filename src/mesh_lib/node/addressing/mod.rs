@@ -0,0 +1,180 @@
+mod acquirer;
+mod coordinator;
+mod prober;
+
+pub use acquirer::AddressAcquirer;
+pub use coordinator::AddressCoordinator;
+pub use prober::AddressProber;
+
+use super::packet::PacketFlagOps;
+use super::{
+    ms, ExactAddressType, GeneralAddressType, LifeTimeType, Packet, PacketDataBytes, PacketState,
+};
+
+const HARDWARE_ID_SIZE: usize = core::mem::size_of::<u32>();
+const LEASE_PERIOD_SIZE: usize = core::mem::size_of::<ms>();
+
+/// Address acquisition configuration.
+/// With this configuration being set, the device does not use
+/// `device_address` from `NodeConfig`, and acquires the address by itself.
+/// Till the address is acquired, the device uses tentative address.
+pub struct AddressAcquisitionConfig {
+    /// Unique identifier of the chip, like serial number or MAC address.
+    /// Tentative addresses are derived from it.
+    pub hardware_id: u32,
+
+    /// The way, the address is acquired.
+    pub mode: AddressAcquisitionMode,
+
+    /// Period of time in milliseconds, during which the answer for the probe or
+    /// for the lease request is awaited. It shall cover listen periods of
+    /// all devices on the way to the farthest device and back.
+    pub answer_timeout: ms,
+
+    /// Count of probes, which shall stay unanswered before the address is claimed.
+    pub probes_count: u8,
+
+    /// Count of devices, which probes and lease requests are able to pass.
+    pub lifetime: LifeTimeType,
+}
+
+/// The way, the device acquires it's address.
+pub enum AddressAcquisitionMode {
+    /// The device probes tentative address, and claims it
+    /// in case if no other device reports the conflict.
+    /// Otherwise the next tentative address is probed.
+    SelfAssigned,
+
+    /// The device requests the address from the coordinator device,
+    /// and renews the lease till it is working.
+    Leased {
+        /// Address of the coordinator device.
+        coordinator: ExactAddressType,
+    },
+}
+
+/// Address coordinator configuration.
+/// With this configuration being set, the device leases addresses
+/// from the range to devices, which use `AddressAcquisitionMode::Leased`.
+pub struct AddressCoordinatorConfig {
+    /// The first address of the range, which is leased.
+    pub first_address: ExactAddressType,
+
+    /// The last address of the range, which is leased.
+    pub last_address: ExactAddressType,
+
+    /// Period of time in milliseconds, for which the address is leased.
+    /// Devices renew the lease after the half of this period.
+    pub lease_period: ms,
+}
+
+/// Address, hardware id and lifetime, which the packet was sent with.
+/// Is kept by all packets of address acquisition, so the answer
+/// can travel as far, as the question did.
+struct AddressClaim {
+    address: ExactAddressType,
+    hardware_id: u32,
+    lifetime: LifeTimeType,
+}
+
+const ADDRESS_CLAIM_SIZE: usize = 1 + HARDWARE_ID_SIZE + 1;
+
+impl AddressClaim {
+    fn from_packet(packet: &Packet) -> Option<AddressClaim> {
+        let address = ExactAddressType::new(*packet.data.first()?)?;
+        let hardware_id = packet
+            .data
+            .get(1..1 + HARDWARE_ID_SIZE)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u32::from_be_bytes)?;
+        let lifetime = *packet.data.get(1 + HARDWARE_ID_SIZE)?;
+        Some(AddressClaim {
+            address,
+            hardware_id,
+            lifetime,
+        })
+    }
+
+    fn into_data(self) -> PacketDataBytes {
+        let mut data = PacketDataBytes::new();
+        let _ = data.push(self.address.get());
+        let _ = data.extend_from_slice(&self.hardware_id.to_be_bytes());
+        let _ = data.push(self.lifetime);
+        data
+    }
+
+    fn into_packet(
+        self,
+        source_device_identifier: ExactAddressType,
+        destination_device_identifier: GeneralAddressType,
+        state: PacketState,
+    ) -> Packet {
+        let lifetime = self.lifetime;
+        Packet::new(
+            source_device_identifier.into(),
+            destination_device_identifier.into(),
+            0,
+            lifetime,
+            state,
+            true,
+            self.into_data(),
+        )
+    }
+}
+
+/// Answer of the device, which already uses the probed address.
+/// Returns `None` in case if the probe is not about `current_device_identifier`.
+pub fn make_conflict_packet(
+    probe: &Packet,
+    current_device_identifier: ExactAddressType,
+) -> Option<Packet> {
+    let claim = AddressClaim::from_packet(probe)?;
+    if claim.address != current_device_identifier {
+        return None;
+    }
+    let mut conflict = claim.into_packet(
+        current_device_identifier,
+        GeneralAddressType::Broadcast,
+        PacketState::AddressConflict,
+    );
+    // Devices with the same address count ids independently, so the answer
+    // may look like the duplicate of the packet, sent by the probing device.
+    conflict.set_ignore_duplication_flag(false);
+    Some(conflict)
+}
+
+/// Address lease given by the coordinator.
+struct AddressLease {
+    claim: AddressClaim,
+    lease_period: ms,
+}
+
+impl AddressLease {
+    fn from_packet(packet: &Packet) -> Option<AddressLease> {
+        let claim = AddressClaim::from_packet(packet)?;
+        let lease_period = packet
+            .data
+            .get(ADDRESS_CLAIM_SIZE..ADDRESS_CLAIM_SIZE + LEASE_PERIOD_SIZE)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(ms::from_be_bytes)?;
+        Some(AddressLease {
+            claim,
+            lease_period,
+        })
+    }
+
+    fn into_packet(self, coordinator: ExactAddressType) -> Packet {
+        let lifetime = self.claim.lifetime;
+        let mut data = self.claim.into_data();
+        let _ = data.extend_from_slice(&self.lease_period.to_be_bytes());
+        Packet::new(
+            coordinator.into(),
+            GeneralAddressType::Broadcast.into(),
+            0,
+            lifetime,
+            PacketState::AddressLease,
+            true,
+            data,
+        )
+    }
+}
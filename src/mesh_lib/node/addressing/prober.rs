@@ -0,0 +1,91 @@
+use super::{
+    AddressClaim, ExactAddressType, GeneralAddressType, LifeTimeType, Packet, PacketState,
+};
+
+/// Checks, if the address is already used by some device,
+/// without claiming it.
+pub struct AddressProber {
+    address: ExactAddressType,
+    hardware_id: u32,
+    is_conflict_reported: bool,
+}
+
+impl AddressProber {
+    /// `hardware_id` tells answers for this probe apart from others.
+    pub fn new(address: ExactAddressType, hardware_id: u32) -> AddressProber {
+        AddressProber {
+            address,
+            hardware_id,
+            is_conflict_reported: false,
+        }
+    }
+
+    pub fn make_probe_packet(
+        &self,
+        source_device_identifier: ExactAddressType,
+        lifetime: LifeTimeType,
+    ) -> Packet {
+        AddressClaim {
+            address: self.address,
+            hardware_id: self.hardware_id,
+            lifetime,
+        }
+        .into_packet(
+            source_device_identifier,
+            GeneralAddressType::Broadcast,
+            PacketState::AddressProbe,
+        )
+    }
+
+    /// Handles the echo of the own probe and the answer for it.
+    /// Returns false in case if the packet is not related to this probe.
+    pub fn handle(&mut self, packet: &Packet) -> bool {
+        let claim = match AddressClaim::from_packet(packet) {
+            Some(claim) => claim,
+            None => return false,
+        };
+        if claim.hardware_id != self.hardware_id || claim.address != self.address {
+            return false;
+        }
+        match packet.get_spec_state() {
+            PacketState::AddressProbe => true,
+            PacketState::AddressConflict => {
+                self.is_conflict_reported = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Tells if some device has reported, that it uses the probed address.
+    pub fn is_conflict_reported(&self) -> bool {
+        self.is_conflict_reported
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::make_conflict_packet;
+    use super::*;
+
+    #[test]
+    fn conflict_is_reported_by_owner_only() {
+        let address = ExactAddressType::new(5).unwrap();
+        let mut prober = AddressProber::new(address, 42);
+        let probe = prober.make_probe_packet(ExactAddressType::new(1).unwrap(), 3);
+
+        // Echo of own probe.
+        assert!(prober.handle(&probe));
+        assert!(!prober.is_conflict_reported());
+
+        assert!(make_conflict_packet(&probe, ExactAddressType::new(6).unwrap()).is_none());
+
+        let conflict = make_conflict_packet(&probe, address).expect("Owner shall answer");
+        assert!(prober.handle(&conflict));
+        assert!(prober.is_conflict_reported());
+
+        let mut other_prober = AddressProber::new(address, 43);
+        assert!(!other_prober.handle(&conflict));
+        assert!(!other_prober.is_conflict_reported());
+    }
+}
@@ -0,0 +1,293 @@
+mod pcapng;
+
+use std::collections::VecDeque;
+
+use super::constants::{PACKET_START_BYTE, PACKET_START_BYTES_COUNT};
+use super::packet::{
+    GeneralAddressType, Packet, PacketSerializedBytes, Serializer, PACKET_BYTES_SIZE,
+};
+use super::{ms, Clock};
+
+pub use pcapng::{PcapReader, PcapWriter};
+
+/// Tells if the frame was sent or received by the device.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Received,
+    Sent,
+}
+
+/// Frame, captured on the interface.
+#[derive(Clone)]
+pub struct CapturedFrame {
+    /// Time in milliseconds, when the frame was captured.
+    pub timestamp: ms,
+
+    pub direction: Direction,
+
+    /// Index of the interface, trough which the frame was sent or received.
+    pub interface: usize,
+
+    /// Packet, deserialized from the bytes of the frame as they were,
+    /// even if the frame is damaged.
+    pub packet: Packet,
+}
+
+impl CapturedFrame {
+    /// Tells if the frame would be accepted by the node.
+    /// Damaged frames are captured too, but are dropped by the node.
+    pub fn is_valid(&self) -> bool {
+        self.packet.is_checksum_correct() && self.packet.has_correct_source_device_identifier()
+    }
+}
+
+/// Pretty-prints the frame as the single line, like:
+/// `      1250 ms  rx  if0    2 -> 1    id 7    lifetime 3    Ping  "hello"`
+/// Damaged frames are ended by `  invalid`.
+impl core::fmt::Display for CapturedFrame {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let direction = match self.direction {
+            Direction::Received => "rx",
+            Direction::Sent => "tx",
+        };
+        write!(
+            f,
+            "{:>10} ms  {}  if{}  {:>3} -> ",
+            self.timestamp, direction, self.interface, self.packet.source_device_identifier,
+        )?;
+        match GeneralAddressType::from(self.packet.get_destination_device_identifier()) {
+            GeneralAddressType::Broadcast => write!(f, "all")?,
+            GeneralAddressType::Exact(address) => write!(f, "{:<3}", address)?,
+        }
+        write!(
+            f,
+            "  id {:<3}  lifetime {:<3}  {:?}  \"",
+            self.packet.get_id(),
+            self.packet.get_lifetime(),
+            self.packet.get_spec_state(),
+        )?;
+        let data_length = self.packet.get_data_length().min(self.packet.data.len());
+        for byte in self.packet.data[..data_length].iter() {
+            write!(f, "{}", core::ascii::escape_default(*byte))?;
+        }
+        write!(f, "\"")?;
+        if !self.is_valid() {
+            write!(f, "  invalid")?;
+        }
+        Ok(())
+    }
+}
+
+/// Wraps the interface driver, and captures every frame, which is
+/// sent or received trough it. The node uses the wrapper the same way
+/// as the driver itself.
+///
+/// Frames are captured as they were heard, damaged ones too, so the reason
+/// of the lost packet can be seen. Every sequence of start bytes, followed
+/// by enough bytes for the packet, is taken for the frame.
+///
+/// Captured frames are kept till they are taken out by `take_frame` method,
+/// so it shall be called regularly.
+///
+/// `main.rs`:
+/// ```
+/// let mut capture = PcapWriter::new(File::create("mesh.pcapng")?, &["lora"])?;
+/// let mut lora = CaptureInterface::new(lora, 0, StdClock::new());
+/// loop {
+///     let _ = mesh_node.update(&mut lora, current_time);
+///     while let Some(frame) = lora.take_frame() {
+///         capture.write_frame(&frame)?;
+///     }
+/// }
+/// ```
+pub struct CaptureInterface<I, C: Clock> {
+    interface: I,
+    interface_index: usize,
+    clock: C,
+    sent_splitter: FrameSplitter,
+    received_splitter: FrameSplitter,
+    frames: VecDeque<CapturedFrame>,
+}
+
+impl<I, C: Clock> CaptureInterface<I, C> {
+    /// Wraps the `interface`, which is passed to the node under `interface_index`.
+    pub fn new(interface: I, interface_index: usize, clock: C) -> CaptureInterface<I, C> {
+        CaptureInterface {
+            interface,
+            interface_index,
+            clock,
+            sent_splitter: FrameSplitter::new(),
+            received_splitter: FrameSplitter::new(),
+            frames: VecDeque::new(),
+        }
+    }
+
+    /// Takes out the oldest captured frame.
+    pub fn take_frame(&mut self) -> Option<CapturedFrame> {
+        self.frames.pop_front()
+    }
+
+    /// Returns the wrapped interface driver.
+    pub fn inner_mut(&mut self) -> &mut I {
+        &mut self.interface
+    }
+
+    fn capture(&mut self, bytes: &[u8], direction: Direction) {
+        let splitter = match direction {
+            Direction::Received => &mut self.received_splitter,
+            Direction::Sent => &mut self.sent_splitter,
+        };
+        for byte in bytes {
+            if let Some(packet) = splitter.push_byte(*byte) {
+                self.frames.push_back(CapturedFrame {
+                    timestamp: self.clock.now(),
+                    direction,
+                    interface: self.interface_index,
+                    packet,
+                });
+            }
+        }
+    }
+}
+
+/// Splits the stream of bytes into frames, without checking them.
+struct FrameSplitter {
+    bytes: VecDeque<u8>,
+}
+
+impl FrameSplitter {
+    fn new() -> FrameSplitter {
+        FrameSplitter {
+            bytes: VecDeque::new(),
+        }
+    }
+
+    /// Returns the packet, once the frame is over.
+    fn push_byte(&mut self, byte: u8) -> Option<Packet> {
+        self.bytes.push_back(byte);
+        if self.bytes.len() < PACKET_START_BYTES_COUNT + PACKET_BYTES_SIZE {
+            return None;
+        }
+
+        if !self
+            .bytes
+            .iter()
+            .take(PACKET_START_BYTES_COUNT)
+            .all(|byte| *byte == PACKET_START_BYTE)
+        {
+            self.bytes.pop_front();
+            return None;
+        }
+
+        let packet_bytes: PacketSerializedBytes = self
+            .bytes
+            .iter()
+            .skip(PACKET_START_BYTES_COUNT)
+            .copied()
+            .collect();
+        let packet = <Packet as Serializer>::deserialize(packet_bytes);
+
+        if packet.is_checksum_correct() && packet.has_correct_source_device_identifier() {
+            self.bytes.clear();
+        } else {
+            // Start bytes of the next frame might be among bytes of the damaged one.
+            self.bytes.pop_front();
+        }
+        Some(packet)
+    }
+}
+
+impl<I: embedded_io::ErrorType, C: Clock> embedded_io::ErrorType for CaptureInterface<I, C> {
+    type Error = I::Error;
+}
+
+impl<I: embedded_io::ReadReady, C: Clock> embedded_io::ReadReady for CaptureInterface<I, C> {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        self.interface.read_ready()
+    }
+}
+
+impl<I: embedded_io::Read, C: Clock> embedded_io::Read for CaptureInterface<I, C> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let count = self.interface.read(buf)?;
+        self.capture(&buf[..count], Direction::Received);
+        Ok(count)
+    }
+}
+
+impl<I: embedded_io::Write, C: Clock> embedded_io::Write for CaptureInterface<I, C> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let count = self.interface.write(buf)?;
+        self.capture(&buf[..count], Direction::Sent);
+        Ok(count)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.interface.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::packet::{PacketDataBytes, PacketState};
+
+    fn make_frame() -> CapturedFrame {
+        CapturedFrame {
+            timestamp: 1250,
+            direction: Direction::Received,
+            interface: 0,
+            packet: Packet::new(
+                2,
+                1,
+                7,
+                3,
+                PacketState::Ping,
+                true,
+                PacketDataBytes::from_slice(b"hello").unwrap(),
+            )
+            .summarized(),
+        }
+    }
+
+    fn frame_bytes(packet: Packet) -> std::vec::Vec<u8> {
+        let mut bytes = std::vec![PACKET_START_BYTE; PACKET_START_BYTES_COUNT];
+        bytes.extend_from_slice(&packet.serialized());
+        bytes
+    }
+
+    #[test]
+    fn captured_frame_is_pretty_printed() {
+        assert_eq!(
+            std::format!("{}", make_frame()),
+            "      1250 ms  rx  if0    2 -> 1    id 7    lifetime 3    Ping  \"hello\""
+        );
+    }
+
+    #[test]
+    fn damaged_frame_is_printed_as_invalid() {
+        let mut frame = make_frame();
+        frame.packet.data[0] ^= 1;
+        assert!(std::format!("{}", frame).ends_with("\"iello\"  invalid"));
+    }
+
+    #[test]
+    fn damaged_frame_is_split_before_valid_one() {
+        let valid = make_frame().packet;
+        let mut damaged = valid.clone();
+        damaged.data[0] ^= 1;
+
+        let mut splitter = FrameSplitter::new();
+        let mut frames = std::vec::Vec::new();
+        for byte in frame_bytes(damaged)
+            .into_iter()
+            .chain(frame_bytes(valid.clone()))
+        {
+            frames.extend(splitter.push_byte(byte));
+        }
+
+        assert_eq!(frames.len(), 2);
+        assert!(!frames[0].is_checksum_correct());
+        assert_eq!(frames[1].clone().serialized(), valid.serialized());
+    }
+}
@@ -0,0 +1,437 @@
+use std::io::{self, Read, Write};
+use std::vec::Vec;
+
+use super::super::ms;
+use super::super::packet::{Packet, PacketSerializedBytes, Serializer, PACKET_BYTES_SIZE};
+use super::{CapturedFrame, Direction};
+
+const SECTION_HEADER_BLOCK: u32 = 0x0A0D_0D0A;
+const INTERFACE_DESCRIPTION_BLOCK: u32 = 0x0000_0001;
+const ENHANCED_PACKET_BLOCK: u32 = 0x0000_0006;
+
+const BYTE_ORDER_MAGIC: u32 = 0x1A2B_3C4D;
+
+/// `LINKTYPE_USER0`, which is reserved for private protocols.
+/// Wireshark shows frames of it as raw bytes, unless a dissector is configured.
+const LINK_TYPE: u16 = 147;
+
+const OPTION_END: u16 = 0;
+const OPTION_IF_NAME: u16 = 2;
+const OPTION_IF_TSRESOL: u16 = 9;
+const OPTION_EPB_FLAGS: u16 = 2;
+
+/// Timestamps are written in milliseconds, as `10^-3` seconds.
+const MILLISECONDS_TSRESOL: u8 = 3;
+/// Resolution of timestamps, if the interface does not tell other one.
+const DEFAULT_TSRESOL: u8 = 6;
+
+const EPB_FLAGS_INBOUND: u32 = 0b01;
+const EPB_FLAGS_OUTBOUND: u32 = 0b10;
+/// Link-layer error of the frame, which is set for damaged frames.
+const EPB_FLAGS_CRC_ERROR: u32 = 1 << 24;
+
+/// Size of block type and block total length fields at the start of every block,
+/// and of the block total length at the end of it.
+const BLOCK_HEADER_SIZE: usize = 8;
+const BLOCK_TRAILER_SIZE: usize = 4;
+
+/// Blocks of captured frames are small, so longer blocks
+/// are considered to be the damage of the file.
+const MAX_BLOCK_SIZE: usize = 1 << 20;
+
+/// Writes captured frames into pcapng file, which can be opened by Wireshark.
+/// Each interface of the node gets own interface description in the file,
+/// and each frame keeps its timestamp and direction.
+pub struct PcapWriter<W: Write> {
+    writer: W,
+    interfaces_count: usize,
+}
+
+impl<W: Write> PcapWriter<W> {
+    /// Writes the header of the file, with interfaces named by `interface_names`
+    /// in the same order, as they are passed into the node.
+    pub fn new(writer: W, interface_names: &[&str]) -> io::Result<PcapWriter<W>> {
+        let mut pcap_writer = PcapWriter {
+            writer,
+            interfaces_count: interface_names.len(),
+        };
+
+        let mut section_header = Vec::new();
+        section_header.extend_from_slice(&BYTE_ORDER_MAGIC.to_le_bytes());
+        section_header.extend_from_slice(&1u16.to_le_bytes()); // Major version.
+        section_header.extend_from_slice(&0u16.to_le_bytes()); // Minor version.
+        section_header.extend_from_slice(&(-1i64).to_le_bytes()); // Section length is unknown.
+        pcap_writer.write_block(SECTION_HEADER_BLOCK, &section_header)?;
+
+        for name in interface_names {
+            let mut interface_description = Vec::new();
+            interface_description.extend_from_slice(&LINK_TYPE.to_le_bytes());
+            interface_description.extend_from_slice(&0u16.to_le_bytes()); // Reserved.
+            interface_description.extend_from_slice(&0u32.to_le_bytes()); // No snap length limit.
+            push_option(&mut interface_description, OPTION_IF_NAME, name.as_bytes());
+            push_option(
+                &mut interface_description,
+                OPTION_IF_TSRESOL,
+                &[MILLISECONDS_TSRESOL],
+            );
+            push_option(&mut interface_description, OPTION_END, &[]);
+            pcap_writer.write_block(INTERFACE_DESCRIPTION_BLOCK, &interface_description)?;
+        }
+
+        Ok(pcap_writer)
+    }
+
+    /// Writes the frame, which shall be captured on one of
+    /// the interfaces, named during creation of the writer.
+    /// Damaged frames are marked by the CRC error flag.
+    // Conversion of `ms` is useless, if `time64` feature is turned on.
+    #[allow(clippy::useless_conversion)]
+    pub fn write_frame(&mut self, frame: &CapturedFrame) -> io::Result<()> {
+        if frame.interface >= self.interfaces_count {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Frame is captured on unknown interface",
+            ));
+        }
+
+        let frame_bytes = frame.packet.clone().serialized();
+        let timestamp = u64::from(frame.timestamp);
+        let mut flags = match frame.direction {
+            Direction::Received => EPB_FLAGS_INBOUND,
+            Direction::Sent => EPB_FLAGS_OUTBOUND,
+        };
+        if !frame.is_valid() {
+            flags |= EPB_FLAGS_CRC_ERROR;
+        }
+
+        let mut enhanced_packet = Vec::new();
+        enhanced_packet.extend_from_slice(&(frame.interface as u32).to_le_bytes());
+        enhanced_packet.extend_from_slice(&((timestamp >> 32) as u32).to_le_bytes());
+        enhanced_packet.extend_from_slice(&(timestamp as u32).to_le_bytes());
+        enhanced_packet.extend_from_slice(&(frame_bytes.len() as u32).to_le_bytes());
+        enhanced_packet.extend_from_slice(&(frame_bytes.len() as u32).to_le_bytes());
+        enhanced_packet.extend_from_slice(&frame_bytes);
+        pad(&mut enhanced_packet);
+        push_option(&mut enhanced_packet, OPTION_EPB_FLAGS, &flags.to_le_bytes());
+        push_option(&mut enhanced_packet, OPTION_END, &[]);
+        self.write_block(ENHANCED_PACKET_BLOCK, &enhanced_packet)
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_block(&mut self, block_type: u32, body: &[u8]) -> io::Result<()> {
+        let total_length = (BLOCK_HEADER_SIZE + body.len() + BLOCK_TRAILER_SIZE) as u32;
+        self.writer.write_all(&block_type.to_le_bytes())?;
+        self.writer.write_all(&total_length.to_le_bytes())?;
+        self.writer.write_all(body)?;
+        self.writer.write_all(&total_length.to_le_bytes())
+    }
+}
+
+fn push_option(body: &mut Vec<u8>, code: u16, value: &[u8]) {
+    body.extend_from_slice(&code.to_le_bytes());
+    body.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    body.extend_from_slice(value);
+    pad(body);
+}
+
+/// Fields of pcapng are aligned to 32 bits.
+fn pad(body: &mut Vec<u8>) {
+    body.resize(body.len().div_ceil(4) * 4, 0);
+}
+
+/// Reads frames from pcapng file, written by `PcapWriter`, or re-saved by Wireshark.
+/// Frames are deserialized by the same code, the node uses to parse the ether.
+///
+/// `main.rs`:
+/// ```
+/// for frame in PcapReader::new(File::open("mesh.pcapng")?)? {
+///     println!("{}", frame?);
+/// }
+/// ```
+pub struct PcapReader<R: Read> {
+    reader: R,
+    is_big_endian: bool,
+    /// Resolution of timestamps of each described interface.
+    interfaces_tsresol: Vec<u8>,
+}
+
+impl<R: Read> PcapReader<R> {
+    /// Reads the header of the file.
+    pub fn new(reader: R) -> io::Result<PcapReader<R>> {
+        let mut pcap_reader = PcapReader {
+            reader,
+            is_big_endian: false,
+            interfaces_tsresol: Vec::new(),
+        };
+        match pcap_reader.read_block()? {
+            Some((SECTION_HEADER_BLOCK, body)) => pcap_reader.start_section(&body)?,
+            _ => return Err(invalid_data("File does not start with section header")),
+        }
+        Ok(pcap_reader)
+    }
+
+    /// Reads the next captured frame. Returns `None` at the end of the file.
+    pub fn next_frame(&mut self) -> io::Result<Option<CapturedFrame>> {
+        while let Some((block_type, body)) = self.read_block()? {
+            match block_type {
+                SECTION_HEADER_BLOCK => self.start_section(&body)?,
+                INTERFACE_DESCRIPTION_BLOCK => self.describe_interface(&body)?,
+                ENHANCED_PACKET_BLOCK => return self.read_frame(&body).map(Some),
+                // Blocks of other types do not carry frames.
+                _ => (),
+            }
+        }
+        Ok(None)
+    }
+
+    fn read_block(&mut self) -> io::Result<Option<(u32, Vec<u8>)>> {
+        let mut header = [0u8; BLOCK_HEADER_SIZE];
+        match self.reader.read_exact(&mut header) {
+            Ok(()) => (),
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(error) => return Err(error),
+        }
+        let block_type = self.u32_at(&header, 0)?;
+
+        // Byte order of the section is known only from its header,
+        // type of which reads the same in both orders.
+        let mut body = Vec::new();
+        if block_type == SECTION_HEADER_BLOCK {
+            let mut magic = [0u8; 4];
+            self.reader.read_exact(&mut magic)?;
+            self.is_big_endian = u32::from_be_bytes(magic) == BYTE_ORDER_MAGIC;
+            body.extend_from_slice(&magic);
+        }
+
+        let total_length = self.u32_at(&header, 4)? as usize;
+        self.read_body(&mut body, total_length)?;
+        Ok(Some((block_type, body)))
+    }
+
+    fn read_body(&mut self, body: &mut Vec<u8>, total_length: usize) -> io::Result<()> {
+        if total_length > MAX_BLOCK_SIZE {
+            return Err(invalid_data("Block is too long"));
+        }
+        let body_length = total_length
+            .checked_sub(BLOCK_HEADER_SIZE + BLOCK_TRAILER_SIZE)
+            .ok_or_else(|| invalid_data("Block is too short"))?;
+        let already_read = body.len();
+        body.resize(body_length, 0);
+        self.reader.read_exact(&mut body[already_read..])?;
+        let mut trailer = [0u8; BLOCK_TRAILER_SIZE];
+        self.reader.read_exact(&mut trailer)
+    }
+
+    fn start_section(&mut self, body: &[u8]) -> io::Result<()> {
+        if self.u32_at(body, 0)? != BYTE_ORDER_MAGIC {
+            return Err(invalid_data("Unknown byte order of section"));
+        }
+        self.interfaces_tsresol.clear();
+        Ok(())
+    }
+
+    fn describe_interface(&mut self, body: &[u8]) -> io::Result<()> {
+        let mut tsresol = DEFAULT_TSRESOL;
+        let mut offset = 8;
+        while offset + 4 <= body.len() {
+            let code = self.u16_at(body, offset)?;
+            let length = self.u16_at(body, offset + 2)? as usize;
+            if code == OPTION_END {
+                break;
+            }
+            if code == OPTION_IF_TSRESOL && length == 1 {
+                tsresol = *body.get(offset + 4).unwrap_or(&DEFAULT_TSRESOL);
+            }
+            offset += 4 + length.div_ceil(4) * 4;
+        }
+        self.interfaces_tsresol.push(tsresol);
+        Ok(())
+    }
+
+    fn read_frame(&mut self, body: &[u8]) -> io::Result<CapturedFrame> {
+        let interface = self.u32_at(body, 0)? as usize;
+        let tsresol = *self
+            .interfaces_tsresol
+            .get(interface)
+            .ok_or_else(|| invalid_data("Frame is captured on undescribed interface"))?;
+        let timestamp = (u64::from(self.u32_at(body, 4)?) << 32) | u64::from(self.u32_at(body, 8)?);
+        let captured_length = self.u32_at(body, 12)? as usize;
+
+        let frame_bytes = body
+            .get(20..20 + captured_length)
+            .ok_or_else(|| invalid_data("Frame is longer than its block"))?;
+
+        let mut direction = Direction::Received;
+        let mut offset = 20 + captured_length.div_ceil(4) * 4;
+        while offset + 4 <= body.len() {
+            let code = self.u16_at(body, offset)?;
+            let length = self.u16_at(body, offset + 2)? as usize;
+            if code == OPTION_END {
+                break;
+            }
+            if code == OPTION_EPB_FLAGS
+                && length == 4
+                && self.u32_at(body, offset + 4)? & 0b11 == EPB_FLAGS_OUTBOUND
+            {
+                direction = Direction::Sent;
+            }
+            offset += 4 + length.div_ceil(4) * 4;
+        }
+
+        let packet_bytes: PacketSerializedBytes = frame_bytes
+            .iter()
+            .take(PACKET_BYTES_SIZE)
+            .copied()
+            .collect();
+
+        Ok(CapturedFrame {
+            timestamp: to_milliseconds(timestamp, tsresol),
+            direction,
+            interface,
+            packet: <Packet as Serializer>::deserialize(packet_bytes),
+        })
+    }
+
+    fn u16_at(&self, bytes: &[u8], offset: usize) -> io::Result<u16> {
+        let field: [u8; 2] = bytes
+            .get(offset..offset + 2)
+            .and_then(|field| field.try_into().ok())
+            .ok_or_else(|| invalid_data("Block is too short"))?;
+        Ok(match self.is_big_endian {
+            true => u16::from_be_bytes(field),
+            false => u16::from_le_bytes(field),
+        })
+    }
+
+    fn u32_at(&self, bytes: &[u8], offset: usize) -> io::Result<u32> {
+        let field: [u8; 4] = bytes
+            .get(offset..offset + 4)
+            .and_then(|field| field.try_into().ok())
+            .ok_or_else(|| invalid_data("Block is too short"))?;
+        Ok(match self.is_big_endian {
+            true => u32::from_be_bytes(field),
+            false => u32::from_le_bytes(field),
+        })
+    }
+}
+
+impl<R: Read> Iterator for PcapReader<R> {
+    type Item = io::Result<CapturedFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_frame().transpose()
+    }
+}
+
+/// Converts the timestamp of `tsresol` resolution into milliseconds.
+/// Resolution is `10^-value` seconds, or `2^-value` if the highest bit is set.
+fn to_milliseconds(timestamp: u64, tsresol: u8) -> ms {
+    let exponent = u32::from(tsresol & 0x7F);
+    let units_per_second: u128 = match tsresol & 0x80 {
+        0 => 10u128.checked_pow(exponent),
+        _ => 2u128.checked_pow(exponent),
+    }
+    .unwrap_or(u128::MAX);
+    (u128::from(timestamp) * 1000 / units_per_second) as ms
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::packet::{PacketDataBytes, PacketState};
+
+    fn make_frame(timestamp: ms, direction: Direction, interface: usize) -> CapturedFrame {
+        CapturedFrame {
+            timestamp,
+            direction,
+            interface,
+            packet: Packet::new(
+                2,
+                1,
+                7,
+                3,
+                PacketState::Ping,
+                true,
+                PacketDataBytes::from_slice(b"hello").unwrap(),
+            )
+            .summarized(),
+        }
+    }
+
+    #[test]
+    fn written_frames_are_read_back() {
+        let mut writer = PcapWriter::new(Vec::new(), &["lora", "hc12"]).unwrap();
+        writer
+            .write_frame(&make_frame(1250, Direction::Received, 0))
+            .unwrap();
+        writer
+            .write_frame(&make_frame(1300, Direction::Sent, 1))
+            .unwrap();
+        let file = writer.into_inner();
+
+        let frames: Vec<CapturedFrame> = PcapReader::new(&file[..])
+            .unwrap()
+            .collect::<io::Result<_>>()
+            .unwrap();
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].timestamp, 1250);
+        assert!(frames[0].direction == Direction::Received);
+        assert_eq!(frames[0].interface, 0);
+        assert!(frames[0].packet.is_checksum_correct());
+        assert!(frames[0].packet.get_spec_state() == PacketState::Ping);
+        assert_eq!(frames[1].timestamp, 1300);
+        assert!(frames[1].direction == Direction::Sent);
+        assert_eq!(frames[1].interface, 1);
+    }
+
+    #[test]
+    fn damaged_frame_is_written_as_it_was() {
+        let mut frame = make_frame(1250, Direction::Received, 0);
+        frame.packet.data[0] ^= 1;
+        assert!(!frame.is_valid());
+
+        let mut writer = PcapWriter::new(Vec::new(), &["lora"]).unwrap();
+        writer.write_frame(&frame).unwrap();
+        let file = writer.into_inner();
+
+        let frames: Vec<CapturedFrame> = PcapReader::new(&file[..])
+            .unwrap()
+            .collect::<io::Result<_>>()
+            .unwrap();
+
+        assert_eq!(frames.len(), 1);
+        assert!(!frames[0].is_valid());
+        assert_eq!(
+            frames[0].packet.clone().serialized(),
+            frame.packet.serialized()
+        );
+    }
+
+    #[test]
+    fn frame_on_unknown_interface_is_not_written() {
+        let mut writer = PcapWriter::new(Vec::new(), &["lora"]).unwrap();
+        assert!(writer
+            .write_frame(&make_frame(0, Direction::Sent, 1))
+            .is_err());
+    }
+
+    #[test]
+    fn timestamps_are_converted_to_milliseconds() {
+        assert_eq!(to_milliseconds(1_250_000, DEFAULT_TSRESOL), 1250);
+        assert_eq!(to_milliseconds(1250, MILLISECONDS_TSRESOL), 1250);
+        assert_eq!(to_milliseconds(1024, 0x80 | 10), 1000);
+    }
+}
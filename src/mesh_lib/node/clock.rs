@@ -0,0 +1,136 @@
+use super::ms;
+
+/// Source of current time in milliseconds since the start of the program.
+/// The time shall not go backwards. Overflow is allowed, as all time
+/// countings are done with wrapping arithmetic, but `time64` feature
+/// makes it practically unreachable.
+///
+/// The clock is read once per call of public method of `Node`. Parts of
+/// the device, like it's timer and duplicate filter, are given the read
+/// time as the clock, which is stopped at that time, so they never
+/// disagree on it.
+///
+/// Is implemented for closures, which return `ms`, so the time
+/// can be provided the same way as before:
+///
+/// ```
+/// let clock = || {
+///     Instant::now()
+///         .duration_since(program_start_time)
+///         .as_millis() as ms
+/// };
+/// ```
+pub trait Clock {
+    /// Returns current time in milliseconds.
+    fn now(&self) -> ms;
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> ms,
+{
+    fn now(&self) -> ms {
+        self()
+    }
+}
+
+/// Value of time is the clock, which is stopped at that time.
+impl Clock for ms {
+    fn now(&self) -> ms {
+        *self
+    }
+}
+
+/// Clock over `std::time::Instant`, which counts
+/// the time since the creation of the clock.
+#[cfg(feature = "std")]
+pub struct StdClock {
+    start: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl StdClock {
+    /// Creates the clock, which counts the time since this call.
+    pub fn new() -> StdClock {
+        StdClock {
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for StdClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for StdClock {
+    fn now(&self) -> ms {
+        self.start.elapsed().as_millis() as ms
+    }
+}
+
+/// Clock over 64-bit `fugit` instants, which are provided
+/// by monotonic timers of embedded HALs.
+///
+/// ```
+/// let clock = FugitClock::new(|| monotonic_timer.now());
+/// ```
+#[cfg(feature = "fugit")]
+pub struct FugitClock<F> {
+    instant_provider: F,
+}
+
+#[cfg(feature = "fugit")]
+impl<F> FugitClock<F> {
+    /// Creates the clock, which takes instants from `instant_provider`.
+    pub fn new(instant_provider: F) -> FugitClock<F> {
+        FugitClock { instant_provider }
+    }
+}
+
+#[cfg(feature = "fugit")]
+impl<F, const NOM: u32, const DENOM: u32> Clock for FugitClock<F>
+where
+    F: Fn() -> fugit::Instant<u64, NOM, DENOM>,
+{
+    fn now(&self) -> ms {
+        (self.instant_provider)().duration_since_epoch().to_millis() as ms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now_of<C: Clock>(clock: &C) -> ms {
+        clock.now()
+    }
+
+    #[test]
+    fn closure_is_clock() {
+        assert_eq!(now_of(&|| 42 as ms), 42);
+    }
+
+    #[test]
+    fn time_is_stopped_clock() {
+        let time: ms = 42;
+        assert_eq!(now_of(&time), 42);
+        assert_eq!(now_of(&time), 42);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_clock_starts_from_zero() {
+        assert!(now_of(&StdClock::new()) < 1000);
+    }
+
+    #[cfg(feature = "fugit")]
+    #[test]
+    fn fugit_clock_counts_milliseconds() {
+        let clock = FugitClock::new(|| fugit::Instant::<u64, 1, 1_000_000>::from_ticks(42_000));
+        assert_eq!(now_of(&clock), 42);
+    }
+}
@@ -1,9 +1,13 @@
-use super::ms;
+use super::{ms, LifeTimeType};
 
 const SECOND: ms = 1000;
 
 pub const PACKET_QUEUE_SIZE: usize = 5;
 
+/// Lifetime, which is returned by `Node` `default_lifetime` method,
+/// unless other one is configured.
+pub const DEFAULT_LIFETIME: LifeTimeType = 10;
+
 /// Start byte of packet. The device will recognize
 /// packets by this byte.
 pub const PACKET_START_BYTE: u8 = b'x';
@@ -18,5 +22,112 @@ pub const RECEIVER_FILTER_REGISTRATION_SIZE: usize = 8;
 /// Perid of time, during which duplicated packets will be ignored.
 pub const RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD: ms = SECOND;
 
+/// Period of time, during which the load of the ether is observed,
+/// in case if adaptive listen period is not configured.
+#[cfg(feature = "adaptive-listen-period")]
+pub const RECEIVER_CHANNEL_LOAD_WINDOW: ms = SECOND;
+
+/// Count of duplicated packets heard during one window of observation,
+/// which is treated as heavy load of the ether.
+#[cfg(feature = "adaptive-listen-period")]
+pub const DUPLICATE_STORM_THRESHOLD: u32 = 4;
+
+/// Count of bits, which are transmitted per each byte over serial
+/// interface: start bit, 8 data bits and stop bit.
+#[cfg(feature = "airtime-limit")]
+pub const UART_BITS_PER_BYTE: usize = 10;
+
+/// Count of sleeping devices, which this device can keep mailboxes for.
+#[cfg(feature = "mailbox")]
+pub const MAILBOXES_COUNT: usize = 2;
+
+/// Count of packets, which are kept in the mailbox of each sleeping device.
+#[cfg(feature = "mailbox")]
+pub const MAILBOX_SIZE: usize = 2;
+
+/// Period of time, during which the packet is kept in the mailbox.
+#[cfg(feature = "mailbox")]
+pub const MAILBOX_PACKET_EXPIRY_PERIOD: ms = 60 * SECOND;
+
+/// Count of reliable sends, which delivery status is tracked simultaneously.
+#[cfg(feature = "reliable")]
+pub const RELIABLE_SENDS_COUNT: usize = 4;
+
+/// Maximal count of retransmissions of the reliably sent packet.
+#[cfg(feature = "reliable")]
+pub const RELIABLE_SEND_MAX_RETRIES: u8 = 3;
+
+/// Count of sent packets, which wait for link acknowledgement simultaneously.
+#[cfg(feature = "link-ack")]
+pub const LINK_ACK_PACKETS_COUNT: usize = 4;
+
+/// Count of addresses, which the coordinator device can lease simultaneously.
+#[cfg(feature = "addressing")]
+pub const ADDRESS_LEASES_COUNT: usize = 16;
+
+/// Count of packets, which are remembered as sent by this device,
+/// in order to tell them apart from packets of other device with the same address.
+#[cfg(feature = "addressing")]
+pub const SENT_PACKETS_HISTORY_SIZE: usize = 16;
+
+/// Shortest period of time, during which the packet is remembered as sent by this device.
+/// Packets, which travel far, are remembered for `lifetime` periods of listening.
+#[cfg(feature = "addressing")]
+pub const SENT_PACKET_MEMORY_PERIOD: ms = RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD;
+
+/// Count of packets, which are kept by the sniffer till they are taken.
+#[cfg(feature = "sniffer")]
+pub const SNIFFED_PACKET_QUEUE_SIZE: usize = 8;
+
+/// Count of interfaces, which the node listens simultaneously.
+/// Each interface has it's own parser of packets.
+#[cfg(feature = "bridge")]
+pub const MAX_INTERFACES_COUNT: usize = 2;
+
+/// Count of interfaces, which the node listens simultaneously.
+/// The node without `bridge` feature has the only interface.
+#[cfg(not(feature = "bridge"))]
+pub const MAX_INTERFACES_COUNT: usize = 1;
+
 /// Size of read buffer from serial interface for receiver.
 pub const RECEIVER_READER_BUFFER_SIZE: usize = 1;
+
+/// Count of methods, which the device can have handlers registered for.
+#[cfg(feature = "rpc")]
+pub const RPC_HANDLERS_COUNT: usize = 8;
+
+/// Count of remote calls, which responses are awaited simultaneously.
+#[cfg(feature = "rpc")]
+pub const RPC_CALLS_COUNT: usize = 4;
+
+/// Count of topics, which the device can be subscribed to.
+#[cfg(feature = "pubsub")]
+pub const SUBSCRIBED_TOPICS_COUNT: usize = 8;
+
+/// Count of topics, which the broker device can keep last values of.
+#[cfg(feature = "pubsub")]
+pub const RETAINED_TOPICS_COUNT: usize = 4;
+
+/// Count of publications, which are kept till they are taken.
+#[cfg(feature = "pubsub")]
+pub const PUBLICATION_QUEUE_SIZE: usize = 5;
+
+/// Count of parameters, which the application can expose to manager devices.
+#[cfg(feature = "parameters")]
+pub const PARAMETERS_COUNT: usize = 8;
+
+/// Count of manager devices, which are allowed to read and write parameters.
+#[cfg(feature = "parameters")]
+pub const PARAMETER_MANAGERS_COUNT: usize = 2;
+
+/// Count of diagnostics reports, which are kept till they are taken.
+#[cfg(feature = "diagnostics")]
+pub const DIAGNOSTICS_QUEUE_SIZE: usize = 4;
+
+/// Count of neighbour devices, which are remembered simultaneously.
+#[cfg(feature = "diagnostics")]
+pub const NEIGHBOURS_COUNT: usize = 8;
+
+/// Period, during which the neighbour device is counted after it was heard last time.
+#[cfg(feature = "diagnostics")]
+pub const NEIGHBOUR_EXPIRY_PERIOD: ms = 60 * SECOND;
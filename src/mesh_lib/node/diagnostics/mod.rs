@@ -0,0 +1,172 @@
+mod neighbours;
+
+use heapless::Deque;
+
+use super::constants::DIAGNOSTICS_QUEUE_SIZE;
+use super::{ExactAddressType, Packet, PacketDataBytes};
+
+pub use neighbours::Neighbours;
+
+/// Version of the protocol, which is reported by the device.
+/// Is changed each time, the format of packets is changed.
+pub const PROTOCOL_VERSION: u8 = 1;
+
+const REPORT_SIZE: usize = 15;
+
+pub type DiagnosticsQueue = Deque<DiagnosticsReport, DIAGNOSTICS_QUEUE_SIZE>;
+
+/// Report about the health of the device, which is returned by
+/// `Node` `receive_diagnostics` method. Counters, which do not fit
+/// into their fields - are kept at their maximal values.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DiagnosticsReport {
+    /// Device, which has made the report.
+    pub source: ExactAddressType,
+
+    /// Time in seconds, as it was passed to `Node` `update` method
+    /// of the device, right before the report was made.
+    pub uptime: u32,
+
+    pub protocol_version: u8,
+
+    /// Version of the firmware, as it is set in `NodeConfig`.
+    pub firmware_version: u16,
+
+    /// Count of received packets, which are not taken by the application yet.
+    pub received_queue_length: u8,
+
+    /// Count of packets, which are waiting to be sent by the device.
+    pub send_queue_length: u8,
+
+    /// Count of packets, which are waiting to be forwarded by the device.
+    pub transit_queue_length: u8,
+
+    /// Count of received packets, which were dropped, as the queue was full.
+    pub dropped_received_packets: u16,
+
+    /// Count of packets, which were not forwarded, as the queue was full.
+    /// Answers to retained values requests and packets of mailboxes,
+    /// which were not sent for the same reason, are counted too.
+    pub dropped_transit_packets: u16,
+
+    /// Count of neighbour devices, which were heard recently.
+    /// Neighbours are recognised by their link acknowledgements, so it stays
+    /// zero, unless `link_ack` is configured on the neighbour devices.
+    pub neighbours: u8,
+}
+
+impl core::fmt::Debug for DiagnosticsReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "DiagnosticsReport {{ source: {}, uptime: {} s, protocol: {}, firmware: {}, \
+             queues: {}/{}/{}, dropped: {}/{}, neighbours: {} }}",
+            self.source,
+            self.uptime,
+            self.protocol_version,
+            self.firmware_version,
+            self.received_queue_length,
+            self.send_queue_length,
+            self.transit_queue_length,
+            self.dropped_received_packets,
+            self.dropped_transit_packets,
+            self.neighbours,
+        )
+    }
+}
+
+/// Makes the content of `DiagnosticsResponse` packet. The source of the report
+/// is not kept, as it is the source of the packet.
+pub fn make_report_data(report: &DiagnosticsReport) -> PacketDataBytes {
+    let mut data = PacketDataBytes::new();
+    let _ = data.push(report.protocol_version);
+    let _ = data.extend_from_slice(&report.firmware_version.to_be_bytes());
+    let _ = data.extend_from_slice(&report.uptime.to_be_bytes());
+    let _ = data.push(report.received_queue_length);
+    let _ = data.push(report.send_queue_length);
+    let _ = data.push(report.transit_queue_length);
+    let _ = data.extend_from_slice(&report.dropped_received_packets.to_be_bytes());
+    let _ = data.extend_from_slice(&report.dropped_transit_packets.to_be_bytes());
+    let _ = data.push(report.neighbours);
+    data
+}
+
+/// Makes `DiagnosticsResponse` packet, which sends the `report`
+/// back to the device, which has sent the `request`.
+pub fn make_answer(request: &Packet, report: &DiagnosticsReport) -> Option<Packet> {
+    let mut response = request.clone().mutated().ok()?;
+    response.set_data(make_report_data(report));
+    Some(response)
+}
+
+/// Returns the report, which is kept in the `DiagnosticsResponse` packet.
+pub fn parse_report(packet: &Packet) -> Option<DiagnosticsReport> {
+    let source = ExactAddressType::new(packet.source_device_identifier)?;
+    let content = &packet.data[..packet.get_data_length().min(packet.data.len())];
+    if content.len() < REPORT_SIZE {
+        return None;
+    }
+    Some(DiagnosticsReport {
+        source,
+        protocol_version: content[0],
+        firmware_version: u16::from_be_bytes([content[1], content[2]]),
+        uptime: u32::from_be_bytes([content[3], content[4], content[5], content[6]]),
+        received_queue_length: content[7],
+        send_queue_length: content[8],
+        transit_queue_length: content[9],
+        dropped_received_packets: u16::from_be_bytes([content[10], content[11]]),
+        dropped_transit_packets: u16::from_be_bytes([content[12], content[13]]),
+        neighbours: content[14],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::packet::PacketState;
+
+    fn make_report(source: u8) -> DiagnosticsReport {
+        DiagnosticsReport {
+            source: ExactAddressType::new(source).unwrap(),
+            uptime: 86_400,
+            protocol_version: PROTOCOL_VERSION,
+            firmware_version: 0x0102,
+            received_queue_length: 1,
+            send_queue_length: 2,
+            transit_queue_length: 3,
+            dropped_received_packets: 400,
+            dropped_transit_packets: 500,
+            neighbours: 6,
+        }
+    }
+
+    #[test]
+    fn report_is_sent_back_to_requesting_device() {
+        let request = Packet::new(
+            1,
+            2,
+            7,
+            3,
+            PacketState::DiagnosticsRequest,
+            true,
+            PacketDataBytes::new(),
+        );
+
+        let response = make_answer(&request, &make_report(2)).expect("Answer shall be made");
+
+        assert_eq!(response.get_spec_state(), PacketState::DiagnosticsResponse);
+        assert_eq!(response.source_device_identifier, 2);
+        assert!(response.is_destination_reached(ExactAddressType::new(1).unwrap().into()));
+        assert_eq!(response.get_id(), 7);
+        assert_eq!(parse_report(&response), Some(make_report(2)));
+    }
+
+    #[test]
+    fn truncated_report_is_ignored() {
+        let mut data = make_report_data(&make_report(2));
+        data.truncate(REPORT_SIZE - 1);
+        let response = Packet::new(2, 1, 7, 3, PacketState::DiagnosticsResponse, true, data);
+
+        assert!(parse_report(&response).is_none());
+    }
+}
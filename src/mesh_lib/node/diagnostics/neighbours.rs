@@ -0,0 +1,139 @@
+use heapless::Vec;
+
+use super::super::constants::{NEIGHBOURS_COUNT, NEIGHBOUR_EXPIRY_PERIOD};
+use super::super::packet::PacketState;
+use super::super::{ms, ExactAddressType, Packet};
+
+struct Neighbour {
+    address: ExactAddressType,
+    heard_at: ms,
+}
+
+/// Keeps devices, which were heard directly, without being forwarded.
+///
+/// Packets do not keep the address of the previous hop, so neighbours are
+/// recognised by their `LinkAck` packets only, which are never forwarded.
+/// Once the table is full - the neighbour, which was heard earliest, is replaced.
+pub struct Neighbours {
+    neighbours: Vec<Neighbour, NEIGHBOURS_COUNT>,
+}
+
+impl Neighbours {
+    pub fn new() -> Neighbours {
+        Neighbours {
+            neighbours: Vec::new(),
+        }
+    }
+
+    /// Remembers the source of the `heard` packet, in case
+    /// if it has been sent by the neighbour device.
+    pub fn record(&mut self, heard: &Packet, my_address: ExactAddressType, current_time: ms) {
+        if heard.get_spec_state() != PacketState::LinkAck
+            || heard.source_device_identifier == my_address.get()
+        {
+            return;
+        }
+        let address = match ExactAddressType::new(heard.source_device_identifier) {
+            Some(address) => address,
+            None => return,
+        };
+
+        if let Some(neighbour) = self
+            .neighbours
+            .iter_mut()
+            .find(|neighbour| neighbour.address == address)
+        {
+            neighbour.heard_at = current_time;
+            return;
+        }
+
+        let neighbour = Neighbour {
+            address,
+            heard_at: current_time,
+        };
+        if let Err(neighbour) = self.neighbours.push(neighbour) {
+            if let Some(earliest) = self
+                .neighbours
+                .iter_mut()
+                .max_by_key(|neighbour| current_time.wrapping_sub(neighbour.heard_at))
+            {
+                *earliest = neighbour;
+            }
+        }
+    }
+
+    /// Count of neighbours, which were heard during `NEIGHBOUR_EXPIRY_PERIOD`.
+    pub fn count(&self, current_time: ms) -> usize {
+        self.neighbours
+            .iter()
+            .filter(|neighbour| {
+                current_time.wrapping_sub(neighbour.heard_at) < NEIGHBOUR_EXPIRY_PERIOD
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::{GeneralAddressType, PacketDataBytes};
+
+    fn my_address() -> ExactAddressType {
+        ExactAddressType::new(1).unwrap()
+    }
+
+    fn make_packet(source: u8, state: PacketState) -> Packet {
+        Packet::new(
+            source,
+            GeneralAddressType::Broadcast.into(),
+            0,
+            1,
+            state,
+            false,
+            PacketDataBytes::new(),
+        )
+    }
+
+    #[test]
+    fn devices_are_counted_by_link_acknowledgements() {
+        let mut neighbours = Neighbours::new();
+
+        neighbours.record(&make_packet(2, PacketState::LinkAck), my_address(), 0);
+        neighbours.record(&make_packet(2, PacketState::LinkAck), my_address(), 10);
+        neighbours.record(&make_packet(3, PacketState::LinkAck), my_address(), 20);
+        // Forwarded packets and own ones tell nothing about neighbours.
+        neighbours.record(&make_packet(4, PacketState::Normal), my_address(), 30);
+        neighbours.record(&make_packet(1, PacketState::LinkAck), my_address(), 40);
+
+        assert_eq!(neighbours.count(50), 2);
+    }
+
+    #[test]
+    fn silent_neighbours_are_not_counted() {
+        let mut neighbours = Neighbours::new();
+
+        neighbours.record(&make_packet(2, PacketState::LinkAck), my_address(), 0);
+        neighbours.record(&make_packet(3, PacketState::LinkAck), my_address(), 500);
+
+        assert_eq!(neighbours.count(NEIGHBOUR_EXPIRY_PERIOD + 100), 1);
+    }
+
+    #[test]
+    fn earliest_heard_neighbour_is_replaced() {
+        let mut neighbours = Neighbours::new();
+        for address in 0..NEIGHBOURS_COUNT as u8 {
+            neighbours.record(
+                &make_packet(address + 2, PacketState::LinkAck),
+                my_address(),
+                address as ms,
+            );
+        }
+        neighbours.record(&make_packet(100, PacketState::LinkAck), my_address(), 100);
+
+        assert_eq!(neighbours.count(100), NEIGHBOURS_COUNT);
+        assert!(neighbours
+            .neighbours
+            .iter()
+            .all(|neighbour| neighbour.address.get() != 2));
+    }
+}
@@ -0,0 +1,127 @@
+use core::convert::Infallible;
+
+use super::constants::{MAILBOX_SIZE, PACKET_QUEUE_SIZE, RECEIVER_FILTER_REGISTRATION_SIZE};
+use super::packet::{Packet, PacketSerializedBytes, Serializer, PACKET_BYTES_SIZE};
+use super::receiver::packet_bytes_parser::PacketBytesParser;
+use super::{ms, ExactAddressType, Node, NodeConfig, SnifferConfig};
+
+/// Period of time, during which the node is updated after all bytes
+/// are heard, so it handles and sends out everything it has queued.
+const SETTLE_PERIOD: ms = 200;
+
+const LISTEN_PERIOD: ms = 10;
+
+/// Device, for which the node keeps the mailbox, so mailboxes are fuzzed too.
+const MAILBOX_OWNER: u8 = 2;
+
+/// Pushes `bytes` into the frame parser, as if they were heard from the ether.
+/// Returns count of parsed packets.
+///
+/// Panics if the parser gives out the packet with incorrect checksum.
+pub fn parse_frames(bytes: &[u8]) -> usize {
+    let mut parser = PacketBytesParser::new();
+    let mut parsed_count = 0;
+    for byte in bytes {
+        parser.push_byte(*byte);
+        if let Some(packet) = parser.get_packet() {
+            assert!(packet.is_checksum_correct());
+            parsed_count += 1;
+        }
+    }
+    parsed_count
+}
+
+/// Deserializes first `PACKET_BYTES_SIZE` of `bytes` as the packet.
+///
+/// Panics if serialization of the packet does not give the same bytes back.
+/// Missing bytes are expected to be deserialized as zeroes.
+pub fn deserialize_packet(bytes: &[u8]) {
+    let bytes: PacketSerializedBytes = bytes.iter().take(PACKET_BYTES_SIZE).copied().collect();
+    let serialized = <Packet as Serializer>::deserialize(bytes.clone()).serialized();
+
+    assert_eq!(serialized.len(), PACKET_BYTES_SIZE);
+    assert_eq!(&serialized[..bytes.len()], &bytes[..]);
+    assert!(serialized[bytes.len()..].iter().all(|byte| *byte == 0));
+}
+
+/// Feeds `bytes` into the node, as if they were heard from the ether,
+/// and updates the node for a while after that.
+/// Returns count of packets, which were heard and handled by the node.
+///
+/// Panics if the node handles the packet with incorrect checksum,
+/// or if it's queues, duplication filter, or mailboxes ever keep
+/// more entries, than their constants allow.
+pub fn update_node(bytes: &[u8]) -> usize {
+    let mut config = NodeConfig::new(ExactAddressType::new(1).unwrap(), LISTEN_PERIOD);
+    config.sniffer = Some(SnifferConfig {
+        forward_transit: true,
+    });
+    let mut node = Node::new(config);
+    let _ = node.register_mailbox(ExactAddressType::new(MAILBOX_OWNER).unwrap());
+    let mut interface = FuzzInterface { bytes };
+
+    let mut heard_count = 0;
+    let mut current_time: ms = 0;
+    let mut settle_time: ms = 0;
+
+    while settle_time < SETTLE_PERIOD {
+        let _ = node.update(&mut interface, current_time);
+        assert_memory_is_bounded(&node);
+
+        while let Some(sniffed) = node.sniff() {
+            assert!(sniffed.packet.is_checksum_correct());
+            heard_count += 1;
+        }
+        while node.receive().is_some() {}
+
+        current_time = current_time.wrapping_add(1);
+        if interface.bytes.is_empty() {
+            settle_time += 1;
+        }
+    }
+    heard_count
+}
+
+fn assert_memory_is_bounded(node: &Node) {
+    let (packet_queue_len, transit_queue_len) = node.transmitter.queue_lens();
+    assert!(packet_queue_len <= PACKET_QUEUE_SIZE);
+    assert!(transit_queue_len <= PACKET_QUEUE_SIZE);
+    assert!(node.received_packet_queue.len() <= PACKET_QUEUE_SIZE);
+    assert!(node.receiver.filter_len() <= RECEIVER_FILTER_REGISTRATION_SIZE);
+    assert!(node.mailboxes.lens().all(|len| len <= MAILBOX_SIZE));
+}
+
+/// Interface, which gives out bytes of the fuzzer, and drops written ones.
+struct FuzzInterface<'a> {
+    bytes: &'a [u8],
+}
+
+impl embedded_io::ErrorType for FuzzInterface<'_> {
+    type Error = Infallible;
+}
+
+impl embedded_io::ReadReady for FuzzInterface<'_> {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.bytes.is_empty())
+    }
+}
+
+impl embedded_io::Read for FuzzInterface<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let count = buf.len().min(self.bytes.len());
+        let (read, rest) = self.bytes.split_at(count);
+        buf[..count].copy_from_slice(read);
+        self.bytes = rest;
+        Ok(count)
+    }
+}
+
+impl embedded_io::Write for FuzzInterface<'_> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
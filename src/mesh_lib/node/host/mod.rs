@@ -0,0 +1,9 @@
+#[cfg(feature = "serial")]
+mod serial;
+mod tcp;
+mod udp;
+
+#[cfg(feature = "serial")]
+pub use serial::SerialInterface;
+pub use tcp::TcpInterface;
+pub use udp::UdpInterface;
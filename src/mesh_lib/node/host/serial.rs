@@ -0,0 +1,79 @@
+use std::boxed::Box;
+use std::io::{self, Read, Write};
+use std::time::Duration;
+
+use serialport::SerialPort;
+
+/// Time, during which writing into the port may be blocked,
+/// before it is considered as failed.
+const WRITE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Interface over serial port of the PC, like USB-UART adapter
+/// with the radio module being connected, or the pseudo terminal.
+///
+/// `main.rs`:
+/// ```
+/// let mut serial = SerialInterface::open("/dev/ttyUSB0", 9600)?;
+/// loop {
+///     let _ = mesh_node.update(&mut serial, current_time);
+/// }
+/// ```
+pub struct SerialInterface {
+    port: Box<dyn SerialPort>,
+}
+
+impl SerialInterface {
+    /// Opens the serial port at `path`, like `/dev/ttyUSB0` or `COM3`.
+    pub fn open(path: &str, baud_rate: u32) -> io::Result<SerialInterface> {
+        let port = serialport::new(path, baud_rate)
+            .timeout(WRITE_TIMEOUT)
+            .open()?;
+        Ok(SerialInterface::from_port(port))
+    }
+
+    /// Wraps already opened port.
+    pub fn from_port(port: Box<dyn SerialPort>) -> SerialInterface {
+        SerialInterface { port }
+    }
+}
+
+impl embedded_io::ErrorType for SerialInterface {
+    type Error = io::Error;
+}
+
+impl embedded_io::ReadReady for SerialInterface {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.port.bytes_to_read()? > 0)
+    }
+}
+
+impl embedded_io::Read for SerialInterface {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        match self.port.read(buf) {
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) =>
+            {
+                Ok(0)
+            }
+            result => result,
+        }
+    }
+}
+
+impl embedded_io::Write for SerialInterface {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        loop {
+            match self.port.write(buf) {
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                result => return result,
+            }
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.port.flush()
+    }
+}
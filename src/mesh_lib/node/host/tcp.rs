@@ -0,0 +1,135 @@
+use std::collections::VecDeque;
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+
+const READ_CHUNK_SIZE: usize = 256;
+
+/// Count of bytes, which are kept while the send buffer of the socket is full.
+const MAX_PENDING_BYTES: usize = 64 * 1024;
+
+/// Interface over TCP connection, which links two devices
+/// as if they were in the range of each other's radio.
+/// Is used to tunnel mesh segments, like the ones in different buildings,
+/// trough the LAN or the internet.
+/// Once the other side closes the connection, reading fails with
+/// `io::ErrorKind::UnexpectedEof`, and the interface is to be reconnected.
+/// While the send buffer of the socket is full, written bytes are kept
+/// and sent on the next update. Once `MAX_PENDING_BYTES` are kept, further
+/// writes are lost, as it happens with the radio, which is busy.
+///
+/// `main.rs`:
+/// ```
+/// let mut tunnel = TcpInterface::connect("gateway.local:47778")?;
+/// loop {
+///     let _ = mesh_node.update_interfaces(&mut [&mut radio, &mut tunnel], current_time);
+/// }
+/// ```
+pub struct TcpInterface {
+    stream: TcpStream,
+    received_bytes: VecDeque<u8>,
+    pending_bytes: VecDeque<u8>,
+}
+
+impl TcpInterface {
+    /// Connects to the interface, which waits in `accept` method.
+    pub fn connect<A: ToSocketAddrs>(address: A) -> io::Result<TcpInterface> {
+        TcpInterface::from_stream(TcpStream::connect(address)?)
+    }
+
+    /// Waits till the other interface connects to the `listener`.
+    pub fn accept(listener: &TcpListener) -> io::Result<TcpInterface> {
+        let (stream, _) = listener.accept()?;
+        TcpInterface::from_stream(stream)
+    }
+
+    fn from_stream(stream: TcpStream) -> io::Result<TcpInterface> {
+        stream.set_nodelay(true)?;
+        stream.set_nonblocking(true)?;
+        Ok(TcpInterface {
+            stream,
+            received_bytes: VecDeque::new(),
+            pending_bytes: VecDeque::new(),
+        })
+    }
+
+    /// Moves bytes, sent by the other side, into `received_bytes`.
+    fn receive_bytes(&mut self) -> io::Result<()> {
+        let mut chunk = [0u8; READ_CHUNK_SIZE];
+        loop {
+            match self.stream.read(&mut chunk) {
+                // Bytes, sent before the connection was closed, are read first.
+                Ok(0) if !self.received_bytes.is_empty() => return Ok(()),
+                // Connection is closed by the other side.
+                Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(size) => self.received_bytes.extend(&chunk[..size]),
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => return Ok(()),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => (),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Sends bytes, which did not fit into the send buffer of the socket before.
+    fn send_pending_bytes(&mut self) -> io::Result<()> {
+        while !self.pending_bytes.is_empty() {
+            let (bytes, _) = self.pending_bytes.as_slices();
+            match self.stream.write(bytes) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(size) => {
+                    self.pending_bytes.drain(..size);
+                }
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => return Ok(()),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => (),
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl embedded_io::ErrorType for TcpInterface {
+    type Error = io::Error;
+}
+
+impl embedded_io::ReadReady for TcpInterface {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        self.send_pending_bytes()?;
+        self.receive_bytes()?;
+        Ok(!self.received_bytes.is_empty())
+    }
+}
+
+impl embedded_io::Read for TcpInterface {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if self.received_bytes.is_empty() {
+            self.receive_bytes()?;
+        }
+        let count = buf.len().min(self.received_bytes.len());
+        for (slot, byte) in buf.iter_mut().zip(self.received_bytes.drain(..count)) {
+            *slot = byte;
+        }
+        Ok(count)
+    }
+}
+
+impl embedded_io::Write for TcpInterface {
+    /// Bytes are either sent, or kept till the next update, or lost as whole,
+    /// so the frame is never cut. Never returns `Ok(0)`, which would make
+    /// `write_all` to fail.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.send_pending_bytes()?;
+        if self.pending_bytes.len() + buf.len() <= MAX_PENDING_BYTES {
+            self.pending_bytes.extend(buf);
+            self.send_pending_bytes()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.send_pending_bytes()?;
+        if !self.pending_bytes.is_empty() {
+            return Err(io::ErrorKind::WouldBlock.into());
+        }
+        self.stream.flush()
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::VecDeque;
+use std::io;
+use std::net::{Ipv4Addr, SocketAddrV4, UdpSocket};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use socket2::{Domain, Protocol, Socket, Type};
+
+const MAX_DATAGRAM_SIZE: usize = 512;
+const SENDER_TAG_SIZE: usize = core::mem::size_of::<u32>();
+
+/// Distinguishes interfaces, created within the same process.
+static INTERFACES_CREATED: AtomicU32 = AtomicU32::new(0);
+
+/// Interface over UDP multicast group, which works as the software ether.
+/// Bytes written into one interface are heard by all other interfaces,
+/// which joined the same group, either on the same host, or within the LAN.
+/// Like the radio, the interface does not hear bytes written by itself.
+///
+/// `main.rs`:
+/// ```
+/// let mut ether = UdpInterface::new(SocketAddrV4::new(Ipv4Addr::new(239, 255, 77, 77), 47777))?;
+/// loop {
+///     let _ = mesh_node.update(&mut ether, current_time);
+/// }
+/// ```
+pub struct UdpInterface {
+    socket: UdpSocket,
+    group: SocketAddrV4,
+    sender_tag: [u8; SENDER_TAG_SIZE],
+    received_bytes: VecDeque<u8>,
+}
+
+impl UdpInterface {
+    /// Joins the multicast `group` on the default network interface.
+    /// Several interfaces may join the same group on the same host.
+    pub fn new(group: SocketAddrV4) -> io::Result<UdpInterface> {
+        let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+        socket.set_reuse_address(true)?;
+        #[cfg(unix)]
+        socket.set_reuse_port(true)?;
+        socket.bind(&SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, group.port()).into())?;
+        socket.join_multicast_v4(group.ip(), &Ipv4Addr::UNSPECIFIED)?;
+        socket.set_multicast_loop_v4(true)?;
+        socket.set_nonblocking(true)?;
+
+        Ok(UdpInterface {
+            socket: socket.into(),
+            group,
+            sender_tag: make_sender_tag().to_be_bytes(),
+            received_bytes: VecDeque::new(),
+        })
+    }
+
+    /// Moves datagrams of other interfaces into `received_bytes`.
+    fn receive_datagrams(&mut self) -> io::Result<()> {
+        let mut datagram = [0u8; MAX_DATAGRAM_SIZE];
+        loop {
+            match self.socket.recv_from(&mut datagram) {
+                Ok((size, _)) if size > SENDER_TAG_SIZE => {
+                    let (sender_tag, bytes) = datagram[..size].split_at(SENDER_TAG_SIZE);
+                    if sender_tag != self.sender_tag {
+                        self.received_bytes.extend(bytes);
+                    }
+                }
+                Ok(_) => (),
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => return Ok(()),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+fn make_sender_tag() -> u32 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.subsec_nanos())
+        .unwrap_or(0);
+    std::process::id().rotate_left(16)
+        ^ nanos
+        ^ INTERFACES_CREATED
+            .fetch_add(1, Ordering::Relaxed)
+            .wrapping_mul(0x9E37_79B9)
+}
+
+impl embedded_io::ErrorType for UdpInterface {
+    type Error = io::Error;
+}
+
+impl embedded_io::ReadReady for UdpInterface {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        self.receive_datagrams()?;
+        Ok(!self.received_bytes.is_empty())
+    }
+}
+
+impl embedded_io::Read for UdpInterface {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if self.received_bytes.is_empty() {
+            self.receive_datagrams()?;
+        }
+        let count = buf.len().min(self.received_bytes.len());
+        for (slot, byte) in buf.iter_mut().zip(self.received_bytes.drain(..count)) {
+            *slot = byte;
+        }
+        Ok(count)
+    }
+}
+
+impl embedded_io::Write for UdpInterface {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let size = buf.len().min(MAX_DATAGRAM_SIZE - SENDER_TAG_SIZE);
+        let mut datagram = [0u8; MAX_DATAGRAM_SIZE];
+        datagram[..SENDER_TAG_SIZE].copy_from_slice(&self.sender_tag);
+        datagram[SENDER_TAG_SIZE..SENDER_TAG_SIZE + size].copy_from_slice(&buf[..size]);
+        self.socket
+            .send_to(&datagram[..SENDER_TAG_SIZE + size], self.group)?;
+        Ok(size)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
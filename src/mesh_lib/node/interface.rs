@@ -0,0 +1,72 @@
+use core::convert::Infallible;
+
+/// Interface, trough which the node sends and receives bytes of packets.
+/// Is implemented for every driver, which implements `embedded_io`
+/// `ReadReady`, `Read` and `Write` traits, so drivers of different
+/// types can be passed together into `Node` `update_interfaces` method.
+pub trait Interface {
+    /// Reads already received bytes into `buf`.
+    /// Returns count of bytes being read.
+    fn read_bytes(&mut self, buf: &mut [u8]) -> usize;
+
+    /// Writes all the bytes into the interface.
+    fn write_bytes(&mut self, bytes: &[u8]);
+}
+
+impl<T> Interface for T
+where
+    T: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
+{
+    fn read_bytes(&mut self, buf: &mut [u8]) -> usize {
+        match self.read_ready() {
+            Ok(true) => self.read(buf).unwrap_or(0),
+            Ok(false) | Err(_) => 0,
+        }
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        let _ = self.write_all(bytes);
+    }
+}
+
+/// Writes bytes into every interface at once.
+/// So packets sent by the node are heard trough all of the interfaces.
+pub struct AllInterfaces<'a, 'b> {
+    interfaces: &'a mut [&'b mut dyn Interface],
+}
+
+impl<'a, 'b> AllInterfaces<'a, 'b> {
+    pub fn new(interfaces: &'a mut [&'b mut dyn Interface]) -> AllInterfaces<'a, 'b> {
+        AllInterfaces { interfaces }
+    }
+}
+
+impl embedded_io::ErrorType for AllInterfaces<'_, '_> {
+    type Error = Infallible;
+}
+
+impl embedded_io::Write for AllInterfaces<'_, '_> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        for interface in self.interfaces.iter_mut() {
+            interface.write_bytes(buf);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Bytes are read from each interface separately.
+impl embedded_io::Read for AllInterfaces<'_, '_> {
+    fn read(&mut self, _buf: &mut [u8]) -> Result<usize, Self::Error> {
+        Ok(0)
+    }
+}
+
+impl embedded_io::ReadReady for AllInterfaces<'_, '_> {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+}
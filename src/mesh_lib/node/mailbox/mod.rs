@@ -0,0 +1,227 @@
+use heapless::{Deque, Vec};
+
+use super::constants::{MAILBOXES_COUNT, MAILBOX_PACKET_EXPIRY_PERIOD, MAILBOX_SIZE};
+use super::{ms, ExactAddressType, Packet};
+
+struct MailboxEntry {
+    packet: Packet,
+    /// Is set during the next `update`, after the packet is stored.
+    stored_at: Option<ms>,
+}
+
+struct Mailbox {
+    owner: ExactAddressType,
+    entries: Deque<MailboxEntry, MAILBOX_SIZE>,
+}
+
+pub struct MailboxesLimitReached;
+
+/// Keeps packets, which are addressed to the sleeping devices,
+/// until those devices wake up and poll for them.
+///
+/// * Each mailbox keeps limited count of packets. Once the mailbox
+///   is full - the oldest packet is dropped.
+/// * Packets, which are not polled during `MAILBOX_PACKET_EXPIRY_PERIOD` - are dropped.
+///
+/// Mailboxes are fixed size, so the count of devices is limited by `MAILBOXES_COUNT`.
+pub struct Mailboxes {
+    mailboxes: Vec<Mailbox, MAILBOXES_COUNT>,
+}
+
+impl Mailboxes {
+    pub fn new() -> Mailboxes {
+        Mailboxes {
+            mailboxes: Vec::new(),
+        }
+    }
+
+    pub fn register(&mut self, owner: ExactAddressType) -> Result<(), MailboxesLimitReached> {
+        if self.mailboxes.iter().any(|mailbox| mailbox.owner == owner) {
+            return Ok(());
+        }
+        match self.mailboxes.push(Mailbox {
+            owner,
+            entries: Deque::new(),
+        }) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(MailboxesLimitReached),
+        }
+    }
+
+    /// Removes the mailbox with all it's packets.
+    pub fn unregister(&mut self, owner: ExactAddressType) {
+        self.mailboxes.retain(|mailbox| mailbox.owner != owner);
+    }
+
+    /// Tells if the packet is addressed to the device, which has the mailbox.
+    pub fn has_mailbox_for(&self, packet: &Packet) -> bool {
+        self.mailboxes
+            .iter()
+            .any(|mailbox| packet.is_destination_reached(mailbox.owner.into()))
+    }
+
+    /// Keeps the packet in the mailbox of it's destination device.
+    /// Returns the packet back, in case if there is no mailbox for it.
+    pub fn store(&mut self, packet: Packet) -> Result<(), Packet> {
+        let mailbox = match self
+            .mailboxes
+            .iter_mut()
+            .find(|mailbox| packet.is_destination_reached(mailbox.owner.into()))
+        {
+            Some(mailbox) => mailbox,
+            None => return Err(packet),
+        };
+
+        if mailbox.entries.is_full() {
+            mailbox.entries.pop_front();
+        }
+
+        let _ = mailbox.entries.push_back(MailboxEntry {
+            packet,
+            stored_at: None,
+        });
+        Ok(())
+    }
+
+    /// Takes the oldest packet out of the mailbox of the `owner`.
+    pub fn take(&mut self, owner: ExactAddressType) -> Option<Packet> {
+        self.mailboxes
+            .iter_mut()
+            .find(|mailbox| mailbox.owner == owner)
+            .and_then(|mailbox| mailbox.entries.pop_front())
+            .map(|entry| entry.packet)
+    }
+
+    /// Returns the packet, which was taken, but could not be sent,
+    /// back to the front of the mailbox of it's destination device.
+    /// It's expiry period is counted anew. Returns the packet back,
+    /// in case if there is no mailbox for it, or the mailbox is full.
+    pub fn put_back(&mut self, packet: Packet) -> Result<(), Packet> {
+        let mailbox = match self
+            .mailboxes
+            .iter_mut()
+            .find(|mailbox| packet.is_destination_reached(mailbox.owner.into()))
+        {
+            Some(mailbox) => mailbox,
+            None => return Err(packet),
+        };
+
+        match mailbox.entries.push_front(MailboxEntry {
+            packet,
+            stored_at: None,
+        }) {
+            Ok(()) => Ok(()),
+            Err(entry) => Err(entry.packet),
+        }
+    }
+
+    /// Counts of packets, kept by each of mailboxes.
+    #[cfg(feature = "fuzzing")]
+    pub fn lens(&self) -> impl Iterator<Item = usize> + '_ {
+        self.mailboxes.iter().map(|mailbox| mailbox.entries.len())
+    }
+
+    /// Stamps newly stored packets with `current_time` and drops expired packets.
+    pub fn update(&mut self, current_time: ms) {
+        for mailbox in self.mailboxes.iter_mut() {
+            for entry in mailbox.entries.iter_mut() {
+                entry.stored_at.get_or_insert(current_time);
+            }
+
+            while let Some(MailboxEntry {
+                stored_at: Some(stored_at),
+                ..
+            }) = mailbox.entries.front()
+            {
+                if current_time.wrapping_sub(*stored_at) <= MAILBOX_PACKET_EXPIRY_PERIOD {
+                    break;
+                }
+                mailbox.entries.pop_front();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::packet::{PacketDataBytes, PacketState};
+
+    fn address(address: u8) -> ExactAddressType {
+        ExactAddressType::new(address).unwrap()
+    }
+
+    fn make_packet(destination: u8, id: u8) -> Packet {
+        Packet::new(
+            1,
+            destination,
+            id,
+            1,
+            PacketState::Normal,
+            true,
+            PacketDataBytes::new(),
+        )
+    }
+
+    #[test]
+    fn packet_for_not_registered_device_is_returned_back() {
+        let mut mailboxes = Mailboxes::new();
+        assert!(mailboxes.store(make_packet(2, 0)).is_err());
+    }
+
+    #[test]
+    fn stored_packets_are_taken_in_order() {
+        let mut mailboxes = Mailboxes::new();
+        let _ = mailboxes.register(address(2));
+        assert!(mailboxes.store(make_packet(2, 0)).is_ok());
+        assert!(mailboxes.store(make_packet(2, 1)).is_ok());
+
+        assert_eq!(mailboxes.take(address(2)).map(|p| p.get_id()), Some(0));
+        assert_eq!(mailboxes.take(address(2)).map(|p| p.get_id()), Some(1));
+        assert!(mailboxes.take(address(2)).is_none());
+    }
+
+    #[test]
+    fn oldest_packet_is_dropped_from_full_mailbox() {
+        let mut mailboxes = Mailboxes::new();
+        let _ = mailboxes.register(address(2));
+        for id in 0..(MAILBOX_SIZE as u8 + 1) {
+            let _ = mailboxes.store(make_packet(2, id));
+        }
+        assert_eq!(mailboxes.take(address(2)).map(|p| p.get_id()), Some(1));
+    }
+
+    #[test]
+    fn packet_put_back_is_taken_first() {
+        let mut mailboxes = Mailboxes::new();
+        let _ = mailboxes.register(address(2));
+        let _ = mailboxes.store(make_packet(2, 0));
+        let _ = mailboxes.store(make_packet(2, 1));
+
+        let taken = mailboxes.take(address(2)).expect("Packet shall be kept");
+        assert!(mailboxes.put_back(taken).is_ok());
+        assert_eq!(mailboxes.take(address(2)).map(|p| p.get_id()), Some(0));
+    }
+
+    #[test]
+    fn expired_packets_are_dropped() {
+        let mut mailboxes = Mailboxes::new();
+        let _ = mailboxes.register(address(2));
+        let _ = mailboxes.store(make_packet(2, 0));
+        mailboxes.update(0);
+        let _ = mailboxes.store(make_packet(2, 1));
+        mailboxes.update(100);
+
+        mailboxes.update(MAILBOX_PACKET_EXPIRY_PERIOD + 1);
+        assert_eq!(mailboxes.take(address(2)).map(|p| p.get_id()), Some(1));
+    }
+
+    #[test]
+    fn mailboxes_count_is_limited() {
+        let mut mailboxes = Mailboxes::new();
+        for owner in 0..MAILBOXES_COUNT as u8 {
+            assert!(mailboxes.register(address(owner + 2)).is_ok());
+        }
+        assert!(mailboxes.register(address(100)).is_err());
+    }
+}
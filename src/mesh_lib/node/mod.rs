@@ -1,19 +1,97 @@
+#[cfg(feature = "addressing")]
+mod addressing;
+#[cfg(feature = "std")]
+mod capture;
+mod clock;
 mod constants;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+/// Entry points for fuzzing of the code, which handles bytes heard from the ether.
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
+#[cfg(feature = "std")]
+mod host;
+mod interface;
+#[cfg(feature = "mailbox")]
+mod mailbox;
 mod packet;
+#[cfg(feature = "parameters")]
+mod parameters;
+#[cfg(feature = "pubsub")]
+mod pubsub;
+mod random;
 mod receiver;
+#[cfg(feature = "reliable")]
+mod reliable;
 mod router;
+#[cfg(feature = "rpc")]
+mod rpc;
+#[cfg(feature = "sniffer")]
+mod sniffer;
+mod stats;
+#[cfg(feature = "time-sync")]
+mod time_sync;
 mod timer;
 mod transmitter;
 mod types;
 
+#[cfg(feature = "addressing")]
+pub use addressing::{AddressAcquisitionConfig, AddressAcquisitionMode, AddressCoordinatorConfig};
+#[cfg(feature = "std")]
+pub use capture::{CaptureInterface, CapturedFrame, Direction, PcapReader, PcapWriter};
+pub use clock::Clock;
+#[cfg(feature = "fugit")]
+pub use clock::FugitClock;
+#[cfg(feature = "std")]
+pub use clock::StdClock;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::{DiagnosticsReport, PROTOCOL_VERSION};
+#[cfg(feature = "serial")]
+pub use host::SerialInterface;
+#[cfg(feature = "std")]
+pub use host::{TcpInterface, UdpInterface};
+pub use interface::Interface;
 pub use packet::{
     ExactAddressType, GeneralAddressType, IdType, LifeTimeType, Packet, PacketDataBytes,
 };
 
+#[cfg(feature = "parameters")]
+pub use parameters::{
+    ParameterAccess, ParameterCallStatus, ParameterId, ParameterManagers, ParameterValue,
+    RemoteParametersConfig, CARRIER_SENSE_MAX_BACKOFF_PARAMETER,
+    CARRIER_SENSE_QUIET_WINDOW_PARAMETER, DEFAULT_LIFETIME_PARAMETER,
+    LISTEN_PERIOD_JITTER_PARAMETER, LISTEN_PERIOD_PARAMETER, TRANSIT_QUEUE_POLICY_PARAMETER,
+};
+#[cfg(feature = "pubsub")]
+pub use pubsub::{Publication, TopicId, TopicPayload};
+pub use random::{RandomNumberGenerator, XorShiftRng};
+#[cfg(feature = "reliable")]
+pub use reliable::DeliveryStatus;
 pub use router::PacketState;
-use types::PacketQueue;
+#[cfg(feature = "rpc")]
+pub use rpc::{RpcArguments, RpcCallStatus, RpcHandler, RpcMethodId, RpcPayload};
+#[cfg(feature = "sniffer")]
+pub use sniffer::{SniffedPacket, SnifferConfig};
+pub use stats::NodeStats;
+#[cfg(feature = "time-sync")]
+pub use time_sync::TimeSyncConfig;
+#[cfg(feature = "adaptive-listen-period")]
+pub use timer::AdaptiveListenPeriodConfig;
+#[cfg(feature = "carrier-sense")]
+pub use timer::CarrierSenseConfig;
+#[cfg(feature = "airtime-limit")]
+pub use transmitter::AirtimeLimitConfig;
+#[cfg(feature = "link-ack")]
+pub use transmitter::LinkAckConfig;
+pub use transmitter::QueuePolicy;
+use types::ReceivedPacketQueue;
 pub use types::{ms, NodeString};
 
+use self::constants::DEFAULT_LIFETIME;
+#[cfg(feature = "bridge")]
+use self::constants::MAX_INTERFACES_COUNT;
+#[cfg(feature = "adaptive-listen-period")]
+use self::constants::RECEIVER_CHANNEL_LOAD_WINDOW;
 use self::router::{RouteError, RouteResult, Router};
 
 /// The main and only structure of the library that brings API for
@@ -31,21 +109,106 @@ use self::router::{RouteError, RouteResult, Router};
 ///                             response back.
 /// * `broadcast` -             Sends the `data` to all devices. Call of this method does not provide any
 ///                             response back.
+/// * `send_reliable` -         Sends the `data` to exact device, and retransmits it till it is acknowledged.
+/// * `delivery_status` -       Tells the status of delivery of the packet, sent by `send_reliable`.
+/// * `register_rpc_handler` -  Registers the handler of the method, which other devices can call.
+/// * `unregister_rpc_handler` - Stops handling calls of the method.
+/// * `send_rpc_request` -      Calls the method of exact device, without blocking.
+/// * `rpc_call_status` -       Tells the status of the call, made by `send_rpc_request`, along with it's result.
+/// * `subscribe` -             Makes the device to receive values, published to the topic.
+/// * `unsubscribe` -           Stops receiving values of the topic.
+/// * `publish` -               Sends the value to all devices, subscribed to the topic.
+/// * `receive_publication` -   Returns the value, published to the topic, the device is subscribed to.
+/// * `retain_topic` -          Makes the device to keep the last value of the topic, as the broker.
+/// * `release_topic` -         Stops keeping the last value of the topic.
+/// * `request_retained` -      Asks broker devices for the last value of the topic.
+/// * `register_parameter` -    Exposes the parameter of the application to manager devices.
+/// * `parameter` -             Returns the current value of the parameter.
+/// * `set_parameter` -         Changes the value of the parameter locally.
+/// * `get_remote_parameter` -  Reads the parameter of exact device, without blocking.
+/// * `set_remote_parameter` -  Writes the parameter of exact device, without blocking.
+/// * `parameter_call_status` - Tells the status of the remote parameter call, along with the value.
+/// * `send_diagnostics_request` - Asks exact device for the report about it's health.
+/// * `receive_diagnostics` -   Returns the report, sent back by the device, asked by `send_diagnostics_request`.
 /// * `send_ping_pong` -        Sends the `data` to exact device, and the receiving device will
 ///                             be forsed to make answer back. The answer from receiving device
 ///                             may tell if sending was successful.
 /// * `send_with_transaction` - Sends the `data` to exact device, and the receiving device will
 ///                             be forsed to make answer back. The answer from receiving device
 ///                             will tell if sending was successful.
+/// * `register_mailbox` -      Makes the device to keep packets for the sleeping device.
+/// * `unregister_mailbox` -    Stops keeping packets for the sleeping device.
+/// * `poll_mailbox` -          Asks the parent device to send packets, kept for this device.
+/// * `receive_with_interface` - Same as `receive`, but tells the interface, the packet came from.
 /// * `update` -                Updates the state of the node. This method should be called in
 ///                             every loop iteration.
-pub struct Node {
+/// * `update_interfaces` -     Same as `update`, but for the node with multiple interfaces.
+/// * `network_time` -          Returns the time of the reference device of the network.
+/// * `address` -               Returns the address of the device, once it is acquired.
+/// * `probe_address` -         Checks if the address is already used by some device.
+/// * `take_address_conflict` - Tells if other device with the same address was heard.
+/// * `sniff` -                 Returns packets heard from the ether, in case if `sniffer` is configured.
+/// * `update_with_clock` -     Same as `update`, but takes the time from the `Clock`.
+/// * `next_wakeup` -           Tells the time, at which `update` method needs to be called next.
+/// * `stats` -                 Returns snapshot of the node's internal state.
+///
+/// Methods of optional subsystems are available only with cargo features
+/// of those subsystems, like `reliable` or `pubsub`, being turned on.
+/// The `Node` takes memory only for subsystems, which are turned on.
+///
+/// `R` is the source of random numbers, which is used to spread
+/// transmissions of the node in time. By default - built-in `XorShiftRng` is used.
+pub struct Node<R: RandomNumberGenerator = XorShiftRng> {
     transmitter: transmitter::Transmitter,
     receiver: receiver::Receiver,
     my_address: ExactAddressType,
+    default_lifetime: LifeTimeType,
     timer: timer::Timer,
-    received_packet_queue: PacketQueue,
+    received_packet_queue: ReceivedPacketQueue,
+    #[cfg(feature = "pubsub")]
+    publications: pubsub::PublicationQueue,
     router: Router,
+    #[cfg(feature = "mailbox")]
+    mailboxes: mailbox::Mailboxes,
+    #[cfg(feature = "reliable")]
+    reliable_sends: reliable::ReliableSends,
+    #[cfg(feature = "rpc")]
+    rpc_handlers: rpc::RpcHandlers,
+    #[cfg(feature = "rpc")]
+    rpc_calls: rpc::RpcCalls,
+    #[cfg(feature = "parameters")]
+    parameters: parameters::Parameters,
+    #[cfg(feature = "parameters")]
+    remote_parameters: Option<RemoteParametersConfig>,
+    #[cfg(feature = "diagnostics")]
+    diagnostics_reports: diagnostics::DiagnosticsQueue,
+    #[cfg(feature = "diagnostics")]
+    neighbours: diagnostics::Neighbours,
+    #[cfg(feature = "diagnostics")]
+    firmware_version: u16,
+    #[cfg(feature = "diagnostics")]
+    last_update_time: ms,
+    #[cfg(feature = "diagnostics")]
+    dropped_received_packets: u32,
+    #[cfg(feature = "diagnostics")]
+    dropped_transit_packets: u32,
+    #[cfg(feature = "time-sync")]
+    time_sync: Option<time_sync::TimeSync>,
+    #[cfg(feature = "addressing")]
+    address_acquirer: Option<addressing::AddressAcquirer>,
+    #[cfg(feature = "addressing")]
+    address_coordinator: Option<addressing::AddressCoordinator>,
+    #[cfg(feature = "addressing")]
+    address_prober: Option<addressing::AddressProber>,
+    #[cfg(feature = "addressing")]
+    address_conflicts: u32,
+    #[cfg(feature = "addressing")]
+    is_address_conflict_detected: bool,
+    #[cfg(feature = "sniffer")]
+    sniffer: Option<sniffer::Sniffer>,
+    #[cfg(feature = "carrier-sense")]
+    carrier_sense: Option<CarrierSenseConfig>,
+    rng: R,
 }
 
 /// Error that can be returned by `Node` `update` method.
@@ -67,6 +230,87 @@ impl core::fmt::Debug for SendError {
     }
 }
 
+/// Error that can be returned by `Node` `register_mailbox` method.
+#[cfg(feature = "mailbox")]
+pub enum MailboxError {
+    /// Case, when the device already keeps mailboxes
+    /// for the maximum count of sleeping devices.
+    MailboxesLimitReached,
+}
+
+#[cfg(feature = "mailbox")]
+impl core::fmt::Debug for MailboxError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MailboxError::MailboxesLimitReached => write!(f, "MailboxesLimitReached"),
+        }
+    }
+}
+
+/// Error that can be returned by `Node` `register_rpc_handler` method.
+#[cfg(feature = "rpc")]
+pub enum RpcError {
+    /// Case, when handlers are already registered
+    /// for the maximum count of methods.
+    HandlersLimitReached,
+}
+
+#[cfg(feature = "rpc")]
+impl core::fmt::Debug for RpcError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RpcError::HandlersLimitReached => write!(f, "HandlersLimitReached"),
+        }
+    }
+}
+
+/// Error that can be returned by `Node` `subscribe` or `retain_topic` method.
+#[cfg(feature = "pubsub")]
+pub enum PubSubError {
+    /// Case, when the device is already subscribed to, or retains
+    /// the maximum count of topics.
+    TopicsLimitReached,
+}
+
+#[cfg(feature = "pubsub")]
+impl core::fmt::Debug for PubSubError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PubSubError::TopicsLimitReached => write!(f, "TopicsLimitReached"),
+        }
+    }
+}
+
+/// Error that can be returned by `Node` `register_parameter` or `set_parameter` method.
+#[cfg(feature = "parameters")]
+pub enum ParameterError {
+    /// Case, when the application already exposes
+    /// the maximum count of parameters.
+    ParametersLimitReached,
+
+    /// Case, when the id belongs to parameters, exposed by the `Node` itself.
+    ReservedParameter,
+
+    /// Case, when the parameter is not exposed.
+    UnknownParameter,
+
+    /// Case, when the value has other type, than the parameter,
+    /// or is out of the allowed range.
+    InvalidValue,
+}
+
+#[cfg(feature = "parameters")]
+impl core::fmt::Debug for ParameterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParameterError::ParametersLimitReached => write!(f, "ParametersLimitReached"),
+            ParameterError::ReservedParameter => write!(f, "ReservedParameter"),
+            ParameterError::UnknownParameter => write!(f, "UnknownParameter"),
+            ParameterError::InvalidValue => write!(f, "InvalidValue"),
+        }
+    }
+}
+
 /// Errors, that may occur during the call
 /// of `Node` `send_with_transaction` or `send_ping_pong` method.
 pub enum SpecialSendError {
@@ -96,6 +340,10 @@ impl From<SendError> for SpecialSendError {
 }
 
 /// User-friendly `Node` configuration structure.
+///
+/// New optional features may add fields, so the structure
+/// is created only with `NodeConfig::new`.
+#[non_exhaustive]
 pub struct NodeConfig {
     /// Address of configurable device. Instance of `ExactAddressType`.
     pub device_address: ExactAddressType,
@@ -104,6 +352,127 @@ pub struct NodeConfig {
     /// milliseconds that configured device will listen for incoming packets
     /// before speaking back into the ether.
     pub listen_period: ms,
+
+    /// Instance of `ms` type. The upper bound of random period
+    /// in milliseconds, which is added to `listen_period` before
+    /// each speak. It prevents devices with same `listen_period` from
+    /// speaking into the ether simultaneously over and over again.
+    /// Zero value turns jitter off.
+    #[cfg(feature = "listen-period-jitter")]
+    pub listen_period_jitter: ms,
+
+    /// Seed of the built-in random numbers generator.
+    /// Is used by `Node::new` method. If not set - the address of
+    /// the device is used as the seed. Good choice is to use
+    /// unique identifier of the chip.
+    pub random_seed: Option<u32>,
+
+    /// Lifetime, which the application passes into send methods,
+    /// as returned by `Node` `default_lifetime` method. So the manager
+    /// device tunes, how far packets of this device travel.
+    pub default_lifetime: LifeTimeType,
+
+    /// Tells, which packet is dropped, once the transit queue is full.
+    /// See `QueuePolicy`.
+    pub transit_queue_policy: QueuePolicy,
+
+    /// Optional instance of `CarrierSenseConfig`. If set, the device
+    /// does not speak into the ether while it hears other devices
+    /// speaking, and postpones speaking for random period of time.
+    #[cfg(feature = "carrier-sense")]
+    pub carrier_sense: Option<CarrierSenseConfig>,
+
+    /// Optional instance of `AdaptiveListenPeriodConfig`. If set, the device
+    /// shortens it's `listen_period` while the ether is idle, and lengthens
+    /// it while the ether is heavily loaded.
+    #[cfg(feature = "adaptive-listen-period")]
+    pub adaptive_listen_period: Option<AdaptiveListenPeriodConfig>,
+
+    /// Optional instance of `AirtimeLimitConfig`. If set, the device
+    /// keeps it's packets in queues, while it's airtime budget is exhausted.
+    #[cfg(feature = "airtime-limit")]
+    pub airtime_limit: Option<AirtimeLimitConfig>,
+
+    /// Optional instance of `LinkAckConfig`. If set, the device
+    /// retransmits packets, which are not confirmed by the neighbour devices,
+    /// and confirms packets, which are addressed to it.
+    #[cfg(feature = "link-ack")]
+    pub link_ack: Option<LinkAckConfig>,
+
+    /// Optional instance of `TimeSyncConfig`. If set, the device
+    /// either broadcasts it's time as the reference one, or
+    /// estimates the time of the reference device.
+    #[cfg(feature = "time-sync")]
+    pub time_sync: Option<TimeSyncConfig>,
+
+    /// Optional instance of `AddressAcquisitionConfig`. If set, the device
+    /// acquires it's address by itself, instead of using `device_address`.
+    #[cfg(feature = "addressing")]
+    pub address_acquisition: Option<AddressAcquisitionConfig>,
+
+    /// Optional instance of `AddressCoordinatorConfig`. If set, the device
+    /// leases addresses to devices, which request them.
+    #[cfg(feature = "addressing")]
+    pub address_coordinator: Option<AddressCoordinatorConfig>,
+
+    /// Optional instance of `SnifferConfig`. If set, the device
+    /// exposes every packet it hears, including transit and duplicated ones.
+    #[cfg(feature = "sniffer")]
+    pub sniffer: Option<SnifferConfig>,
+
+    /// Optional instance of `RemoteParametersConfig`. If set, manager
+    /// devices read and write parameters of the device at runtime.
+    #[cfg(feature = "parameters")]
+    pub remote_parameters: Option<RemoteParametersConfig>,
+
+    /// Version of the firmware, which the device reports
+    /// in answer to diagnostics requests.
+    #[cfg(feature = "diagnostics")]
+    pub firmware_version: u16,
+}
+
+impl NodeConfig {
+    /// Creates configuration with all optional features being turned off.
+    /// Optional features can be turned on by assigning the fields afterwards:
+    ///
+    /// ```
+    /// let mut config = NodeConfig::new(ExactAddressType::new(1).unwrap(), 150 as ms);
+    /// config.carrier_sense = Some(CarrierSenseConfig {
+    ///     quiet_window: 20 as ms,
+    ///     max_backoff: 100 as ms,
+    /// });
+    /// ```
+    pub fn new(device_address: ExactAddressType, listen_period: ms) -> NodeConfig {
+        NodeConfig {
+            device_address,
+            listen_period,
+            #[cfg(feature = "listen-period-jitter")]
+            listen_period_jitter: 0,
+            random_seed: None,
+            default_lifetime: DEFAULT_LIFETIME,
+            transit_queue_policy: QueuePolicy::DropNewest,
+            #[cfg(feature = "carrier-sense")]
+            carrier_sense: None,
+            #[cfg(feature = "adaptive-listen-period")]
+            adaptive_listen_period: None,
+            #[cfg(feature = "airtime-limit")]
+            airtime_limit: None,
+            #[cfg(feature = "link-ack")]
+            link_ack: None,
+            #[cfg(feature = "time-sync")]
+            time_sync: None,
+            #[cfg(feature = "addressing")]
+            address_acquisition: None,
+            #[cfg(feature = "addressing")]
+            address_coordinator: None,
+            #[cfg(feature = "sniffer")]
+            sniffer: None,
+            #[cfg(feature = "parameters")]
+            remote_parameters: None,
+            #[cfg(feature = "diagnostics")]
+            firmware_version: 0,
+        }
+    }
 }
 
 impl Node {
@@ -111,22 +480,157 @@ impl Node {
     /// To initialize a `Node`, you need to provide `NodeConfig` with values:
     /// - `ExactAddressType`: Sets the device's identification address in the network. Multiple deivces can share same address in the same network.
     /// - `listen_period`: Sets period in milliseconds that determines how long the device will wait before transmitting packet to the network. It prevents network congestion.
-
+    /// - `listen_period_jitter`: Sets upper bound of random period, which is added to `listen_period` before each speak. Requires `listen-period-jitter` feature.
+    /// - `random_seed`: Optionally sets the seed of built-in random numbers generator.
+    /// - `default_lifetime`: Sets lifetime, which is returned by `default_lifetime` method.
+    /// - `transit_queue_policy`: Tells, which packet is dropped, once the transit queue is full. See `QueuePolicy`.
+    /// - `carrier_sense`: Optionally makes the device to listen before talk. See `CarrierSenseConfig`. Requires `carrier-sense` feature.
+    /// - `adaptive_listen_period`: Optionally makes the device to adjust `listen_period` to the load of the ether. See `AdaptiveListenPeriodConfig`. Requires `adaptive-listen-period` feature.
+    /// - `airtime_limit`: Optionally limits duty cycle of the device. See `AirtimeLimitConfig`. Requires `airtime-limit` feature.
+    /// - `link_ack`: Optionally makes the device to confirm and retransmit packets hop by hop. See `LinkAckConfig`. Requires `link-ack` feature.
+    /// - `time_sync`: Optionally synchronises the time of the device with the reference one. See `TimeSyncConfig`. Requires `time-sync` feature.
+    /// - `address_acquisition`: Optionally makes the device to acquire it's address by itself. See `AddressAcquisitionConfig`. Requires `addressing` feature.
+    /// - `address_coordinator`: Optionally makes the device to lease addresses to other devices. See `AddressCoordinatorConfig`. Requires `addressing` feature.
+    /// - `sniffer`: Optionally makes the device to expose every packet it hears. See `SnifferConfig`. Requires `sniffer` feature.
+    /// - `remote_parameters`: Optionally lets manager devices read and write parameters of the device. See `RemoteParametersConfig`. Requires `parameters` feature.
+    /// - `firmware_version`: Sets version of the firmware, which is reported to other devices. See `DiagnosticsReport`. Requires `diagnostics` feature.
+    ///
+    /// The node uses built-in `XorShiftRng` seeded with `random_seed`, or with the hardware id,
+    /// or with the device address.
+    /// To provide other source of random numbers - use `with_rng` method.
+    ///
     /// `main.rs`:
     /// ```
-    /// let mut mesh_node = Node::new(NodeConfig {
-    ///     device_address: ExactAddressType::new(1).unwrap(),
-    ///     listen_period: 150 as ms,
-    /// });
+    /// let mut mesh_node = Node::new(NodeConfig::new(
+    ///     ExactAddressType::new(1).unwrap(),
+    ///     150 as ms,
+    /// ));
     /// ```
     pub fn new(config: NodeConfig) -> Node {
+        #[cfg(feature = "addressing")]
+        let hardware_id = config
+            .address_acquisition
+            .as_ref()
+            .map(|acquisition| acquisition.hardware_id);
+        #[cfg(not(feature = "addressing"))]
+        let hardware_id = None;
+
+        let seed = match (config.random_seed, hardware_id) {
+            (Some(seed), _) => seed,
+            (None, Some(hardware_id)) => hardware_id,
+            (None, None) => config.device_address.get() as u32,
+        };
+        Node::with_rng(config, XorShiftRng::new(seed))
+    }
+}
+
+impl<R: RandomNumberGenerator> Node<R> {
+    /// With RNG Method
+    /// Same as `new` method, but uses provided `rng`
+    /// as the source of random numbers.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let mut mesh_node = Node::with_rng(
+    ///     NodeConfig::new(ExactAddressType::new(1).unwrap(), 150 as ms),
+    ///     HardwareRng::new(peripherals.RNG),
+    /// );
+    /// ```
+    #[cfg_attr(not(feature = "listen-period-jitter"), allow(unused_mut))]
+    pub fn with_rng(config: NodeConfig, mut rng: R) -> Node<R> {
+        #[cfg(feature = "adaptive-listen-period")]
+        let channel_load_window = match config.adaptive_listen_period {
+            Some(ref adaptive) => adaptive.load_window,
+            None => RECEIVER_CHANNEL_LOAD_WINDOW,
+        };
+
+        let timer = timer::Timer::new(config.listen_period);
+        #[cfg(feature = "adaptive-listen-period")]
+        let timer = match config.adaptive_listen_period {
+            Some(adaptive) => timer.with_adaptive_listen_period(adaptive),
+            None => timer,
+        };
+        #[cfg(feature = "listen-period-jitter")]
+        let mut timer = timer.with_listen_period_jitter(config.listen_period_jitter);
+        // Devices, which are powered on simultaneously - shall not speak simultaneously.
+        #[cfg(feature = "listen-period-jitter")]
+        timer.pick_listen_period(&mut rng);
+
+        #[cfg(feature = "addressing")]
+        let address_acquirer = config
+            .address_acquisition
+            .map(addressing::AddressAcquirer::new);
+        // Till the address is acquired - the tentative one is used.
+        #[cfg(feature = "addressing")]
+        let my_address = match address_acquirer {
+            Some(ref acquirer) => acquirer.current_address(),
+            None => config.device_address,
+        };
+        #[cfg(not(feature = "addressing"))]
+        let my_address = config.device_address;
+
         Node {
-            transmitter: transmitter::Transmitter::new(),
-            receiver: receiver::Receiver::new(),
-            my_address: config.device_address.clone(),
-            timer: timer::Timer::new(config.listen_period),
-            received_packet_queue: PacketQueue::new(),
-            router: Router::new(config.device_address.into()),
+            transmitter: transmitter::Transmitter::new(
+                config.transit_queue_policy,
+                #[cfg(feature = "airtime-limit")]
+                config.airtime_limit,
+                #[cfg(feature = "link-ack")]
+                config.link_ack,
+            ),
+            receiver: receiver::Receiver::new(
+                #[cfg(feature = "adaptive-listen-period")]
+                channel_load_window,
+            ),
+            my_address,
+            default_lifetime: config.default_lifetime,
+            timer,
+            received_packet_queue: ReceivedPacketQueue::new(),
+            #[cfg(feature = "pubsub")]
+            publications: pubsub::PublicationQueue::new(),
+            router: Router::new(my_address),
+            #[cfg(feature = "mailbox")]
+            mailboxes: mailbox::Mailboxes::new(),
+            #[cfg(feature = "reliable")]
+            reliable_sends: reliable::ReliableSends::new(),
+            #[cfg(feature = "rpc")]
+            rpc_handlers: rpc::RpcHandlers::new(),
+            #[cfg(feature = "rpc")]
+            rpc_calls: rpc::RpcCalls::new(),
+            #[cfg(feature = "parameters")]
+            parameters: parameters::Parameters::new(),
+            #[cfg(feature = "parameters")]
+            remote_parameters: config.remote_parameters,
+            #[cfg(feature = "diagnostics")]
+            diagnostics_reports: diagnostics::DiagnosticsQueue::new(),
+            #[cfg(feature = "diagnostics")]
+            neighbours: diagnostics::Neighbours::new(),
+            #[cfg(feature = "diagnostics")]
+            firmware_version: config.firmware_version,
+            #[cfg(feature = "diagnostics")]
+            last_update_time: 0,
+            #[cfg(feature = "diagnostics")]
+            dropped_received_packets: 0,
+            #[cfg(feature = "diagnostics")]
+            dropped_transit_packets: 0,
+            #[cfg(feature = "time-sync")]
+            time_sync: config.time_sync.map(time_sync::TimeSync::new),
+            #[cfg(feature = "addressing")]
+            address_acquirer,
+            #[cfg(feature = "addressing")]
+            address_coordinator: config
+                .address_coordinator
+                .map(addressing::AddressCoordinator::new),
+            #[cfg(feature = "addressing")]
+            address_prober: None,
+            #[cfg(feature = "addressing")]
+            address_conflicts: 0,
+            #[cfg(feature = "addressing")]
+            is_address_conflict_detected: false,
+            #[cfg(feature = "sniffer")]
+            sniffer: config.sniffer.map(sniffer::Sniffer::new),
+            #[cfg(feature = "carrier-sense")]
+            carrier_sense: config.carrier_sense,
+            rng,
         }
     }
 
@@ -182,7 +686,8 @@ impl Node {
     /// this device will listen for response. In case if no response was caught during that
     /// period of time, the method will return `Err(SpecialSendError::Timeout)`.
     ///
-    /// * `millis_provider` - Is the closure that returns current time in milliseconds,
+    /// * `millis_provider` - Is the instance of `Clock`, for example closure,
+    ///   that returns current time in milliseconds.
     ///
     /// * `interface_driver` - Is the instance of `embedded_serial::MutNonBlockingRx`
     ///                        and `MutBlockingTx` traits.
@@ -199,7 +704,7 @@ impl Node {
     ) -> Result<(), SpecialSendError>
     where
         I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
-        M: Fn() -> ms,
+        M: Clock,
     {
         self._special_send(
             data,
@@ -271,7 +776,8 @@ impl Node {
     /// In case if no response was caught during that period of time, the method will
     /// return `Err(SpecialSendError::Timeout)`.
     ///
-    /// * `millis_provider` - Is the closure that returns current time in milliseconds,
+    /// * `millis_provider` - Is the instance of `Clock`, for example closure,
+    ///   that returns current time in milliseconds.
     ///
     /// * `interface_driver` - Is the instance of `embedded_serial::MutNonBlockingRx`
     ///                        and `MutBlockingTx` traits.
@@ -288,7 +794,7 @@ impl Node {
     ) -> Result<(), SpecialSendError>
     where
         I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
-        M: Fn() -> ms,
+        M: Clock,
     {
         self._special_send(
             data,
@@ -315,9 +821,9 @@ impl Node {
     ) -> Result<(), SpecialSendError>
     where
         I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
-        M: Fn() -> ms,
+        M: Clock,
     {
-        let mut current_time = millis_provider();
+        let mut current_time = millis_provider.now();
         let start_time = current_time;
 
         while let Some(_) = self.receive() {} // Flush out all messages in the queuee.
@@ -358,7 +864,7 @@ impl Node {
                 return Ok(());
             }
 
-            current_time = millis_provider();
+            current_time = millis_provider.now();
         }
 
         Err(SpecialSendError::Timeout)
@@ -417,180 +923,1863 @@ impl Node {
         }
     }
 
-    /// Broadcast Method
-    /// Shares the message to all nodes in the network.
-    /// Distance of sharing is set by `lifetime` parameter.
-    /// It sends packet with destination address set as
-    /// `GeneralAddressType::BROADCAST`. Every device will treats `GeneralAddressType::Broadcast`
-    /// as it's own address, so they keep the message as received and transits copy of that message further.
+    /// Send Reliable Method
+    /// Sends the message to device with exact address in the network,
+    /// and the receiving device answers with small acknowledgement packet, which
+    /// has no content. In case if acknowledgement is not received in time - the
+    /// message is retransmitted during the call of `update` method.
+    /// Unlike `send_ping_pong` - this method does not block.
+    ///
+    /// Returns the delivery id, which can be passed into `delivery_status` method.
+    /// Receiving device may receive the message more than once, in case if
+    /// the acknowledgement was lost.
+    ///
     /// `main.rs`:
     /// ```
-    /// let _ = mesh_node.broadcast(
-    ///     message.into_bytes(), // data.
-    ///     10 as LifeTimeType,   // lifetime.
+    /// let delivery_id = mesh_node.send_reliable(
+    ///     message.into_bytes(),              // Content.
+    ///     ExactAddressType::new(2).unwrap(), // Send to device with address 2.
+    ///     10 as LifeTimeType,                // Let message travel 10 devices before being destroyed.
+    ///     500 as ms,                         // Wait 500 ms for acknowledgement.
+    ///     3,                                 // Retransmit up to 3 times.
     /// );
     /// ```
-    /// Sends the `data` to all devices.
     ///
-    /// * `data` - Is the instance of `PacketDataBytes`, which is just type alias of
-    /// heapless vector of bytes of special size. This size is configured in the
-    /// node/packet/config.rs file.
-    /// `Note!` That all devices should have same version of protocol flashed, in order to
-    /// be able to correctly to communicate with each other.
+    /// * `data`, `destination_device_identifier`, `lifetime` - same as in `send_to_exact` method.
     ///
-    /// * `lifetime` - is the instance of `LifeTimeType`. This value configures the count of
-    /// how many nodes - the packet will be able to pass. Also this value is provided
-    /// to void the ether being jammed by packets, that in theory might be echoed
-    /// by other nodes to the infinity...
-    /// Each device, once passes transit packet trough it - it reduces packet's lifetime.
-    pub fn broadcast(
+    /// * `ack_timeout` - Is the period of time in milliseconds, during which
+    ///   the acknowledgement is awaited. It shall cover listen periods of all devices
+    ///   on the way to the receiving device and back. The period is doubled
+    ///   after each retransmission.
+    ///
+    /// * `retries` - Is the count of retransmissions. It is limited by `RELIABLE_SEND_MAX_RETRIES`.
+    #[cfg(feature = "reliable")]
+    pub fn send_reliable(
         &mut self,
         data: PacketDataBytes,
+        destination_device_identifier: ExactAddressType,
         lifetime: LifeTimeType,
-    ) -> Result<(), SendError> {
-        match self._send(Packet::new(
+        ack_timeout: ms,
+        retries: u8,
+    ) -> Result<IdType, SendError> {
+        if !self.reliable_sends.has_free_slot() {
+            return Err(SendError::SendingQueueIsFull);
+        }
+
+        let mut packet = Packet::new(
             self.my_address.into(),
-            GeneralAddressType::Broadcast.into(),
+            destination_device_identifier.into(),
             0,
             lifetime,
-            PacketState::Normal,
+            PacketState::ReliableSend,
             true,
             data,
-        )) {
-            Ok(_) => Ok(()),
-            Err(err) => Err(err),
+        );
+
+        let delivery_id = self._send(packet.clone())?;
+        packet.set_id(delivery_id);
+
+        match self.reliable_sends.register(packet, ack_timeout, retries) {
+            Ok(()) => Ok(delivery_id),
+            Err(reliable::ReliableSendsLimitReached) => Err(SendError::SendingQueueIsFull),
         }
     }
 
-    fn _send(&mut self, packet: Packet) -> Result<IdType, SendError> {
-        match self.transmitter.send(packet) {
-            Ok(generated_packet_id) => Ok(generated_packet_id),
-            Err(transmitter::PacketQueueIsFull) => Err(SendError::SendingQueueIsFull),
-        }
+    /// Delivery Status Method
+    /// Tells the status of delivery of the packet, which was sent by
+    /// `send_reliable` method. Returns `None` in case if the delivery with
+    /// such id is not tracked anymore. Finished deliveries are kept,
+    /// till they are replaced by the new ones.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// match mesh_node.delivery_status(delivery_id) {
+    ///     Some(DeliveryStatus::Delivered) => ...,
+    ///     Some(DeliveryStatus::Failed) => ...,
+    ///     _ => ...,
+    /// }
+    /// ```
+    #[cfg(feature = "reliable")]
+    pub fn delivery_status(&self, delivery_id: IdType) -> Option<DeliveryStatus> {
+        self.reliable_sends.status(delivery_id)
     }
 
-    /// Receive Method
-    /// Optionally returns `PacketDataBytes` instance with data,
-    /// which has been send exactly to this device, or has been
-    /// `broadcast`ed trough all the network.
+    /// Register RPC Handler Method
+    /// Makes the device to answer requests of other devices, which call the `method`.
+    /// The `handler` takes arguments of the call, and returns the result, which is
+    /// sent back to the calling device. Handlers are plain functions, so the hardware,
+    /// like sensors, is reached by them trough statics.
+    /// Previously registered handler of the same method is replaced.
     ///
-    /// You can tell which type the packet is by matching `special_state` field of returned `Packet` instance.
-    /// Field contains value of `PacketState` enum.
+    /// Calls of methods, which have no handler - are answered with `RpcCallStatus::UnknownMethod`.
+    /// Requests, sent to all devices - are not answered.
     ///
     /// `main.rs`:
     /// ```
-    /// match mesh_node.receive() {
-    ///     Some(packet) => ...,
-    ///     Node => ....,
+    /// const READ_SENSOR: RpcMethodId = 1;
+    ///
+    /// fn read_sensor(arguments: &[u8]) -> Option<RpcPayload> {
+    ///     let channel = *arguments.first()?;
+    ///     let value: u16 = read_adc(channel)?;
+    ///     RpcPayload::from_slice(&value.to_be_bytes()).ok()
     /// }
+    ///
+    /// let _ = mesh_node.register_rpc_handler(READ_SENSOR, read_sensor);
     /// ```
-
-    pub fn receive(&mut self) -> Option<Packet> {
-        self.received_packet_queue.pop_front()
+    #[cfg(feature = "rpc")]
+    pub fn register_rpc_handler(
+        &mut self,
+        method: RpcMethodId,
+        handler: RpcHandler,
+    ) -> Result<(), RpcError> {
+        match self.rpc_handlers.register(method, handler) {
+            Ok(()) => Ok(()),
+            Err(rpc::RpcHandlersLimitReached) => Err(RpcError::HandlersLimitReached),
+        }
     }
 
-    /// Update Method
-    /// The most important method.
-    /// During call of `update` method - it does all internal work:
-    /// - routes packets trough the network
-    /// - transits packets that were sent to other devices
-    /// - handles `lifetime` of packets
-    /// - handles special packets like `ping` and `pong`, or any kind of transaction one.
-    /// - saves received packets that will be available trough `receive` method.
-    /// - sends packets, that are in the `send` queue.
+    /// Unregister RPC Handler Method
+    /// Stops handling calls of the `method`.
     ///
-    /// As the protocol relies on physical device - it is crucial to provide
-    /// driver for communication interface.
-    /// Also node shall know if it's the time to broadcast into the ether or not,
-    /// so for that purpose the closure that counts milliseconds since program start
-    /// is required.
+    /// `main.rs`:
+    /// ```
+    /// mesh_node.unregister_rpc_handler(READ_SENSOR);
+    /// ```
+    #[cfg(feature = "rpc")]
+    pub fn unregister_rpc_handler(&mut self, method: RpcMethodId) {
+        self.rpc_handlers.unregister(method);
+    }
+
+    /// Send RPC Request Method
+    /// Calls the `method` of the device with exact address in the network.
+    /// The called device answers with the result of it's handler of the method.
+    /// Unlike `send_ping_pong` - this method does not block.
     ///
-    /// Methods: `send_ping_pong`, `send_with_transaction` also relies on `millis_provider` closure and `interface_driver`.
-    /// With out call this method in a loop - the node will stop working.
+    /// Returns the request id, which can be passed into `rpc_call_status` method.
+    /// The response is told apart from responses to other calls by this id.
     ///
-    ///`main.rs`:
-    ///```
-    /// loop {
-    ///    let current_time = Instant::now()
-    ///        .duration_since(program_start_time)
-    ///        .as_millis() as ms;
+    /// `main.rs`:
+    /// ```
+    /// let request_id = mesh_node.send_rpc_request(
+    ///     READ_SENSOR,                              // Method.
+    ///     RpcArguments::from_slice(&[3]).unwrap(),  // Read sensor 3.
+    ///     ExactAddressType::new(2).unwrap(),        // Call device with address 2.
+    ///     10 as LifeTimeType,                       // Let request travel 10 devices before being destroyed.
+    ///     1000 as ms,                               // Wait 1000 ms for response.
+    /// );
+    /// ```
     ///
-    ///    let _ = mesh_node.update(&mut serial, current_time);
-    /// }
-    ///```
-
-    /// Does all necessary internal work of mesh node:
-    /// * Receives packets from ether, and manages their further life.
-    ///     ** Data that is addressed to other devices are going to be send back into ether.
-    ///     ** Data addressed to current device, will be unpacked and stored.
+    /// * `method` - Is the id of the method, which handler is registered on the called device.
     ///
-    /// * Call of this method also requires the general types to be passed in.
-    /// As the process relies onto timing countings and onto serial stream,
+    /// * `arguments` - Is the instance of `RpcArguments`, which is passed to the handler.
+    ///   It is one byte shorter than `PacketDataBytes`, because the request keeps the method id.
     ///
-    /// parameters:
-    /// * `interface_driver` - is instance of `MutNonBlockingRx` and `MutBlockingTx`
-    /// traits.
+    /// * `destination_device_identifier`, `lifetime` - same as in `send_to_exact` method.
     ///
-    /// * `current_time` - Is a closure which returns current time in milliseconds
-    /// since the start of the program.
-    pub fn update<I>(
+    /// * `timeout` - Is the period of time in milliseconds, during which
+    ///   the response is awaited. It shall cover listen periods of all devices
+    ///   on the way to the called device and back.
+    #[cfg(feature = "rpc")]
+    pub fn send_rpc_request(
         &mut self,
-        interface_driver: &mut I,
-        current_time: ms,
-    ) -> Result<(), NodeUpdateError>
-    where
-        I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
-    {
-        if self.timer.is_time_to_speak(current_time) {
-            self.transmitter.update(interface_driver);
-            self.timer.record_speak_time(current_time);
+        method: RpcMethodId,
+        arguments: RpcArguments,
+        destination_device_identifier: ExactAddressType,
+        lifetime: LifeTimeType,
+        timeout: ms,
+    ) -> Result<IdType, SendError> {
+        if !self.rpc_calls.has_free_slot() {
+            return Err(SendError::SendingQueueIsFull);
         }
-        self.receiver.update(current_time, interface_driver);
 
-        let packet_to_route = match self.receiver.receive(current_time) {
-            Some(packet_to_handle) => packet_to_handle,
-            None => return Ok(()),
-        };
+        let request_id = self._send(Packet::new(
+            self.my_address.into(),
+            destination_device_identifier.into(),
+            0,
+            lifetime,
+            PacketState::RpcRequest,
+            true,
+            rpc::make_request_data(method, &arguments),
+        ))?;
 
-        let (received_packet, transit_packet) = match self.router.route(packet_to_route) {
-            Ok(ok_case) => match ok_case {
-                RouteResult::ReceivedOnly(packet) => (Some(packet), None),
-                RouteResult::TransitOnly(transit) => (None, Some(transit)),
-                RouteResult::ReceivedAndTransit { received, transit } => {
-                    (Some(received), Some(transit))
-                }
-            },
-            Err(RouteError::PacketLifetimeEnded) => (None, None),
-            Err(RouteError::RespondToBroadcastAddressError) => (None, None),
-        };
+        match self.rpc_calls.register(
+            request_id,
+            destination_device_identifier,
+            method,
+            PacketState::RpcResponse,
+            timeout,
+        ) {
+            Ok(()) => Ok(request_id),
+            Err(rpc::RpcCallsLimitReached) => Err(SendError::SendingQueueIsFull),
+        }
+    }
 
-        let (mut is_receive_queue_full, mut is_transit_queue_full): (bool, bool) = (false, false);
+    /// RPC Call Status Method
+    /// Tells the status of the call, which was made by `send_rpc_request`
+    /// method, along with the result of the call, once it is answered.
+    /// Returns `None` in case if the call with such id is not tracked anymore,
+    /// or if it is the call of the parameter, made by `get_remote_parameter`
+    /// or `set_remote_parameter` method.
+    /// Finished calls are kept, till they are replaced by the new ones.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// match mesh_node.rpc_call_status(request_id) {
+    ///     Some(RpcCallStatus::Answered(result)) => ...,
+    ///     Some(RpcCallStatus::Pending) => ...,
+    ///     _ => ...,
+    /// }
+    /// ```
+    #[cfg(feature = "rpc")]
+    pub fn rpc_call_status(&self, request_id: IdType) -> Option<RpcCallStatus> {
+        self.rpc_calls.status(request_id, PacketState::RpcResponse)
+    }
 
-        if let Some(received_packet) = received_packet {
-            match self.received_packet_queue.push_back(received_packet) {
-                Ok(()) => (),
-                Err(_) => {
-                    is_receive_queue_full = true;
-                }
-            }
+    /// Subscribe Method
+    /// Makes the device to receive values, published to the `topic`
+    /// by other devices. Received values are taken with `receive_publication` method.
+    /// Values of other topics are only forwarded further into the network,
+    /// and do not get into the queues of the device.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// const TEMPERATURE: TopicId = 1;
+    ///
+    /// let _ = mesh_node.subscribe(TEMPERATURE);
+    /// ```
+    #[cfg(feature = "pubsub")]
+    pub fn subscribe(&mut self, topic: TopicId) -> Result<(), PubSubError> {
+        match self.router.topics_mut().subscribe(topic) {
+            Ok(()) => Ok(()),
+            Err(pubsub::TopicsLimitReached) => Err(PubSubError::TopicsLimitReached),
         }
+    }
 
-        if let Some(transit_packet) = transit_packet {
-            match self.transmitter.send_transit(transit_packet) {
-                Ok(_) => (),
-                Err(transmitter::PacketTransitQueueIsFull) => {
-                    is_transit_queue_full = true;
-                }
-            }
-        }
+    /// Unsubscribe Method
+    /// Stops receiving values of the `topic`.
+    /// Already received values are kept till they are taken.
+    #[cfg(feature = "pubsub")]
+    pub fn unsubscribe(&mut self, topic: TopicId) {
+        self.router.topics_mut().unsubscribe(topic);
+    }
 
-        if is_receive_queue_full || is_transit_queue_full {
-            return Err(NodeUpdateError {
-                is_receive_queue_full,
-                is_transit_queue_full,
-            });
-        } else {
+    /// Publish Method
+    /// Sends the `payload` to all devices, which are subscribed to the `topic`.
+    /// The value is spread the same way as by `broadcast` method,
+    /// but is delivered only to subscribed and broker devices.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let _ = mesh_node.publish(
+    ///     TEMPERATURE,                                // Topic.
+    ///     TopicPayload::from_slice(b"21.5").unwrap(), // Value.
+    ///     10 as LifeTimeType,                         // Let value travel 10 devices before being destroyed.
+    /// );
+    /// ```
+    ///
+    /// * `payload` - Is the instance of `TopicPayload`. It is one byte shorter
+    ///   than `PacketDataBytes`, because the packet keeps the topic id.
+    #[cfg(feature = "pubsub")]
+    pub fn publish(
+        &mut self,
+        topic: TopicId,
+        payload: TopicPayload,
+        lifetime: LifeTimeType,
+    ) -> Result<(), SendError> {
+        let packet = Packet::new(
+            self.my_address.into(),
+            GeneralAddressType::Broadcast.into(),
+            0,
+            lifetime,
+            PacketState::Publish,
+            true,
+            pubsub::make_publish_data(topic, &payload),
+        );
+        // Device does not hear itself, so it's own values are retained right away.
+        if let Some(publication) = pubsub::parse_publication(&packet) {
+            self.router.topics_mut().store(&publication);
+        }
+        match self._send(packet) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Receive Publication Method
+    /// Optionally returns the value, published to the topic,
+    /// which the device is subscribed to.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// while let Some(publication) = mesh_node.receive_publication() {
+    ///     if publication.topic == TEMPERATURE {
+    ///         show_temperature(&publication.payload);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "pubsub")]
+    pub fn receive_publication(&mut self) -> Option<Publication> {
+        self.publications.pop_front()
+    }
+
+    /// Retain Topic Method
+    /// Makes the device to act as the broker of the `topic`: to keep the last
+    /// value, published to it, and to send the value to devices, which ask for it
+    /// with `request_retained` method. So devices, which have joined the network
+    /// later, or have been sleeping - get the value without waiting for the next publication.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let _ = mesh_node.retain_topic(TEMPERATURE);
+    /// ```
+    #[cfg(feature = "pubsub")]
+    pub fn retain_topic(&mut self, topic: TopicId) -> Result<(), PubSubError> {
+        match self.router.topics_mut().retain(topic) {
+            Ok(()) => Ok(()),
+            Err(pubsub::TopicsLimitReached) => Err(PubSubError::TopicsLimitReached),
+        }
+    }
+
+    /// Release Topic Method
+    /// Stops keeping the last value of the `topic`. The kept value is dropped.
+    #[cfg(feature = "pubsub")]
+    pub fn release_topic(&mut self, topic: TopicId) {
+        self.router.topics_mut().release(topic);
+    }
+
+    /// Request Retained Method
+    /// Asks broker devices for the last value of the `topic`.
+    /// Each broker, which keeps the value - sends it back, and it is
+    /// taken with `receive_publication` method, with `is_retained` flag being set.
+    /// The device shall be subscribed to the `topic` to receive the value.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let _ = mesh_node.subscribe(TEMPERATURE);
+    /// let _ = mesh_node.request_retained(TEMPERATURE, 10 as LifeTimeType);
+    /// ```
+    ///
+    /// * `lifetime` - Is used both for the request and for the value, sent back.
+    #[cfg(feature = "pubsub")]
+    pub fn request_retained(
+        &mut self,
+        topic: TopicId,
+        lifetime: LifeTimeType,
+    ) -> Result<(), SendError> {
+        match self._send(Packet::new(
+            self.my_address.into(),
+            GeneralAddressType::Broadcast.into(),
+            0,
+            lifetime,
+            PacketState::RetainedRequest,
+            true,
+            pubsub::make_retained_request_data(topic, lifetime),
+        )) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Register Parameter Method
+    /// Exposes the parameter of the application, so it can be read, and
+    /// optionally written, by manager devices, listed in `RemoteParametersConfig`.
+    /// Previously registered parameter with the same id is replaced.
+    ///
+    /// Parameters of the `Node` itself, like `LISTEN_PERIOD_PARAMETER`,
+    /// are exposed without registration, and their ids can not be used.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// const REPORT_PERIOD: ParameterId = 1;
+    ///
+    /// let _ = mesh_node.register_parameter(
+    ///     REPORT_PERIOD,
+    ///     ParameterValue::Time(stored_report_period),
+    ///     ParameterAccess::ReadWrite,
+    /// );
+    /// ```
+    #[cfg(feature = "parameters")]
+    pub fn register_parameter(
+        &mut self,
+        parameter: ParameterId,
+        value: ParameterValue,
+        access: ParameterAccess,
+    ) -> Result<(), ParameterError> {
+        if parameter >= parameters::FIRST_NODE_PARAMETER {
+            return Err(ParameterError::ReservedParameter);
+        }
+        match self.parameters.register(parameter, value, access) {
+            Ok(()) => Ok(()),
+            Err(parameters::ParametersLimitReached) => Err(ParameterError::ParametersLimitReached),
+        }
+    }
+
+    /// Parameter Method
+    /// Returns the current value of the parameter, either registered by the
+    /// application, or exposed by the `Node` itself. So the application
+    /// reads values, which were written by manager devices.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// if let Some(ParameterValue::Time(report_period)) = mesh_node.parameter(REPORT_PERIOD) {
+    ///     ...
+    /// }
+    /// ```
+    #[cfg(feature = "parameters")]
+    pub fn parameter(&self, parameter: ParameterId) -> Option<ParameterValue> {
+        self.read_parameter(parameter).ok()
+    }
+
+    /// Set Parameter Method
+    /// Changes the value of the parameter locally. Read-only parameters
+    /// are changed too, so the application keeps them up to date.
+    /// The value shall have the same type, as the parameter has.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let _ = mesh_node.set_parameter(BATTERY_VOLTAGE, ParameterValue::U16(measure_voltage()));
+    /// ```
+    #[cfg(feature = "parameters")]
+    pub fn set_parameter(
+        &mut self,
+        parameter: ParameterId,
+        value: ParameterValue,
+    ) -> Result<(), ParameterError> {
+        match self.write_parameter(parameter, value, false) {
+            Ok(_) => Ok(()),
+            Err(parameters::ParameterFault::UnknownParameter) => {
+                Err(ParameterError::UnknownParameter)
+            }
+            Err(_) => Err(ParameterError::InvalidValue),
+        }
+    }
+
+    /// Get Remote Parameter Method
+    /// Reads the `parameter` of the device with exact address in the network.
+    /// This device shall be listed as manager in `RemoteParametersConfig`
+    /// of the called device.
+    ///
+    /// Returns the request id, which can be passed into `parameter_call_status` method.
+    /// Parameter calls share tracking slots with calls, made by `send_rpc_request` method.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let request_id = mesh_node.get_remote_parameter(
+    ///     LISTEN_PERIOD_PARAMETER,           // Parameter.
+    ///     ExactAddressType::new(2).unwrap(), // Read parameter of device with address 2.
+    ///     10 as LifeTimeType,                // Let request travel 10 devices before being destroyed.
+    ///     1000 as ms,                        // Wait 1000 ms for response.
+    /// );
+    /// ```
+    #[cfg(feature = "parameters")]
+    pub fn get_remote_parameter(
+        &mut self,
+        parameter: ParameterId,
+        destination_device_identifier: ExactAddressType,
+        lifetime: LifeTimeType,
+        timeout: ms,
+    ) -> Result<IdType, SendError> {
+        self.send_parameter_request(
+            parameters::ParameterOperation::Get(parameter),
+            destination_device_identifier,
+            lifetime,
+            timeout,
+        )
+    }
+
+    /// Set Remote Parameter Method
+    /// Writes the `parameter` of the device with exact address in the network.
+    /// The called device applies the value right away, and answers with
+    /// the applied value, which is told by `parameter_call_status` method.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let request_id = mesh_node.set_remote_parameter(
+    ///     LISTEN_PERIOD_PARAMETER,
+    ///     ParameterValue::Time(300),
+    ///     ExactAddressType::new(2).unwrap(),
+    ///     10 as LifeTimeType,
+    ///     1000 as ms,
+    /// );
+    /// ```
+    #[cfg(feature = "parameters")]
+    pub fn set_remote_parameter(
+        &mut self,
+        parameter: ParameterId,
+        value: ParameterValue,
+        destination_device_identifier: ExactAddressType,
+        lifetime: LifeTimeType,
+        timeout: ms,
+    ) -> Result<IdType, SendError> {
+        self.send_parameter_request(
+            parameters::ParameterOperation::Set(parameter, value),
+            destination_device_identifier,
+            lifetime,
+            timeout,
+        )
+    }
+
+    /// Parameter Call Status Method
+    /// Tells the status of the call, which was made by `get_remote_parameter`
+    /// or `set_remote_parameter` method, along with the value of the parameter.
+    /// Returns `None` in case if the call with such id is not tracked anymore,
+    /// or if it is the call, made by `send_rpc_request` method.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// match mesh_node.parameter_call_status(request_id) {
+    ///     Some(ParameterCallStatus::Done(value)) => ...,
+    ///     Some(ParameterCallStatus::Pending) => ...,
+    ///     _ => ...,
+    /// }
+    /// ```
+    #[cfg(feature = "parameters")]
+    pub fn parameter_call_status(&self, request_id: IdType) -> Option<ParameterCallStatus> {
+        self.rpc_calls
+            .status(request_id, PacketState::ParameterResponse)
+            .map(parameters::call_status_of)
+    }
+
+    /// Send Diagnostics Request Method
+    /// Asks exact device for the report about it's health: uptime, versions,
+    /// occupancy of queues, counters of dropped packets and count of neighbours.
+    /// The request is answered by the `Node` itself, so no code of the
+    /// application is needed on the asked device.
+    ///
+    /// The call does not block. The report is taken with `receive_diagnostics` method.
+    /// Requests are not retransmitted, so the device, which has not answered during
+    /// reasonable time, may be treated as unreachable.
+    ///
+    /// The report is sent back with the lifetime, left from the request, so the
+    /// `lifetime` shall be enough for the request to reach the device and for
+    /// the report to return, the same as for `send_ping_pong` method.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let _ = gateway_node.send_diagnostics_request(
+    ///     ExactAddressType::new(2).unwrap(), // Asked device.
+    ///     10 as LifeTimeType,                // Lifetime of the request and of the report.
+    /// );
+    /// ```
+    #[cfg(feature = "diagnostics")]
+    pub fn send_diagnostics_request(
+        &mut self,
+        destination_device_identifier: ExactAddressType,
+        lifetime: LifeTimeType,
+    ) -> Result<(), SendError> {
+        match self._send(Packet::new(
+            self.my_address.into(),
+            destination_device_identifier.into(),
+            0,
+            lifetime,
+            PacketState::DiagnosticsRequest,
+            true,
+            PacketDataBytes::new(),
+        )) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Receive Diagnostics Method
+    /// Optionally returns the report, sent back by the device,
+    /// which was asked by `send_diagnostics_request` method.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// while let Some(report) = gateway_node.receive_diagnostics() {
+    ///     if report.dropped_transit_packets > 0 {
+    ///         warn_about_congestion(report.source);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "diagnostics")]
+    pub fn receive_diagnostics(&mut self) -> Option<DiagnosticsReport> {
+        self.diagnostics_reports.pop_front()
+    }
+
+    /// Makes the report about the health of this device.
+    // Conversion of `ms` is useless, unless `time64` feature is turned on.
+    #[allow(clippy::useless_conversion)]
+    #[cfg(feature = "diagnostics")]
+    fn make_diagnostics_report(&self) -> DiagnosticsReport {
+        let stats = self.stats();
+        DiagnosticsReport {
+            source: self.my_address,
+            uptime: u32::try_from(self.last_update_time / 1000).unwrap_or(u32::MAX),
+            protocol_version: diagnostics::PROTOCOL_VERSION,
+            firmware_version: self.firmware_version,
+            received_queue_length: self.received_packet_queue.len() as u8,
+            send_queue_length: self.transmitter.queued_count() as u8,
+            transit_queue_length: self.transmitter.queued_transit_count() as u8,
+            dropped_received_packets: stats.dropped_received_packets.min(u16::MAX as u32) as u16,
+            dropped_transit_packets: stats.dropped_transit_packets.min(u16::MAX as u32) as u16,
+            neighbours: stats.neighbours.min(u8::MAX as u32) as u8,
+        }
+    }
+
+    #[cfg(feature = "parameters")]
+    fn send_parameter_request(
+        &mut self,
+        operation: parameters::ParameterOperation,
+        destination_device_identifier: ExactAddressType,
+        lifetime: LifeTimeType,
+        timeout: ms,
+    ) -> Result<IdType, SendError> {
+        if !self.rpc_calls.has_free_slot() {
+            return Err(SendError::SendingQueueIsFull);
+        }
+
+        let request_id = self._send(Packet::new(
+            self.my_address.into(),
+            destination_device_identifier.into(),
+            0,
+            lifetime,
+            PacketState::ParameterRequest,
+            true,
+            parameters::make_request_data(&operation),
+        ))?;
+
+        // Response is correlated with the call by the same means, as the RPC response.
+        match self.rpc_calls.register(
+            request_id,
+            destination_device_identifier,
+            operation.code(),
+            PacketState::ParameterResponse,
+            timeout,
+        ) {
+            Ok(()) => Ok(request_id),
+            Err(rpc::RpcCallsLimitReached) => Err(SendError::SendingQueueIsFull),
+        }
+    }
+
+    /// Broadcast Method
+    /// Shares the message to all nodes in the network.
+    /// Distance of sharing is set by `lifetime` parameter.
+    /// It sends packet with destination address set as
+    /// `GeneralAddressType::BROADCAST`. Every device will treats `GeneralAddressType::Broadcast`
+    /// as it's own address, so they keep the message as received and transits copy of that message further.
+    /// `main.rs`:
+    /// ```
+    /// let _ = mesh_node.broadcast(
+    ///     message.into_bytes(), // data.
+    ///     10 as LifeTimeType,   // lifetime.
+    /// );
+    /// ```
+    /// Sends the `data` to all devices.
+    ///
+    /// * `data` - Is the instance of `PacketDataBytes`, which is just type alias of
+    /// heapless vector of bytes of special size. This size is configured in the
+    /// node/packet/config.rs file.
+    /// `Note!` That all devices should have same version of protocol flashed, in order to
+    /// be able to correctly to communicate with each other.
+    ///
+    /// * `lifetime` - is the instance of `LifeTimeType`. This value configures the count of
+    /// how many nodes - the packet will be able to pass. Also this value is provided
+    /// to void the ether being jammed by packets, that in theory might be echoed
+    /// by other nodes to the infinity...
+    /// Each device, once passes transit packet trough it - it reduces packet's lifetime.
+    pub fn broadcast(
+        &mut self,
+        data: PacketDataBytes,
+        lifetime: LifeTimeType,
+    ) -> Result<(), SendError> {
+        match self._send(Packet::new(
+            self.my_address.into(),
+            GeneralAddressType::Broadcast.into(),
+            0,
+            lifetime,
+            PacketState::Normal,
+            true,
+            data,
+        )) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Register Mailbox Method
+    /// Makes this device to keep packets, addressed to the sleeping
+    /// `child_device_identifier` device, instead of sending them into the ether.
+    /// Kept packets are sent, once the sleeping device wakes up and
+    /// calls `poll_mailbox` method. This device shall be always on,
+    /// and shall be reachable by the sleeping device directly.
+    ///
+    /// Each mailbox keeps limited count of packets, the oldest packets are dropped
+    /// in case if the mailbox is full. Packets, which are not polled for a long
+    /// time - are dropped too.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let _ = mesh_node.register_mailbox(ExactAddressType::new(3).unwrap());
+    /// ```
+    #[cfg(feature = "mailbox")]
+    pub fn register_mailbox(
+        &mut self,
+        child_device_identifier: ExactAddressType,
+    ) -> Result<(), MailboxError> {
+        match self.mailboxes.register(child_device_identifier) {
+            Ok(()) => Ok(()),
+            Err(mailbox::MailboxesLimitReached) => Err(MailboxError::MailboxesLimitReached),
+        }
+    }
+
+    /// Unregister Mailbox Method
+    /// Stops keeping packets for the `child_device_identifier` device.
+    /// Already kept packets are dropped.
+    #[cfg(feature = "mailbox")]
+    pub fn unregister_mailbox(&mut self, child_device_identifier: ExactAddressType) {
+        self.mailboxes.unregister(child_device_identifier);
+    }
+
+    /// Poll Mailbox Method
+    /// Asks the `parent_device_identifier` device to send packets,
+    /// which it keeps for this device. Shall be called by the sleeping device
+    /// right after wake up. Then `update` method shall be called for a while
+    /// to receive the packets.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let _ = mesh_node.poll_mailbox(
+    ///     ExactAddressType::new(1).unwrap(), // Parent device.
+    ///     1 as LifeTimeType,                 // Parent device is the neighbour.
+    /// );
+    /// ```
+    pub fn poll_mailbox(
+        &mut self,
+        parent_device_identifier: ExactAddressType,
+        lifetime: LifeTimeType,
+    ) -> Result<(), SendError> {
+        match self._send(Packet::new(
+            self.my_address.into(),
+            parent_device_identifier.into(),
+            0,
+            lifetime,
+            PacketState::PollMailbox,
+            true,
+            PacketDataBytes::new(),
+        )) {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn _send(&mut self, packet: Packet) -> Result<IdType, SendError> {
+        // Packets for sleeping devices are kept till they are polled.
+        #[cfg(feature = "mailbox")]
+        if self.mailboxes.has_mailbox_for(&packet) {
+            let mut packet = packet;
+            let generated_packet_id = self.transmitter.generate_id();
+            packet.set_id(generated_packet_id);
+            let _ = self.mailboxes.store(packet);
+            return Ok(generated_packet_id);
+        }
+
+        match self.transmitter.send(packet) {
+            Ok(generated_packet_id) => Ok(generated_packet_id),
+            Err(transmitter::PacketQueueIsFull) => Err(SendError::SendingQueueIsFull),
+        }
+    }
+
+    /// Receive Method
+    /// Optionally returns `PacketDataBytes` instance with data,
+    /// which has been send exactly to this device, or has been
+    /// `broadcast`ed trough all the network.
+    ///
+    /// You can tell which type the packet is by matching `special_state` field of returned `Packet` instance.
+    /// Field contains value of `PacketState` enum.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// match mesh_node.receive() {
+    ///     Some(packet) => ...,
+    ///     Node => ....,
+    /// }
+    /// ```
+
+    pub fn receive(&mut self) -> Option<Packet> {
+        let received_packet = self.received_packet_queue.pop_front()?;
+        #[cfg(feature = "bridge")]
+        let (received_packet, _interface) = received_packet;
+        Some(received_packet)
+    }
+
+    /// Receive With Interface Method
+    /// Same as `receive` method, but also returns the index of the interface
+    /// in the slice, passed into `update_interfaces` method, trough which
+    /// the packet came. For the node, updated by `update` method - it is always 0.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// if let Some((packet, interface)) = mesh_node.receive_with_interface() {
+    ///     println!("Came trough {}", if interface == 0 { "LoRa" } else { "HC-12" });
+    /// }
+    /// ```
+    #[cfg(feature = "bridge")]
+    pub fn receive_with_interface(&mut self) -> Option<(Packet, usize)> {
+        self.received_packet_queue.pop_front()
+    }
+
+    /// Update Method
+    /// The most important method.
+    /// During call of `update` method - it does all internal work:
+    /// - routes packets trough the network
+    /// - transits packets that were sent to other devices
+    /// - handles `lifetime` of packets
+    /// - handles special packets like `ping` and `pong`, or any kind of transaction one.
+    /// - saves received packets that will be available trough `receive` method.
+    /// - sends packets, that are in the `send` queue.
+    ///
+    /// As the protocol relies on physical device - it is crucial to provide
+    /// driver for communication interface.
+    /// Also node shall know if it's the time to broadcast into the ether or not,
+    /// so for that purpose the current time in milliseconds since program start
+    /// is required. To take it from the `Clock` - use `update_with_clock` method.
+    ///
+    /// Methods: `send_ping_pong`, `send_with_transaction` also relies on `millis_provider` clock and `interface_driver`.
+    /// With out call this method in a loop - the node will stop working.
+    ///
+    ///`main.rs`:
+    ///```
+    /// loop {
+    ///    let current_time = Instant::now()
+    ///        .duration_since(program_start_time)
+    ///        .as_millis() as ms;
+    ///
+    ///    let _ = mesh_node.update(&mut serial, current_time);
+    /// }
+    ///```
+
+    /// Does all necessary internal work of mesh node:
+    /// * Receives packets from ether, and manages their further life.
+    ///     ** Data that is addressed to other devices are going to be send back into ether.
+    ///     ** Data addressed to current device, will be unpacked and stored.
+    ///
+    /// * Call of this method also requires the general types to be passed in.
+    /// As the process relies onto timing countings and onto serial stream,
+    ///
+    /// parameters:
+    /// * `interface_driver` - is instance of `MutNonBlockingRx` and `MutBlockingTx`
+    /// traits.
+    ///
+    /// * `current_time` - Is the current time in milliseconds
+    /// since the start of the program.
+    pub fn update<I>(
+        &mut self,
+        interface_driver: &mut I,
+        current_time: ms,
+    ) -> Result<(), NodeUpdateError>
+    where
+        I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
+    {
+        self._update(&mut [interface_driver as &mut dyn Interface], current_time)
+    }
+
+    /// Update Interfaces Method
+    /// Same as `update` method, but for the node, which has multiple
+    /// interfaces, like radio modules of different kinds. The node bridges them:
+    /// * Packets are received trough every interface. The packet heard trough
+    ///   several interfaces is handled only once.
+    /// * Packets are sent trough every interface, so transit packets
+    ///   are forwarded from one interface to the others.
+    ///
+    /// The same interfaces shall be passed in the same order on every call.
+    /// The node listens at most `MAX_INTERFACES_COUNT` (two) interfaces.
+    /// Passing more of them panics in debug builds. In release builds extra
+    /// interfaces are ignored, so the node does not speak where it does not listen.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// loop {
+    ///     let _ = mesh_node.update_interfaces(&mut [&mut lora, &mut hc12], current_time);
+    /// }
+    /// ```
+    #[cfg(feature = "bridge")]
+    pub fn update_interfaces(
+        &mut self,
+        interfaces: &mut [&mut dyn Interface],
+        current_time: ms,
+    ) -> Result<(), NodeUpdateError> {
+        debug_assert!(
+            interfaces.len() <= MAX_INTERFACES_COUNT,
+            "Node listens at most {} interfaces",
+            MAX_INTERFACES_COUNT
+        );
+        let interfaces_count = interfaces.len().min(MAX_INTERFACES_COUNT);
+        self._update(&mut interfaces[..interfaces_count], current_time)
+    }
+
+    fn _update(
+        &mut self,
+        interfaces: &mut [&mut dyn Interface],
+        current_time: ms,
+    ) -> Result<(), NodeUpdateError> {
+        #[cfg(feature = "diagnostics")]
+        {
+            self.last_update_time = current_time;
+        }
+        #[cfg(feature = "airtime-limit")]
+        self.transmitter.restore_airtime(current_time);
+
+        if self.timer.is_time_to_speak(&current_time) {
+            #[cfg(feature = "carrier-sense")]
+            if self.is_ether_busy(current_time) {
+                self.back_off(current_time);
+                return self.receive_from_interfaces(interfaces, current_time);
+            }
+            #[cfg(feature = "time-sync")]
+            self.stamp_time_sync(current_time);
+            let hop_period = self.hop_period();
+            self.transmitter.update(
+                &mut interface::AllInterfaces::new(interfaces),
+                current_time,
+                hop_period,
+            );
+            self.timer.record_speak_time(&current_time, &mut self.rng);
+        }
+        self.receive_from_interfaces(interfaces, current_time)
+    }
+
+    /// Reads bytes from the interfaces, and handles packets, built from them.
+    fn receive_from_interfaces(
+        &mut self,
+        interfaces: &mut [&mut dyn Interface],
+        current_time: ms,
+    ) -> Result<(), NodeUpdateError> {
+        self.receiver.update(current_time, interfaces);
+
+        #[cfg(feature = "adaptive-listen-period")]
+        if let Some(channel_load) = self.receiver.take_channel_load() {
+            self.timer.adapt_listen_period(&channel_load);
+        }
+
+        #[cfg(feature = "mailbox")]
+        self.mailboxes.update(current_time);
+        #[cfg(feature = "reliable")]
+        self.update_reliable_sends(current_time);
+        #[cfg(feature = "rpc")]
+        self.rpc_calls.update(current_time);
+        #[cfg(feature = "time-sync")]
+        self.update_time_sync(current_time);
+        #[cfg(feature = "addressing")]
+        self.update_address_acquisition(current_time);
+
+        #[cfg(any(
+            feature = "sniffer",
+            feature = "addressing",
+            feature = "diagnostics",
+            feature = "link-ack"
+        ))]
+        if let Some((duplicate, interface)) = self.receiver.take_heard_duplicate() {
+            self.handle_heard(&duplicate, interface, true, current_time);
+        }
+
+        let (packet_to_route, interface) = match self.receiver.receive(current_time) {
+            Some(packet_to_handle) => packet_to_handle,
+            None => return Ok(()),
+        };
+
+        #[cfg(any(
+            feature = "sniffer",
+            feature = "addressing",
+            feature = "diagnostics",
+            feature = "link-ack"
+        ))]
+        self.handle_heard(&packet_to_route, interface, false, current_time);
+
+        // Transit packets, made of the packet, addressed to this device,
+        // are answers of this device, rather than forwarded ones.
+        #[cfg(feature = "sniffer")]
+        let is_addressed_to_me = packet_to_route.is_destination_reached(self.my_address.into());
+
+        let (received_packet, transit_packet) = match self.router.route(packet_to_route) {
+            Ok(ok_case) => match ok_case {
+                RouteResult::ReceivedOnly(packet) => (Some(packet), None),
+                RouteResult::TransitOnly(transit) => (None, Some(transit)),
+                RouteResult::ReceivedAndTransit { received, transit } => {
+                    (Some(received), Some(transit))
+                }
+            },
+            Err(RouteError::PacketLifetimeEnded) => (None, None),
+            Err(RouteError::RespondToBroadcastAddressError) => (None, None),
+        };
+
+        let (mut is_receive_queue_full, mut is_transit_queue_full): (bool, bool) = (false, false);
+
+        let received_packet = match received_packet {
+            #[cfg(feature = "mailbox")]
+            Some(packet) if packet.get_spec_state() == PacketState::PollMailbox => {
+                self.release_mailbox(&packet);
+                None
+            }
+            #[cfg(feature = "time-sync")]
+            Some(packet) if packet.get_spec_state() == PacketState::TimeSync => {
+                if let Some(ref mut time_sync) = self.time_sync {
+                    time_sync.handle_sync(&packet, current_time);
+                }
+                None
+            }
+            #[cfg(feature = "addressing")]
+            Some(packet)
+                if matches!(
+                    packet.get_spec_state(),
+                    PacketState::AddressProbe
+                        | PacketState::AddressConflict
+                        | PacketState::AddressLeaseRequest
+                        | PacketState::AddressLease
+                ) =>
+            {
+                self.handle_address_packet(&packet, current_time);
+                None
+            }
+            #[cfg(feature = "reliable")]
+            Some(packet) if packet.get_spec_state() == PacketState::ReliableAck => {
+                self.reliable_sends.acknowledge(&packet);
+                None
+            }
+            #[cfg(feature = "rpc")]
+            Some(packet) if packet.get_spec_state() == PacketState::RpcRequest => {
+                // Response keeps the id of the request, as transit packets do.
+                if let Some(response) = self.rpc_handlers.answer(&packet) {
+                    is_transit_queue_full = self.transmitter.send_transit(response).is_err();
+                }
+                None
+            }
+            #[cfg(feature = "rpc")]
+            Some(packet) if packet.get_spec_state() == PacketState::RpcResponse => {
+                self.rpc_calls.handle_response(&packet);
+                None
+            }
+            #[cfg(feature = "parameters")]
+            Some(packet) if packet.get_spec_state() == PacketState::ParameterRequest => {
+                if let Some(response) = self.answer_parameter_request(&packet) {
+                    is_transit_queue_full = self.transmitter.send_transit(response).is_err();
+                }
+                None
+            }
+            #[cfg(feature = "parameters")]
+            Some(packet) if packet.get_spec_state() == PacketState::ParameterResponse => {
+                self.rpc_calls.handle_response(&packet);
+                None
+            }
+            #[cfg(feature = "diagnostics")]
+            Some(packet) if packet.get_spec_state() == PacketState::DiagnosticsRequest => {
+                // Report keeps the id of the request, as transit packets do.
+                let report = self.make_diagnostics_report();
+                if let Some(response) = diagnostics::make_answer(&packet, &report) {
+                    is_transit_queue_full = self.transmitter.send_transit(response).is_err();
+                }
+                None
+            }
+            #[cfg(feature = "diagnostics")]
+            Some(packet) if packet.get_spec_state() == PacketState::DiagnosticsResponse => {
+                if let Some(report) = diagnostics::parse_report(&packet) {
+                    is_receive_queue_full = self.diagnostics_reports.push_back(report).is_err();
+                }
+                None
+            }
+            #[cfg(feature = "pubsub")]
+            Some(packet) if packet.get_spec_state() == PacketState::Publish => {
+                if let Some(publication) = pubsub::parse_publication(&packet) {
+                    self.router.topics_mut().store(&publication);
+                    if self.router.topics().is_subscribed(publication.topic) {
+                        is_receive_queue_full = self.publications.push_back(publication).is_err();
+                    }
+                }
+                None
+            }
+            #[cfg(feature = "pubsub")]
+            Some(packet) if packet.get_spec_state() == PacketState::RetainedRequest => {
+                if let Some(answer) =
+                    pubsub::make_retained_answer(&packet, self.router.topics(), self.my_address)
+                {
+                    // Answer is sent on behalf of the requesting device,
+                    // so it is counted as the transit packet, once dropped.
+                    if self._send(answer).is_err() {
+                        self.count_dropped_transit_packet();
+                    }
+                }
+                None
+            }
+            // Packets, which are handled by the node itself, are not given to the
+            // application, even if the subsystem, which handles them, is turned off.
+            Some(packet) if !packet.get_spec_state().carries_application_data() => None,
+            received_packet => received_packet,
+        };
+
+        // Reference time, which can not be updated by this device,
+        // would be stale at the next hop.
+        let transit_packet = match transit_packet {
+            Some(packet)
+                if packet.get_spec_state() == PacketState::TimeSync
+                    && !self.is_network_time_known(current_time) =>
+            {
+                None
+            }
+            transit_packet => transit_packet,
+        };
+
+        // Monitoring device may keep the traffic of the network unchanged,
+        // but it still answers packets, addressed to it.
+        #[cfg(feature = "sniffer")]
+        let transit_packet = match self.sniffer {
+            Some(ref sniffer) if !sniffer.forwards_transit() && !is_addressed_to_me => None,
+            _ => transit_packet,
+        };
+
+        // Packets for sleeping devices are kept till they are polled.
+        #[cfg(feature = "mailbox")]
+        let transit_packet = match transit_packet {
+            Some(packet) => self.mailboxes.store(packet).err(),
+            None => None,
+        };
+
+        if let Some(received_packet) = received_packet {
+            #[cfg(feature = "bridge")]
+            let received_packet = (received_packet, interface);
+            // The node without `bridge` feature has the only interface.
+            #[cfg(not(feature = "bridge"))]
+            let _ = interface;
+            match self.received_packet_queue.push_back(received_packet) {
+                Ok(()) => (),
+                Err(_) => {
+                    is_receive_queue_full = true;
+                }
+            }
+        }
+
+        if let Some(transit_packet) = transit_packet {
+            match self.transmitter.send_transit(transit_packet) {
+                Ok(_) => (),
+                Err(transmitter::PacketTransitQueueIsFull) => {
+                    is_transit_queue_full = true;
+                }
+            }
+        }
+
+        if is_receive_queue_full {
+            self.count_dropped_received_packet();
+        }
+        if is_transit_queue_full {
+            self.count_dropped_transit_packet();
+        }
+
+        if is_receive_queue_full || is_transit_queue_full {
+            return Err(NodeUpdateError {
+                is_receive_queue_full,
+                is_transit_queue_full,
+            });
+        } else {
             Ok(())
         }
     }
+
+    /// Update With Clock Method
+    /// Same as `update` method, but takes current time from the `clock`,
+    /// so the time is provided the same way as for `send_ping_pong`
+    /// and `send_with_transaction` methods.
+    /// The clock is read once, and the same time is used by all parts
+    /// of the device, like it's timer and duplicate filter.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let clock = StdClock::new();
+    /// loop {
+    ///     let _ = mesh_node.update_with_clock(&mut serial, &clock);
+    /// }
+    /// ```
+    pub fn update_with_clock<I, C>(
+        &mut self,
+        interface_driver: &mut I,
+        clock: &C,
+    ) -> Result<(), NodeUpdateError>
+    where
+        I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
+        C: Clock,
+    {
+        self.update(interface_driver, clock.now())
+    }
+
+    /// Next Wakeup Method
+    /// Tells the time, at which `update` method needs to be called next,
+    /// so battery powered devices can sleep till that time, or till
+    /// the interface receives the data.
+    ///
+    /// It combines:
+    /// - the time to speak, in case if there are packets to be sent.
+    /// - restoration of airtime budget, in case if it is exhausted.
+    /// - expiration of duplicated packets filter entries.
+    /// - acknowledgement timeouts of packets, sent by `send_reliable` method.
+    /// - timeouts of calls, made by `send_rpc_request` method.
+    /// - link acknowledgement timeouts, in case if `link_ack` is configured.
+    /// - broadcast of the reference time, in case if the device is the reference one.
+    /// - probes and lease requests, in case if `address_acquisition` is configured.
+    ///
+    /// Returns `None` in case if there is nothing to wait for.
+    /// Returned time may be equal to `current_time`, which means, that the
+    /// `update` method shall be called without sleep.
+    /// `send_ping_pong` and `send_with_transaction` methods wait for
+    /// response by themselves, so they have no pending timeouts after return.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// loop {
+    ///     let _ = mesh_node.update(&mut serial, current_time);
+    ///
+    ///     match mesh_node.next_wakeup(current_time) {
+    ///         Some(wakeup_time) => sleep_till(wakeup_time), // Or till serial interrupt.
+    ///         None => sleep_till_interrupt(),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// parameters:
+    /// * `current_time` - Is current time in milliseconds
+    /// since the start of the program.
+    pub fn next_wakeup(&self, current_time: ms) -> Option<ms> {
+        let mut time_till_wakeup: Option<ms> = None;
+
+        let mut consider = |period: Option<ms>| {
+            if let Some(period) = period {
+                time_till_wakeup.replace(match time_till_wakeup {
+                    Some(time_till_wakeup) => time_till_wakeup.min(period),
+                    None => period,
+                });
+            }
+        };
+
+        #[cfg(feature = "airtime-limit")]
+        if self.transmitter.has_pending_packets() {
+            consider(
+                self.transmitter
+                    .time_till_airtime_available(current_time)
+                    .map(|till_airtime| {
+                        till_airtime.max(self.timer.time_till_speak(&current_time))
+                    }),
+            );
+        }
+        #[cfg(not(feature = "airtime-limit"))]
+        if self.transmitter.has_pending_packets() {
+            consider(Some(self.timer.time_till_speak(&current_time)));
+        }
+
+        consider(self.receiver.time_till_next_filter_expiration(current_time));
+        #[cfg(feature = "reliable")]
+        consider(self.reliable_sends.time_till_next_timeout(current_time));
+        #[cfg(feature = "rpc")]
+        consider(self.rpc_calls.time_till_next_timeout(current_time));
+        #[cfg(feature = "time-sync")]
+        consider(
+            self.time_sync
+                .as_ref()
+                .and_then(|time_sync| time_sync.time_till_broadcast(current_time)),
+        );
+        #[cfg(feature = "link-ack")]
+        consider(
+            self.transmitter
+                .time_till_link_retransmission(current_time)
+                .map(|till_retransmission| {
+                    till_retransmission.max(self.timer.time_till_speak(&current_time))
+                }),
+        );
+        #[cfg(feature = "addressing")]
+        consider(
+            self.address_acquirer
+                .as_ref()
+                .and_then(|acquirer| acquirer.time_till_next_action(current_time)),
+        );
+
+        time_till_wakeup.map(|time_till_wakeup| current_time.wrapping_add(time_till_wakeup))
+    }
+
+    /// Network Time Method
+    /// Returns the estimation of the time of the reference device
+    /// at `local_time`, so the timestamps made by different devices
+    /// can be compared with each other.
+    /// Reference device returns it's own time. Other devices return `None`
+    /// till the time of the reference device is received.
+    /// Requires `time_sync` to be configured, otherwise returns `None`.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// if let Some(network_time) = mesh_node.network_time(current_time) {
+    ///     measurement.timestamp = network_time;
+    /// }
+    /// ```
+    #[cfg(feature = "time-sync")]
+    pub fn network_time(&self, local_time: ms) -> Option<ms> {
+        self.time_sync
+            .as_ref()
+            .and_then(|time_sync| time_sync.network_time(local_time))
+    }
+
+    /// Default Lifetime Method
+    /// Returns the lifetime, which is configured by `default_lifetime` of
+    /// `NodeConfig`, or written remotely by the manager device.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let _ = mesh_node.send_to_exact(
+    ///     message.into_bytes(),
+    ///     ExactAddressType::new(2).unwrap(),
+    ///     mesh_node.default_lifetime(),
+    ///     true,
+    /// );
+    /// ```
+    pub fn default_lifetime(&self) -> LifeTimeType {
+        self.default_lifetime
+    }
+
+    /// Address Method
+    /// Returns the address of the device. In case if `address_acquisition`
+    /// is configured - returns `None` till the address is claimed or leased.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// if let Some(address) = mesh_node.address() {
+    ///     println!("Address: {}", address);
+    /// }
+    /// ```
+    pub fn address(&self) -> Option<ExactAddressType> {
+        #[cfg(feature = "addressing")]
+        if let Some(ref acquirer) = self.address_acquirer {
+            return acquirer.assigned_address();
+        }
+        Some(self.my_address)
+    }
+
+    /// Probe Address Method
+    /// Asks devices of the network, if any of them uses the `address`,
+    /// and waits for the answer till the `timeout`.
+    /// Returns `Ok(true)` in case if the address is used by some device.
+    /// Probing of own address tells, if other device has the same address.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// match mesh_node.probe_address(
+    ///     ExactAddressType::new(2).unwrap(), // Address to be checked.
+    ///     10 as LifeTimeType,                // Let the probe travel 10 devices.
+    ///     1000 as ms,                        // Wait for the answer for 1000 ms.
+    ///     &clock,
+    ///     &mut serial,
+    /// ) {
+    ///     Ok(true) => println!("Address is used"),
+    ///     Ok(false) => println!("Address is free"),
+    ///     Err(_) => println!("Probe was not sent"),
+    /// }
+    /// ```
+    #[cfg(feature = "addressing")]
+    pub fn probe_address<I, M>(
+        &mut self,
+        address: ExactAddressType,
+        lifetime: LifeTimeType,
+        timeout: ms,
+        millis_provider: M,
+        interface_driver: &mut I,
+    ) -> Result<bool, SendError>
+    where
+        I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
+        M: Clock,
+    {
+        let mut current_time = millis_provider.now();
+        let start_time = current_time;
+
+        let prober = addressing::AddressProber::new(address, self.rng.next_u32());
+        self._send(prober.make_probe_packet(self.my_address, lifetime))?;
+        self.address_prober.replace(prober);
+
+        while current_time.wrapping_sub(start_time) < timeout {
+            let _ = self.update(interface_driver, current_time);
+
+            if let Some(ref prober) = self.address_prober {
+                if prober.is_conflict_reported() {
+                    break;
+                }
+            }
+
+            current_time = millis_provider.now();
+        }
+
+        Ok(self
+            .address_prober
+            .take()
+            .is_some_and(|prober| prober.is_conflict_reported()))
+    }
+
+    /// Take Address Conflict Method
+    /// Tells if since the last call, a packet was heard, which was sent
+    /// by other device with the same address as this device has.
+    /// Count of such packets is kept in `NodeStats`.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// if mesh_node.take_address_conflict() {
+    ///     println!("Other device uses the same address");
+    /// }
+    /// ```
+    #[cfg(feature = "addressing")]
+    pub fn take_address_conflict(&mut self) -> bool {
+        core::mem::replace(&mut self.is_address_conflict_detected, false)
+    }
+
+    /// Sniff Method
+    /// Returns the oldest packet heard from the ether, in case if
+    /// `sniffer` is configured. Unlike `receive` method, it returns
+    /// packets addressed to other devices, and duplicated ones, as well.
+    /// Packets are kept in the limited queue, so the oldest ones
+    /// are dropped, in case if they are not taken in time.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// while let Some(sniffed) = mesh_node.sniff() {
+    ///     println!(
+    ///         "{} ms: from {} duplicate {}",
+    ///         sniffed.heard_at,
+    ///         sniffed.packet.source_device_identifier,
+    ///         sniffed.is_duplicate,
+    ///     );
+    /// }
+    /// ```
+    #[cfg(feature = "sniffer")]
+    pub fn sniff(&mut self) -> Option<SniffedPacket> {
+        self.sniffer.as_mut().and_then(|sniffer| sniffer.take())
+    }
+
+    /// Stats Method
+    /// Returns snapshot of the node's internal state, like
+    /// currently used listen period, observed load of the ether
+    /// and remaining airtime budget.
+    ///
+    /// `main.rs`:
+    /// ```
+    /// let stats = mesh_node.stats();
+    /// println!("Listen period: {} ms", stats.effective_listen_period);
+    /// ```
+    pub fn stats(&self) -> NodeStats {
+        NodeStats {
+            effective_listen_period: self.timer.listen_period(),
+            #[cfg(feature = "adaptive-listen-period")]
+            channel_load_bytes: self.receiver.channel_load().bytes_heard,
+            #[cfg(feature = "adaptive-listen-period")]
+            channel_load_duplicates: self.receiver.channel_load().duplicates_heard,
+            #[cfg(feature = "airtime-limit")]
+            remaining_airtime: self.transmitter.remaining_airtime(),
+            #[cfg(feature = "addressing")]
+            address_conflicts: self.address_conflicts,
+            #[cfg(feature = "diagnostics")]
+            dropped_received_packets: self.dropped_received_packets,
+            #[cfg(feature = "diagnostics")]
+            dropped_transit_packets: self.dropped_transit_packets,
+            #[cfg(feature = "diagnostics")]
+            neighbours: self.neighbours.count(self.last_update_time) as u32,
+        }
+    }
+
+    /// Lets subsystems know about the packet, heard from the ether,
+    /// including the duplicated one.
+    #[cfg(any(
+        feature = "sniffer",
+        feature = "addressing",
+        feature = "diagnostics",
+        feature = "link-ack"
+    ))]
+    #[allow(unused_variables)]
+    fn handle_heard(
+        &mut self,
+        heard: &Packet,
+        interface: usize,
+        is_duplicate: bool,
+        current_time: ms,
+    ) {
+        #[cfg(feature = "sniffer")]
+        if let Some(ref mut sniffer) = self.sniffer {
+            sniffer.record(heard, current_time, interface, is_duplicate);
+        }
+        #[cfg(feature = "addressing")]
+        self.detect_address_conflict(heard, current_time);
+        #[cfg(feature = "diagnostics")]
+        self.neighbours.record(heard, self.my_address, current_time);
+        #[cfg(feature = "link-ack")]
+        self.handle_link_ack(heard);
+    }
+
+    /// Confirms packets, which are sent by neighbours to this device,
+    /// and stops retransmission of packets, confirmed by neighbours.
+    #[cfg(feature = "link-ack")]
+    fn handle_link_ack(&mut self, heard: &Packet) {
+        if !self.transmitter.is_link_ack_enabled() {
+            return;
+        }
+
+        self.transmitter.acknowledge_link(heard);
+
+        if heard.get_spec_state() == PacketState::LinkAck
+            || !heard.is_destination_reached(self.my_address.into())
+        {
+            return;
+        }
+
+        let mut data = PacketDataBytes::new();
+        let _ = data.extend_from_slice(&[heard.source_device_identifier, heard.get_id()]);
+
+        // Link acknowledgement is heard by neighbours only.
+        let _ = self.transmitter.send_transit(Packet::new(
+            self.my_address.into(),
+            GeneralAddressType::Broadcast.into(),
+            0,
+            1,
+            PacketState::LinkAck,
+            false,
+            data,
+        ));
+    }
+
+    /// Broadcasts the reference time, once the sync period is over.
+    #[cfg(feature = "time-sync")]
+    fn update_time_sync(&mut self, current_time: ms) {
+        let packet = match self.time_sync {
+            Some(ref time_sync) if time_sync.time_till_broadcast(current_time) == Some(0) => {
+                time_sync.make_broadcast_packet(self.my_address)
+            }
+            _ => None,
+        };
+        if let Some(packet) = packet {
+            if self._send(packet).is_ok() {
+                if let Some(ref mut time_sync) = self.time_sync {
+                    time_sync.record_broadcast(current_time);
+                }
+            }
+        }
+    }
+
+    /// Tells if the time of the reference device is known,
+    /// so this device can update it in time sync packets.
+    #[cfg(feature = "time-sync")]
+    fn is_network_time_known(&self, current_time: ms) -> bool {
+        self.network_time(current_time).is_some()
+    }
+
+    #[cfg(not(feature = "time-sync"))]
+    fn is_network_time_known(&self, _current_time: ms) -> bool {
+        false
+    }
+
+    /// Sends probes and lease requests, once they are due.
+    #[cfg(feature = "addressing")]
+    fn update_address_acquisition(&mut self, current_time: ms) {
+        let packet = match self.address_acquirer {
+            Some(ref mut acquirer) => acquirer.update(current_time),
+            None => None,
+        };
+        if let Some(packet) = packet {
+            let _ = self._send(packet);
+        }
+        self.sync_address();
+    }
+
+    /// Answers probes of the address, which this device uses,
+    /// and lease requests, in case if this device is the coordinator.
+    #[cfg(feature = "addressing")]
+    fn handle_address_packet(&mut self, packet: &Packet, current_time: ms) {
+        if let Some(ref mut prober) = self.address_prober {
+            if prober.handle(packet) {
+                return;
+            }
+        }
+
+        let answer = match (packet.get_spec_state(), &mut self.address_coordinator) {
+            (PacketState::AddressLeaseRequest, Some(ref mut coordinator)) => {
+                coordinator.handle_request(packet, self.my_address, current_time)
+            }
+            (_, _) => match self.address_acquirer {
+                Some(ref mut acquirer) => acquirer.handle(packet, current_time),
+                None if packet.get_spec_state() == PacketState::AddressProbe => {
+                    addressing::make_conflict_packet(packet, self.my_address)
+                }
+                None => None,
+            },
+        };
+        if let Some(answer) = answer {
+            let _ = self._send(answer);
+        }
+        self.sync_address();
+    }
+
+    /// Reads or writes the parameter on behalf of the manager device.
+    /// Requests are not answered, in case if remote parameters are not configured.
+    #[cfg(feature = "parameters")]
+    fn answer_parameter_request(&mut self, request: &Packet) -> Option<Packet> {
+        let config = self.remote_parameters.as_ref()?;
+        let operation = parameters::parse_request(request)?;
+        let on_change = config.on_change;
+        let is_manager = config
+            .managers
+            .iter()
+            .any(|manager| manager.get() == request.source_device_identifier);
+
+        let result = match operation {
+            _ if !is_manager => Err(parameters::ParameterFault::Denied),
+            parameters::ParameterOperation::Get(parameter) => self.read_parameter(parameter),
+            parameters::ParameterOperation::Set(parameter, value) => {
+                let result = self.write_parameter(parameter, value, true);
+                if let (Ok(applied), Some(on_change)) = (result, on_change) {
+                    on_change(parameter, applied);
+                }
+                result
+            }
+        };
+        parameters::make_answer(request, result)
+    }
+
+    /// Returns the value of the parameter of the application, or of the `Node` itself.
+    #[cfg(feature = "parameters")]
+    fn read_parameter(
+        &self,
+        parameter: ParameterId,
+    ) -> Result<ParameterValue, parameters::ParameterFault> {
+        let period = match parameter {
+            DEFAULT_LIFETIME_PARAMETER => return Ok(ParameterValue::U8(self.default_lifetime)),
+            TRANSIT_QUEUE_POLICY_PARAMETER => {
+                return Ok(ParameterValue::U8(
+                    self.transmitter.transit_queue_policy().code(),
+                ))
+            }
+            LISTEN_PERIOD_PARAMETER => Some(self.timer.listen_period()),
+            #[cfg(feature = "listen-period-jitter")]
+            LISTEN_PERIOD_JITTER_PARAMETER => Some(self.timer.listen_period_jitter()),
+            #[cfg(feature = "carrier-sense")]
+            CARRIER_SENSE_QUIET_WINDOW_PARAMETER => self
+                .carrier_sense
+                .as_ref()
+                .map(|carrier_sense| carrier_sense.quiet_window),
+            #[cfg(feature = "carrier-sense")]
+            CARRIER_SENSE_MAX_BACKOFF_PARAMETER => self
+                .carrier_sense
+                .as_ref()
+                .map(|carrier_sense| carrier_sense.max_backoff),
+            _ => {
+                return self
+                    .parameters
+                    .get(parameter)
+                    .ok_or(parameters::ParameterFault::UnknownParameter)
+            }
+        };
+        match period {
+            // Periods, which do not fit into 32 bits, can not be sent.
+            Some(period) => {
+                ParameterValue::time_of(period).ok_or(parameters::ParameterFault::InvalidValue)
+            }
+            None => Err(parameters::ParameterFault::UnknownParameter),
+        }
+    }
+
+    /// Changes the value of the parameter, and returns the value, which was applied.
+    /// Read-only parameters of the application are changed only locally.
+    // Conversion of `ms` is useless, if `time64` feature is turned off.
+    #[allow(clippy::useless_conversion)]
+    #[cfg(feature = "parameters")]
+    fn write_parameter(
+        &mut self,
+        parameter: ParameterId,
+        value: ParameterValue,
+        is_remote: bool,
+    ) -> Result<ParameterValue, parameters::ParameterFault> {
+        if parameter < parameters::FIRST_NODE_PARAMETER {
+            match is_remote {
+                true => self.parameters.write(parameter, value)?,
+                false => self.parameters.set(parameter, value)?,
+            }
+            return self.read_parameter(parameter);
+        }
+
+        match (parameter, value) {
+            (DEFAULT_LIFETIME_PARAMETER, ParameterValue::U8(lifetime)) => {
+                self.default_lifetime = lifetime;
+                return self.read_parameter(parameter);
+            }
+            (TRANSIT_QUEUE_POLICY_PARAMETER, ParameterValue::U8(code)) => {
+                let policy =
+                    QueuePolicy::from_code(code).ok_or(parameters::ParameterFault::InvalidValue)?;
+                self.transmitter.set_transit_queue_policy(policy);
+                return self.read_parameter(parameter);
+            }
+            (DEFAULT_LIFETIME_PARAMETER | TRANSIT_QUEUE_POLICY_PARAMETER, _) => {
+                return Err(parameters::ParameterFault::InvalidValue)
+            }
+            _ => (),
+        }
+
+        // Other parameters of the `Node` itself are periods of time.
+        let period = match (self.read_parameter(parameter)?, value) {
+            (_, ParameterValue::Time(0)) if parameter == LISTEN_PERIOD_PARAMETER => {
+                return Err(parameters::ParameterFault::InvalidValue)
+            }
+            (ParameterValue::Time(_), ParameterValue::Time(period)) => ms::from(period),
+            _ => return Err(parameters::ParameterFault::InvalidValue),
+        };
+        match parameter {
+            LISTEN_PERIOD_PARAMETER => self.timer.set_listen_period(period),
+            #[cfg(feature = "listen-period-jitter")]
+            LISTEN_PERIOD_JITTER_PARAMETER => self.timer.set_listen_period_jitter(period),
+            #[cfg(feature = "carrier-sense")]
+            CARRIER_SENSE_QUIET_WINDOW_PARAMETER => {
+                if let Some(ref mut carrier_sense) = self.carrier_sense {
+                    carrier_sense.quiet_window = period;
+                }
+            }
+            #[cfg(feature = "carrier-sense")]
+            CARRIER_SENSE_MAX_BACKOFF_PARAMETER => {
+                if let Some(ref mut carrier_sense) = self.carrier_sense {
+                    carrier_sense.max_backoff = period;
+                }
+            }
+            _ => return Err(parameters::ParameterFault::UnknownParameter),
+        }
+        self.read_parameter(parameter)
+    }
+
+    /// Counts packets, which carry the address of this device
+    /// as the source one, but were not sent by this device.
+    /// Address probes are sent from the probed address, which is not used
+    /// by the probing device yet, so they are answered instead of being counted.
+    #[cfg(feature = "addressing")]
+    fn detect_address_conflict(&mut self, heard: &Packet, current_time: ms) {
+        if heard.source_device_identifier != self.my_address.get()
+            || heard.get_spec_state() == PacketState::AddressProbe
+            || self.transmitter.has_sent(heard, current_time)
+        {
+            return;
+        }
+        self.address_conflicts = self.address_conflicts.saturating_add(1);
+        self.is_address_conflict_detected = true;
+    }
+
+    /// Makes the device to use the address, chosen by the acquirer.
+    #[cfg(feature = "addressing")]
+    fn sync_address(&mut self) {
+        if let Some(ref acquirer) = self.address_acquirer {
+            let address = acquirer.current_address();
+            if address != self.my_address {
+                self.my_address = address;
+                self.router.set_current_device_identifier(address);
+            }
+        }
+    }
+
+    /// Writes the current time into the time sync packets,
+    /// right before the transmission. So the time spent in queues
+    /// of this device is taken into account.
+    #[cfg(feature = "time-sync")]
+    fn stamp_time_sync(&mut self, current_time: ms) {
+        let time_sync = match self.time_sync {
+            Some(ref time_sync) => time_sync,
+            None => return,
+        };
+        for packet in self
+            .transmitter
+            .queued_packets_mut()
+            .filter(|packet| packet.get_spec_state() == PacketState::TimeSync)
+        {
+            time_sync.stamp(packet, current_time);
+        }
+    }
+
+    /// Retransmits packets, which acknowledgement timed out.
+    #[cfg(feature = "reliable")]
+    fn update_reliable_sends(&mut self, current_time: ms) {
+        self.reliable_sends.update(current_time);
+
+        while !self.transmitter.is_queue_full() {
+            let (delivery_id, packet) = match self.reliable_sends.next_retransmission(current_time)
+            {
+                Some(retransmission) => retransmission,
+                None => return,
+            };
+            match self._send(packet) {
+                Ok(attempt_id) => self
+                    .reliable_sends
+                    .record_retransmission(delivery_id, attempt_id),
+                Err(SendError::SendingQueueIsFull) => return,
+            }
+        }
+    }
+
+    /// Sends packets, kept for the device, which has polled it's mailbox.
+    #[cfg(feature = "mailbox")]
+    fn release_mailbox(&mut self, poll_packet: &Packet) {
+        let owner = match ExactAddressType::new(poll_packet.source_device_identifier) {
+            Some(owner) => owner,
+            None => return,
+        };
+        // Packets, which do not fit into the transit queue, are kept
+        // in the mailbox till the next poll.
+        while !self.transmitter.is_transit_queue_full() {
+            let packet = match self.mailboxes.take(owner) {
+                Some(packet) => packet,
+                None => return,
+            };
+            if let Err(transmitter::PacketTransitQueueIsFull) =
+                self.transmitter.send_transit(packet.clone())
+            {
+                if self.mailboxes.put_back(packet).is_err() {
+                    self.count_dropped_transit_packet();
+                }
+                return;
+            }
+        }
+    }
+
+    /// Counts the packet, which did not fit into the queue of received ones.
+    fn count_dropped_received_packet(&mut self) {
+        #[cfg(feature = "diagnostics")]
+        {
+            self.dropped_received_packets = self.dropped_received_packets.saturating_add(1);
+        }
+    }
+
+    /// Counts the packet, which did not fit into the transit queue.
+    fn count_dropped_transit_packet(&mut self) {
+        #[cfg(feature = "diagnostics")]
+        {
+            self.dropped_transit_packets = self.dropped_transit_packets.saturating_add(1);
+        }
+    }
+
+    /// Period of time, for which neighbours are expected to hold the packet
+    /// before forwarding it. Neighbours are expected to listen about as long
+    /// as this device does.
+    #[cfg(feature = "listen-period-jitter")]
+    fn hop_period(&self) -> ms {
+        self.timer
+            .listen_period()
+            .saturating_add(self.timer.listen_period_jitter())
+    }
+
+    /// Period of time, for which neighbours are expected to hold the packet
+    /// before forwarding it. Neighbours are expected to listen about as long
+    /// as this device does.
+    #[cfg(not(feature = "listen-period-jitter"))]
+    fn hop_period(&self) -> ms {
+        self.timer.listen_period()
+    }
+
+    #[cfg(feature = "carrier-sense")]
+    fn is_ether_busy(&self, current_time: ms) -> bool {
+        match self.carrier_sense {
+            Some(ref carrier_sense) => self
+                .receiver
+                .is_ether_busy(current_time, carrier_sense.quiet_window),
+            None => false,
+        }
+    }
+
+    #[cfg(feature = "carrier-sense")]
+    fn back_off(&mut self, current_time: ms) {
+        let max_backoff = match self.carrier_sense {
+            Some(ref carrier_sense) => carrier_sense.max_backoff,
+            None => return,
+        };
+        let backoff = self.rng.next_period(max_backoff);
+        self.timer.back_off(&current_time, backoff);
+    }
 }
@@ -48,8 +48,92 @@ pub const INITIATE_TRANSACTION_FLAG: FlagsType = 0b00000100;
 /// transaction sender's device about that.
 pub const FINISH_TRANSACTION_FLAG: FlagsType = 0b00000010;
 
-// This flag is not used yet.
-// pub const NOT_USED_FLAG: FlagsType = 0b00000001;
+/// This flag tells, that the state of the packet is not kept
+/// as a single flag, but is kept as the code in the bits of
+/// other state flags. It allows to have more states, than
+/// there are bits in the flags field.
+pub const EXTENDED_STATE_FLAG: FlagsType = 0b00000001;
+
+/// Marker, which is mixed by XOR into the checksum of the packet
+/// with `EXTENDED_STATE_FLAG` being set.
+///
+/// The code of extended state is kept in the bits of state flags of
+/// version 2.1.x, so the device of that version would take the packet
+/// for ping, pong or transaction one. With the marker such device
+/// finds the checksum incorrect and drops the packet instead.
+/// The value is not of `2^n - 1` form, so a single flipped
+/// `EXTENDED_STATE_FLAG` bit still breaks the checksum.
+pub const EXTENDED_STATE_CHECKSUM_MARKER: ChecksumType = 0b01011010;
+
+/// Mask of bits, which keep the code of extended state.
+pub const EXTENDED_STATE_CODE_MASK: FlagsType = 0b01111110;
+
+/// Offset of bits, which keep the code of extended state.
+pub const EXTENDED_STATE_CODE_OFFSET: u32 = 1;
+
+/// Code of extended state, which tells, that the sleeping device
+/// has woken up, and asks it's parent device for packets,
+/// which were kept in the mailbox for it.
+pub const POLL_MAILBOX_STATE_CODE: FlagsType = 1;
+
+/// Code of extended state, which forces end-receiver device
+/// to acknowledge reception of the packet.
+pub const RELIABLE_SEND_STATE_CODE: FlagsType = 2;
+
+/// Code of extended state, which tells the sender device,
+/// that the packet was received by end-receiver device.
+pub const RELIABLE_ACK_STATE_CODE: FlagsType = 3;
+
+/// Code of extended state, which tells the previous hop device,
+/// that the packet was caught by the neighbour device.
+pub const LINK_ACK_STATE_CODE: FlagsType = 4;
+
+/// Code of extended state, which tells, that the packet
+/// keeps the time of the reference device.
+pub const TIME_SYNC_STATE_CODE: FlagsType = 5;
+
+/// Code of extended state, which asks the network,
+/// if the address is already used by other device.
+pub const ADDRESS_PROBE_STATE_CODE: FlagsType = 6;
+
+/// Code of extended state, which tells the probing device,
+/// that the address is already used.
+pub const ADDRESS_CONFLICT_STATE_CODE: FlagsType = 7;
+
+/// Code of extended state, which asks the coordinator device for the address.
+pub const ADDRESS_LEASE_REQUEST_STATE_CODE: FlagsType = 8;
+
+/// Code of extended state, which gives the address to the requesting device.
+pub const ADDRESS_LEASE_STATE_CODE: FlagsType = 9;
+
+/// Code of extended state, which asks end-receiver device
+/// to call the method, and to respond with it's result.
+pub const RPC_REQUEST_STATE_CODE: FlagsType = 10;
+
+/// Code of extended state, which keeps the result of the called method.
+pub const RPC_RESPONSE_STATE_CODE: FlagsType = 11;
+
+/// Code of extended state, which tells, that the packet
+/// keeps the value, published to the topic.
+pub const PUBLISH_STATE_CODE: FlagsType = 12;
+
+/// Code of extended state, which asks broker devices
+/// for the last value, published to the topic.
+pub const RETAINED_REQUEST_STATE_CODE: FlagsType = 13;
+
+/// Code of extended state, which asks end-receiver device
+/// to read or to write the parameter, and to respond with it's value.
+pub const PARAMETER_REQUEST_STATE_CODE: FlagsType = 14;
+
+/// Code of extended state, which keeps the value of the requested parameter.
+pub const PARAMETER_RESPONSE_STATE_CODE: FlagsType = 15;
+
+/// Code of extended state, which asks end-receiver device
+/// to respond with the report about it's health.
+pub const DIAGNOSTICS_REQUEST_STATE_CODE: FlagsType = 16;
+
+/// Code of extended state, which keeps the report about the health of the device.
+pub const DIAGNOSTICS_RESPONSE_STATE_CODE: FlagsType = 17;
 
 pub const ADDRESS_TYPE_SIZE: usize = size_of::<AddressType>();
 pub const ID_TYPE_SIZE: usize = size_of::<IdType>();
@@ -1,5 +1,6 @@
+use super::super::constants::EXTENDED_STATE_CHECKSUM_MARKER;
 use super::super::types::ChecksumType;
-use super::super::Packet;
+use super::super::{Packet, PacketFlagOps};
 
 impl Packet {
     /// Checks if the calculated checksum of the packet
@@ -16,6 +17,9 @@ impl Packet {
     ///      flags
     ///      data_length
     ///      data
+    ///
+    /// Checksum of the packet with extended state is mixed
+    /// with `EXTENDED_STATE_CHECKSUM_MARKER`.
     fn calculate_packet_sum(&self) -> ChecksumType {
         let mut result: ChecksumType = 0;
 
@@ -54,6 +58,10 @@ impl Packet {
             (result, _) = result.overflowing_add(*byte);
         }
 
+        if self.is_extended_state_flag_set() {
+            result ^= EXTENDED_STATE_CHECKSUM_MARKER;
+        }
+
         result
     }
 
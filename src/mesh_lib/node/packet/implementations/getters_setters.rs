@@ -1,4 +1,4 @@
-use super::super::types::IdType;
+use super::super::types::{AddressType, DataLengthType, IdType, LifeTimeType, PacketDataBytes};
 use super::super::Packet;
 
 impl Packet {
@@ -10,6 +10,26 @@ impl Packet {
         self.id = id;
     }
 
+    pub fn get_lifetime(&self) -> LifeTimeType {
+        self.lifetime
+    }
+
+    pub fn get_destination_device_identifier(&self) -> AddressType {
+        self.destination_device_identifier
+    }
+
+    /// Returns count of meaningful bytes in `data`, as it was told by the sender.
+    pub fn get_data_length(&self) -> usize {
+        self.data_length as usize
+    }
+
+    /// Replaces the content of the packet.
+    pub fn set_data(&mut self, mut data: PacketDataBytes) {
+        self.data_length = data.len() as DataLengthType;
+        let _ = data.resize(data.capacity(), b'\0');
+        self.data = data;
+    }
+
     pub fn increment_id(&mut self) {
         self.id = self.id.overflowing_add(1).0;
     }
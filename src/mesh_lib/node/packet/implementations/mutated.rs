@@ -27,11 +27,19 @@ impl Packet {
         }
     }
 
+    fn clear_data(&mut self) {
+        self.data.iter_mut().for_each(|byte| *byte = b'\0');
+        self.data_length = 0;
+    }
+
     pub fn mutated(mut self) -> Result<Self, RespondToBroadcastAddressError> {
         let old_state = self.get_spec_state().clone();
 
         match old_state {
-            PacketState::Ping => self.swap_source_destination()?,
+            PacketState::Ping
+            | PacketState::RpcRequest
+            | PacketState::ParameterRequest
+            | PacketState::DiagnosticsRequest => self.swap_source_destination()?,
             PacketState::SendTransaction | PacketState::InitTransaction => {
                 self.swap_source_destination()?;
             }
@@ -39,9 +47,39 @@ impl Packet {
                 self.increment_id();
                 self.swap_source_destination()?
             }
+            PacketState::ReliableSend => {
+                // Acknowledgement does not echo the content back.
+                self.swap_source_destination()?;
+                self.clear_data();
+            }
             _ => (),
         };
         self.set_spec_state(old_state.mutated());
         Ok(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::PacketDataBytes;
+    use super::*;
+
+    #[test]
+    fn reliable_send_is_acknowledged_without_content() {
+        let mut data = PacketDataBytes::new();
+        let _ = data.extend_from_slice(b"content");
+        let packet = Packet::new(1, 2, 7, 1, PacketState::ReliableSend, true, data);
+
+        let ack = match packet.mutated() {
+            Ok(ack) => ack,
+            Err(RespondToBroadcastAddressError) => panic!("Packet is addressed to exact device"),
+        };
+
+        assert!(ack.get_spec_state() == PacketState::ReliableAck);
+        assert_eq!(ack.source_device_identifier, 2);
+        assert_eq!(ack.destination_device_identifier, 1);
+        assert_eq!(ack.get_id(), 7);
+        assert_eq!(ack.data_length, 0);
+        assert!(ack.data.iter().all(|byte| *byte == 0));
+    }
+}
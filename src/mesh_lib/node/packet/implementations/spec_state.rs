@@ -1,7 +1,60 @@
+use super::super::constants::{
+    ADDRESS_CONFLICT_STATE_CODE, ADDRESS_LEASE_REQUEST_STATE_CODE, ADDRESS_LEASE_STATE_CODE,
+    ADDRESS_PROBE_STATE_CODE, DIAGNOSTICS_REQUEST_STATE_CODE, DIAGNOSTICS_RESPONSE_STATE_CODE,
+};
+use super::super::constants::{
+    EXTENDED_STATE_CODE_MASK, EXTENDED_STATE_CODE_OFFSET, LINK_ACK_STATE_CODE,
+    PARAMETER_REQUEST_STATE_CODE, PARAMETER_RESPONSE_STATE_CODE, POLL_MAILBOX_STATE_CODE,
+    PUBLISH_STATE_CODE, RELIABLE_ACK_STATE_CODE, RELIABLE_SEND_STATE_CODE,
+    RETAINED_REQUEST_STATE_CODE, RPC_REQUEST_STATE_CODE, RPC_RESPONSE_STATE_CODE,
+    TIME_SYNC_STATE_CODE,
+};
+use super::super::types::FlagsType;
 use super::super::{Packet, PacketFlagOps, PacketState};
 
+impl PacketState {
+    /// Tells if packets with this state are given to the application by
+    /// `Node` `receive` method. Packets of other states are handled
+    /// by the `Node` itself.
+    pub fn carries_application_data(&self) -> bool {
+        matches!(
+            self,
+            PacketState::Normal
+                | PacketState::Ping
+                | PacketState::Pong
+                | PacketState::SendTransaction
+                | PacketState::AcceptTransaction
+                | PacketState::InitTransaction
+                | PacketState::FinishTransaction
+                | PacketState::ReliableSend
+        )
+    }
+}
+
 impl Packet {
     pub fn get_spec_state(&self) -> PacketState {
+        if self.is_extended_state_flag_set() {
+            return match self.get_extended_state_code() {
+                POLL_MAILBOX_STATE_CODE => PacketState::PollMailbox,
+                RELIABLE_SEND_STATE_CODE => PacketState::ReliableSend,
+                RELIABLE_ACK_STATE_CODE => PacketState::ReliableAck,
+                LINK_ACK_STATE_CODE => PacketState::LinkAck,
+                TIME_SYNC_STATE_CODE => PacketState::TimeSync,
+                ADDRESS_PROBE_STATE_CODE => PacketState::AddressProbe,
+                ADDRESS_CONFLICT_STATE_CODE => PacketState::AddressConflict,
+                ADDRESS_LEASE_REQUEST_STATE_CODE => PacketState::AddressLeaseRequest,
+                ADDRESS_LEASE_STATE_CODE => PacketState::AddressLease,
+                RPC_REQUEST_STATE_CODE => PacketState::RpcRequest,
+                RPC_RESPONSE_STATE_CODE => PacketState::RpcResponse,
+                PUBLISH_STATE_CODE => PacketState::Publish,
+                RETAINED_REQUEST_STATE_CODE => PacketState::RetainedRequest,
+                PARAMETER_REQUEST_STATE_CODE => PacketState::ParameterRequest,
+                PARAMETER_RESPONSE_STATE_CODE => PacketState::ParameterResponse,
+                DIAGNOSTICS_REQUEST_STATE_CODE => PacketState::DiagnosticsRequest,
+                DIAGNOSTICS_RESPONSE_STATE_CODE => PacketState::DiagnosticsResponse,
+                _ => PacketState::Normal,
+            };
+        }
         if self.is_ping_flag_set() {
             return PacketState::Ping;
         }
@@ -34,7 +87,86 @@ impl Packet {
             PacketState::AcceptTransaction => self.set_accept_transaction_flag(true),
             PacketState::InitTransaction => self.set_initiate_transaction_flag(true),
             PacketState::FinishTransaction => self.set_finish_transaction_flag(true),
+            PacketState::PollMailbox => self.set_extended_state_code(POLL_MAILBOX_STATE_CODE),
+            PacketState::ReliableSend => self.set_extended_state_code(RELIABLE_SEND_STATE_CODE),
+            PacketState::ReliableAck => self.set_extended_state_code(RELIABLE_ACK_STATE_CODE),
+            PacketState::LinkAck => self.set_extended_state_code(LINK_ACK_STATE_CODE),
+            PacketState::TimeSync => self.set_extended_state_code(TIME_SYNC_STATE_CODE),
+            PacketState::AddressProbe => self.set_extended_state_code(ADDRESS_PROBE_STATE_CODE),
+            PacketState::AddressConflict => {
+                self.set_extended_state_code(ADDRESS_CONFLICT_STATE_CODE)
+            }
+            PacketState::AddressLeaseRequest => {
+                self.set_extended_state_code(ADDRESS_LEASE_REQUEST_STATE_CODE)
+            }
+            PacketState::AddressLease => self.set_extended_state_code(ADDRESS_LEASE_STATE_CODE),
+            PacketState::RpcRequest => self.set_extended_state_code(RPC_REQUEST_STATE_CODE),
+            PacketState::RpcResponse => self.set_extended_state_code(RPC_RESPONSE_STATE_CODE),
+            PacketState::Publish => self.set_extended_state_code(PUBLISH_STATE_CODE),
+            PacketState::RetainedRequest => {
+                self.set_extended_state_code(RETAINED_REQUEST_STATE_CODE)
+            }
+            PacketState::ParameterRequest => {
+                self.set_extended_state_code(PARAMETER_REQUEST_STATE_CODE)
+            }
+            PacketState::ParameterResponse => {
+                self.set_extended_state_code(PARAMETER_RESPONSE_STATE_CODE)
+            }
+            PacketState::DiagnosticsRequest => {
+                self.set_extended_state_code(DIAGNOSTICS_REQUEST_STATE_CODE)
+            }
+            PacketState::DiagnosticsResponse => {
+                self.set_extended_state_code(DIAGNOSTICS_RESPONSE_STATE_CODE)
+            }
         }
         self.set_ignore_duplication_flag(is_ignore_duplication_flag_set);
     }
+
+    fn get_extended_state_code(&self) -> FlagsType {
+        (self.flags & EXTENDED_STATE_CODE_MASK) >> EXTENDED_STATE_CODE_OFFSET
+    }
+
+    fn set_extended_state_code(&mut self, code: FlagsType) {
+        self.set_extended_state_flag(true);
+        self.flags |= (code << EXTENDED_STATE_CODE_OFFSET) & EXTENDED_STATE_CODE_MASK;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::packet::PacketDataBytes;
+
+    fn make_packet(spec_state: PacketState, ignore_duplications_flag: bool) -> Packet {
+        Packet::new(
+            1,
+            2,
+            0,
+            1,
+            spec_state,
+            ignore_duplications_flag,
+            PacketDataBytes::new(),
+        )
+    }
+
+    #[test]
+    fn extended_state_is_kept() {
+        let packet = make_packet(PacketState::PollMailbox, false);
+        assert!(packet.get_spec_state() == PacketState::PollMailbox);
+    }
+
+    #[test]
+    fn extended_state_keeps_ignore_duplication_flag() {
+        let packet = make_packet(PacketState::PollMailbox, true);
+        assert!(packet.is_ignore_duplication_flag_set());
+        assert!(packet.get_spec_state() == PacketState::PollMailbox);
+    }
+
+    #[test]
+    fn extended_state_is_replaced_by_single_flag_state() {
+        let mut packet = make_packet(PacketState::PollMailbox, true);
+        packet.set_spec_state(PacketState::Ping);
+        assert!(packet.get_spec_state() == PacketState::Ping);
+        assert!(packet.is_ignore_duplication_flag_set());
+    }
 }
@@ -1,5 +1,7 @@
 mod bitpos;
 mod constants;
+#[cfg(test)]
+mod proptests;
 mod types;
 
 pub mod implementations;
@@ -0,0 +1,260 @@
+extern crate std;
+
+use proptest::prelude::*;
+use std::vec::Vec;
+
+use super::{
+    AddressType, IdType, LifeTimeType, Packet, PacketDataBytes, PacketFlagOps,
+    PacketSerializedBytes, PacketState, Serializer, StateMutator, CONTENT_SIZE,
+};
+
+const ALL_STATES: [PacketState; 24] = [
+    PacketState::Normal,
+    PacketState::Ping,
+    PacketState::Pong,
+    PacketState::SendTransaction,
+    PacketState::AcceptTransaction,
+    PacketState::InitTransaction,
+    PacketState::FinishTransaction,
+    PacketState::PollMailbox,
+    PacketState::ReliableSend,
+    PacketState::ReliableAck,
+    PacketState::LinkAck,
+    PacketState::TimeSync,
+    PacketState::AddressProbe,
+    PacketState::AddressConflict,
+    PacketState::AddressLeaseRequest,
+    PacketState::AddressLease,
+    PacketState::RpcRequest,
+    PacketState::RpcResponse,
+    PacketState::Publish,
+    PacketState::RetainedRequest,
+    PacketState::ParameterRequest,
+    PacketState::ParameterResponse,
+    PacketState::DiagnosticsRequest,
+    PacketState::DiagnosticsResponse,
+];
+
+/// `PacketState` is generated as index in `ALL_STATES`,
+/// so failing cases are printed without `Debug` of packet types.
+fn any_state() -> impl Strategy<Value = usize> {
+    0..ALL_STATES.len()
+}
+
+fn any_data() -> impl Strategy<Value = Vec<u8>> {
+    proptest::collection::vec(any::<u8>(), 0..=CONTENT_SIZE)
+}
+
+fn make_packet(
+    source: AddressType,
+    destination: AddressType,
+    id: IdType,
+    lifetime: LifeTimeType,
+    state: usize,
+    ignore_duplications_flag: bool,
+    data: &[u8],
+) -> Packet {
+    Packet::new(
+        source,
+        destination,
+        id,
+        lifetime,
+        ALL_STATES[state].clone(),
+        ignore_duplications_flag,
+        PacketDataBytes::from_slice(data).unwrap(),
+    )
+}
+
+fn is_same(first: &Packet, second: &Packet) -> bool {
+    first.source_device_identifier == second.source_device_identifier
+        && first.destination_device_identifier == second.destination_device_identifier
+        && first.id == second.id
+        && first.lifetime == second.lifetime
+        && first.flags == second.flags
+        && first.data_length == second.data_length
+        && first.data == second.data
+        && first.checksum == second.checksum
+}
+
+/// Documented transitions of the answering device.
+fn answer_state(state: &PacketState) -> PacketState {
+    match state {
+        PacketState::Ping => PacketState::Pong,
+        PacketState::SendTransaction => PacketState::AcceptTransaction,
+        PacketState::AcceptTransaction => PacketState::InitTransaction,
+        PacketState::InitTransaction => PacketState::FinishTransaction,
+        PacketState::ReliableSend => PacketState::ReliableAck,
+        PacketState::RpcRequest => PacketState::RpcResponse,
+        PacketState::ParameterRequest => PacketState::ParameterResponse,
+        PacketState::DiagnosticsRequest => PacketState::DiagnosticsResponse,
+        other => other.clone(),
+    }
+}
+
+proptest! {
+    #[test]
+    fn serialized_packet_is_deserialized_back(
+        source in any::<AddressType>(),
+        destination in any::<AddressType>(),
+        id in any::<IdType>(),
+        lifetime in any::<LifeTimeType>(),
+        state in any_state(),
+        ignore_duplications_flag in any::<bool>(),
+        data in any_data(),
+    ) {
+        let packet = make_packet(
+            source, destination, id, lifetime, state, ignore_duplications_flag, &data,
+        )
+        .summarized();
+
+        let deserialized = <Packet as Serializer>::deserialize(packet.clone().serialized());
+
+        prop_assert!(is_same(&packet, &deserialized));
+        prop_assert!(deserialized.get_spec_state() == ALL_STATES[state]);
+        prop_assert_eq!(
+            deserialized.is_ignore_duplication_flag_set(),
+            ignore_duplications_flag
+        );
+        prop_assert_eq!(deserialized.data_length as usize, data.len());
+        prop_assert_eq!(&deserialized.data[..data.len()], &data[..]);
+    }
+
+    #[test]
+    fn summarized_packet_has_correct_checksum(
+        source in any::<AddressType>(),
+        destination in any::<AddressType>(),
+        id in any::<IdType>(),
+        lifetime in any::<LifeTimeType>(),
+        state in any_state(),
+        data in any_data(),
+    ) {
+        let packet = make_packet(source, destination, id, lifetime, state, true, &data);
+        prop_assert!(packet.summarized().is_checksum_correct());
+    }
+
+    #[test]
+    fn flipped_bit_breaks_checksum(
+        state in any_state(),
+        data in any_data(),
+        position in any::<prop::sample::Index>(),
+        bit in 0..8u8,
+    ) {
+        let mut bytes: PacketSerializedBytes =
+            make_packet(1, 2, 3, 4, state, true, &data).summarized().serialized();
+        let position = position.index(bytes.len());
+        bytes[position] ^= 1 << bit;
+
+        prop_assert!(!<Packet as Serializer>::deserialize(bytes).is_checksum_correct());
+    }
+
+    #[test]
+    fn extended_state_packet_has_incorrect_checksum_for_old_devices(
+        state in any_state(),
+        data in any_data(),
+    ) {
+        let packet = make_packet(1, 2, 3, 4, state, true, &data).summarized();
+        let is_extended = packet.is_extended_state_flag_set();
+        let bytes: PacketSerializedBytes = packet.serialized();
+
+        // Devices of version 2.1.x sum all bytes before the checksum.
+        let (checksum, fields) = bytes.split_last().unwrap();
+        let old_checksum = fields.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+
+        prop_assert_eq!(old_checksum != *checksum, is_extended);
+    }
+
+    #[test]
+    fn spec_state_is_replaced_by_new_one(
+        old_state in any_state(),
+        new_state in any_state(),
+        ignore_duplications_flag in any::<bool>(),
+    ) {
+        let mut packet = make_packet(1, 2, 3, 4, old_state, ignore_duplications_flag, &[]);
+        packet.set_spec_state(ALL_STATES[new_state].clone());
+
+        prop_assert!(packet.get_spec_state() == ALL_STATES[new_state]);
+        prop_assert_eq!(
+            packet.is_ignore_duplication_flag_set(),
+            ignore_duplications_flag
+        );
+    }
+
+    #[test]
+    fn state_mutation_reaches_final_state(state in any_state()) {
+        let mut state = ALL_STATES[state].clone();
+        // The longest chain is the transaction:
+        // SendTransaction -> AcceptTransaction -> InitTransaction -> FinishTransaction.
+        for _ in 0..3 {
+            let mutated = state.clone().mutated();
+            prop_assert!(mutated == answer_state(&state));
+            state = mutated;
+        }
+        prop_assert!(state.clone().mutated() == state);
+    }
+
+    #[test]
+    fn mutated_packet_is_the_answer(
+        source in 1..=AddressType::MAX,
+        destination in 1..=AddressType::MAX,
+        id in any::<IdType>(),
+        lifetime in any::<LifeTimeType>(),
+        state in any_state(),
+        data in any_data(),
+    ) {
+        let packet = make_packet(source, destination, id, lifetime, state, true, &data);
+        let state = &ALL_STATES[state];
+
+        let answer = match packet.clone().mutated() {
+            Ok(answer) => answer,
+            Err(_) => return Err(TestCaseError::fail("Packet is addressed to exact device")),
+        };
+
+        prop_assert!(answer.get_spec_state() == answer_state(state));
+        prop_assert_eq!(answer.lifetime, lifetime);
+
+        let is_answered = matches!(
+            state,
+            PacketState::Ping
+                | PacketState::SendTransaction
+                | PacketState::AcceptTransaction
+                | PacketState::InitTransaction
+                | PacketState::ReliableSend
+                | PacketState::RpcRequest
+                | PacketState::ParameterRequest
+                | PacketState::DiagnosticsRequest
+        );
+        if is_answered {
+            prop_assert_eq!(answer.source_device_identifier, destination);
+            prop_assert_eq!(answer.destination_device_identifier, source);
+        } else {
+            prop_assert_eq!(answer.source_device_identifier, source);
+            prop_assert_eq!(answer.destination_device_identifier, destination);
+        }
+
+        // Accepted transaction is continued with the next id.
+        let expected_id = match state {
+            PacketState::AcceptTransaction => id.wrapping_add(1),
+            _ => id,
+        };
+        prop_assert_eq!(answer.id, expected_id);
+
+        // Acknowledgement does not echo the content back.
+        if *state == PacketState::ReliableSend {
+            prop_assert_eq!(answer.data_length, 0);
+            prop_assert!(answer.data.iter().all(|byte| *byte == 0));
+        } else {
+            prop_assert!(answer.data == packet.data);
+        }
+    }
+
+    #[test]
+    fn broadcast_packet_is_not_answered(
+        source in 1..=AddressType::MAX,
+        state in any_state(),
+    ) {
+        let packet = make_packet(source, 0, 1, 1, state, true, &[]);
+        let must_answer = answer_state(&ALL_STATES[state]) != ALL_STATES[state];
+
+        prop_assert_eq!(packet.mutated().is_err(), must_answer);
+    }
+}
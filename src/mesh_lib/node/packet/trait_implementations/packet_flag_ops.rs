@@ -1,7 +1,8 @@
 use super::super::bitpos::{is_flag_set, set_flag};
 use super::super::constants::{
-    ACCEPT_TRANSACTION_FLAG, FINISH_TRANSACTION_FLAG, IGNORE_DUPLICATIONS_FLAG,
-    INITIATE_TRANSACTION_FLAG, PING_FLAG, PONG_FLAG, SEND_TRANSACTION_FLAG,
+    ACCEPT_TRANSACTION_FLAG, EXTENDED_STATE_FLAG, FINISH_TRANSACTION_FLAG,
+    IGNORE_DUPLICATIONS_FLAG, INITIATE_TRANSACTION_FLAG, PING_FLAG, PONG_FLAG,
+    SEND_TRANSACTION_FLAG,
 };
 use super::super::traits::PacketFlagOps;
 use super::super::Packet;
@@ -64,4 +65,12 @@ impl PacketFlagOps for Packet {
     fn is_finish_transaction_flag_set(&self) -> bool {
         is_flag_set(self.flags, FINISH_TRANSACTION_FLAG)
     }
+
+    // EXTENDED_STATE_FLAG
+    fn set_extended_state_flag(&mut self, new_state: bool) {
+        set_flag(&mut self.flags, EXTENDED_STATE_FLAG, new_state);
+    }
+    fn is_extended_state_flag_set(&self) -> bool {
+        is_flag_set(self.flags, EXTENDED_STATE_FLAG)
+    }
 }
@@ -8,6 +8,10 @@ impl StateMutator for PacketState {
             Self::SendTransaction => Self::AcceptTransaction,
             Self::AcceptTransaction => Self::InitTransaction,
             Self::InitTransaction => Self::FinishTransaction,
+            Self::ReliableSend => Self::ReliableAck,
+            Self::RpcRequest => Self::RpcResponse,
+            Self::ParameterRequest => Self::ParameterResponse,
+            Self::DiagnosticsRequest => Self::DiagnosticsResponse,
             _ => self,
         }
     }
@@ -26,4 +26,8 @@ pub trait PacketFlagOps {
     // FINISH_TRANSACTION_FLAG
     fn set_finish_transaction_flag(&mut self, new_state: bool);
     fn is_finish_transaction_flag_set(&self) -> bool;
+
+    // EXTENDED_STATE_FLAG
+    fn set_extended_state_flag(&mut self, new_state: bool);
+    fn is_extended_state_flag_set(&self) -> bool;
 }
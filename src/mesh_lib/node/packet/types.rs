@@ -117,4 +117,116 @@ pub enum PacketState {
     /// Packet with this state being set - is sent by receiver and
     /// informs sender device, that receiver have the transaction finished.
     FinishTransaction,
+
+    /// Packet with this state being set - is sent by sleeping device,
+    /// once it woke up. It forces end-receiver device to send all the
+    /// packets, which were kept in it's mailbox for the sleeping device.
+    PollMailbox,
+
+    /// Packet with this state being set - forces end-receiver device,
+    /// to automatically respond with `ReliableAck` packet without content.
+    /// Also receiving device receives content of the packet.
+    ReliableSend,
+
+    /// Packet with this state being set - is sent by receiver and
+    /// informs sender device, that receiver has received the packet.
+    ReliableAck,
+
+    /// Packet with this state being set - is sent by the device, which
+    /// has caught the packet addressed to it, to the neighbour devices only.
+    /// Informs the previous hop device, that the packet shall not
+    /// be retransmitted. Content keeps source device address and id
+    /// of the caught packet.
+    LinkAck,
+
+    /// Packet with this state being set - is broadcasted by the reference
+    /// device, and keeps it's time. Each device, which forwards the packet,
+    /// updates the time right before the transmission.
+    TimeSync,
+
+    /// Packet with this state being set - is broadcasted by the device,
+    /// which is going to take the address. Content keeps the address
+    /// and hardware id of the probing device.
+    AddressProbe,
+
+    /// Packet with this state being set - is broadcasted by the device,
+    /// which already uses the probed address. Content keeps the address
+    /// and hardware id of the probing device.
+    AddressConflict,
+
+    /// Packet with this state being set - asks the coordinator device for
+    /// the address. Content keeps hardware id of the requesting device.
+    AddressLeaseRequest,
+
+    /// Packet with this state being set - is broadcasted by the coordinator device,
+    /// and gives the address to the requesting device for a period of time.
+    /// Content keeps the address, hardware id of the requesting device and the period.
+    AddressLease,
+
+    /// Packet with this state being set - forces end-receiver device,
+    /// to call the method, which id is kept in the content along with
+    /// arguments, and to respond with `RpcResponse` packet with the same id.
+    RpcRequest,
+
+    /// Packet with this state being set - is sent by receiver and
+    /// keeps the result of the method, called by `RpcRequest` packet.
+    RpcResponse,
+
+    /// Packet with this state being set - keeps the value, published to the topic,
+    /// which id is kept in the content along with the value. Is delivered
+    /// only to devices, which are subscribed to the topic, or retain it.
+    Publish,
+
+    /// Packet with this state being set - asks broker devices, which retain
+    /// the topic, to send the last value of it back with `Publish` packet.
+    RetainedRequest,
+
+    /// Packet with this state being set - forces end-receiver device, to read
+    /// or to write the parameter, which id is kept in the content, and to respond
+    /// with `ParameterResponse` packet with the same id.
+    ParameterRequest,
+
+    /// Packet with this state being set - is sent by receiver and keeps the
+    /// value of the parameter, requested by `ParameterRequest` packet.
+    ParameterResponse,
+
+    /// Packet with this state being set - forces end-receiver device, to
+    /// respond with `DiagnosticsResponse` packet with the same id. Is answered
+    /// by the node itself, without the application being involved.
+    DiagnosticsRequest,
+
+    /// Packet with this state being set - is sent by receiver and keeps
+    /// the report about it's uptime, versions, queues and neighbours.
+    DiagnosticsResponse,
+}
+
+impl core::fmt::Debug for PacketState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PacketState::Normal => write!(f, "Normal"),
+            PacketState::Ping => write!(f, "Ping"),
+            PacketState::Pong => write!(f, "Pong"),
+            PacketState::SendTransaction => write!(f, "SendTransaction"),
+            PacketState::AcceptTransaction => write!(f, "AcceptTransaction"),
+            PacketState::InitTransaction => write!(f, "InitTransaction"),
+            PacketState::FinishTransaction => write!(f, "FinishTransaction"),
+            PacketState::PollMailbox => write!(f, "PollMailbox"),
+            PacketState::ReliableSend => write!(f, "ReliableSend"),
+            PacketState::ReliableAck => write!(f, "ReliableAck"),
+            PacketState::LinkAck => write!(f, "LinkAck"),
+            PacketState::TimeSync => write!(f, "TimeSync"),
+            PacketState::AddressProbe => write!(f, "AddressProbe"),
+            PacketState::AddressConflict => write!(f, "AddressConflict"),
+            PacketState::AddressLeaseRequest => write!(f, "AddressLeaseRequest"),
+            PacketState::AddressLease => write!(f, "AddressLease"),
+            PacketState::RpcRequest => write!(f, "RpcRequest"),
+            PacketState::RpcResponse => write!(f, "RpcResponse"),
+            PacketState::Publish => write!(f, "Publish"),
+            PacketState::RetainedRequest => write!(f, "RetainedRequest"),
+            PacketState::ParameterRequest => write!(f, "ParameterRequest"),
+            PacketState::ParameterResponse => write!(f, "ParameterResponse"),
+            PacketState::DiagnosticsRequest => write!(f, "DiagnosticsRequest"),
+            PacketState::DiagnosticsResponse => write!(f, "DiagnosticsResponse"),
+        }
+    }
 }
@@ -0,0 +1,464 @@
+mod registry;
+
+use heapless::Vec;
+
+use super::constants::PARAMETER_MANAGERS_COUNT;
+use super::rpc::{self, RpcArguments, RpcCallStatus, RpcMethodId, RpcPayload};
+use super::{ms, ExactAddressType, Packet, PacketDataBytes};
+
+pub use registry::{Parameters, ParametersLimitReached};
+
+/// Identifier of the parameter, which is read or written remotely.
+pub type ParameterId = u8;
+
+/// Parameters with ids since this one - are exposed by the `Node` itself,
+/// and can not be registered by the application.
+pub const FIRST_NODE_PARAMETER: ParameterId = 0xF0;
+
+/// `listen_period` of `NodeConfig`. Value is `ParameterValue::Time`.
+pub const LISTEN_PERIOD_PARAMETER: ParameterId = 0xF0;
+
+/// `listen_period_jitter` of `NodeConfig`. Value is `ParameterValue::Time`.
+/// Is exposed only with `listen-period-jitter` feature.
+pub const LISTEN_PERIOD_JITTER_PARAMETER: ParameterId = 0xF1;
+
+/// `quiet_window` of `CarrierSenseConfig`. Value is `ParameterValue::Time`.
+/// Is exposed only in case if carrier sense is configured, which requires `carrier-sense` feature.
+pub const CARRIER_SENSE_QUIET_WINDOW_PARAMETER: ParameterId = 0xF2;
+
+/// `max_backoff` of `CarrierSenseConfig`. Value is `ParameterValue::Time`.
+/// Is exposed only in case if carrier sense is configured, which requires `carrier-sense` feature.
+pub const CARRIER_SENSE_MAX_BACKOFF_PARAMETER: ParameterId = 0xF3;
+
+/// `default_lifetime` of `NodeConfig`. Value is `ParameterValue::U8`.
+pub const DEFAULT_LIFETIME_PARAMETER: ParameterId = 0xF4;
+
+/// `transit_queue_policy` of `NodeConfig`. Value is `ParameterValue::U8`,
+/// which is the code of `QueuePolicy`.
+pub const TRANSIT_QUEUE_POLICY_PARAMETER: ParameterId = 0xF5;
+
+/// Devices, which are allowed to read and write parameters.
+pub type ParameterManagers = Vec<ExactAddressType, PARAMETER_MANAGERS_COUNT>;
+
+/// Remote parameters configuration.
+/// With this configuration being set, manager devices read and write
+/// parameters of this device with `Node` `get_remote_parameter` and
+/// `set_remote_parameter` methods.
+pub struct RemoteParametersConfig {
+    /// Devices, which are allowed to read and write parameters of this device.
+    /// Requests of other devices are answered with `ParameterCallStatus::Denied`.
+    ///
+    /// This is not the access control: the manager is recognised by the source
+    /// address of the request only, which any device in range can forge.
+    pub managers: ParameterManagers,
+
+    /// Is called, once the parameter is written by the manager device,
+    /// so the application keeps the value in the persistent memory,
+    /// and passes it into `NodeConfig` or `register_parameter` after restart.
+    pub on_change: Option<fn(ParameterId, ParameterValue)>,
+}
+
+/// Typed value of the parameter.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum ParameterValue {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    I32(i32),
+    /// Period of time in milliseconds. Is 32-bit even with `time64` feature,
+    /// so devices with and without it understand each other.
+    Time(u32),
+}
+
+impl core::fmt::Debug for ParameterValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParameterValue::Bool(value) => write!(f, "Bool({})", value),
+            ParameterValue::U8(value) => write!(f, "U8({})", value),
+            ParameterValue::U16(value) => write!(f, "U16({})", value),
+            ParameterValue::U32(value) => write!(f, "U32({})", value),
+            ParameterValue::I32(value) => write!(f, "I32({})", value),
+            ParameterValue::Time(value) => write!(f, "Time({})", value),
+        }
+    }
+}
+
+const BOOL_TAG: u8 = 0;
+const U8_TAG: u8 = 1;
+const U16_TAG: u8 = 2;
+const U32_TAG: u8 = 3;
+const I32_TAG: u8 = 4;
+const TIME_TAG: u8 = 5;
+
+impl ParameterValue {
+    pub fn is_same_type(&self, other: &ParameterValue) -> bool {
+        core::mem::discriminant(self) == core::mem::discriminant(other)
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            ParameterValue::Bool(_) => BOOL_TAG,
+            ParameterValue::U8(_) => U8_TAG,
+            ParameterValue::U16(_) => U16_TAG,
+            ParameterValue::U32(_) => U32_TAG,
+            ParameterValue::I32(_) => I32_TAG,
+            ParameterValue::Time(_) => TIME_TAG,
+        }
+    }
+
+    /// Writes the type tag, followed by the value.
+    fn encode<const N: usize>(&self, bytes: &mut Vec<u8, N>) {
+        let _ = bytes.push(self.tag());
+        let _ = match self {
+            ParameterValue::Bool(value) => bytes.extend_from_slice(&[*value as u8]),
+            ParameterValue::U8(value) => bytes.extend_from_slice(&[*value]),
+            ParameterValue::U16(value) => bytes.extend_from_slice(&value.to_be_bytes()),
+            ParameterValue::U32(value) => bytes.extend_from_slice(&value.to_be_bytes()),
+            ParameterValue::I32(value) => bytes.extend_from_slice(&value.to_be_bytes()),
+            ParameterValue::Time(value) => bytes.extend_from_slice(&value.to_be_bytes()),
+        };
+    }
+
+    /// Reads the value, written by `encode` method.
+    /// Returns `None` in case if there are more or less bytes, than the value takes.
+    fn decode(bytes: &[u8]) -> Option<ParameterValue> {
+        let (tag, value) = bytes.split_first()?;
+        match *tag {
+            BOOL_TAG => match value {
+                [0] => Some(ParameterValue::Bool(false)),
+                [1] => Some(ParameterValue::Bool(true)),
+                _ => None,
+            },
+            U8_TAG => match value {
+                [value] => Some(ParameterValue::U8(*value)),
+                _ => None,
+            },
+            U16_TAG => Some(ParameterValue::U16(u16::from_be_bytes(array_of(value)?))),
+            U32_TAG => Some(ParameterValue::U32(u32::from_be_bytes(array_of(value)?))),
+            I32_TAG => Some(ParameterValue::I32(i32::from_be_bytes(array_of(value)?))),
+            TIME_TAG => Some(ParameterValue::Time(u32::from_be_bytes(array_of(value)?))),
+            _ => None,
+        }
+    }
+
+    /// Value of the period of time, which is kept by the `Node`.
+    /// Returns `None` in case if the period does not fit into 32 bits.
+    // Conversion of `ms` is useless, if `time64` feature is turned off.
+    #[allow(clippy::useless_conversion)]
+    pub fn time_of(period: ms) -> Option<ParameterValue> {
+        u32::try_from(period).ok().map(ParameterValue::Time)
+    }
+}
+
+/// Bytes of the value, which takes exactly `N` bytes.
+fn array_of<const N: usize>(bytes: &[u8]) -> Option<[u8; N]> {
+    bytes.try_into().ok()
+}
+
+/// Tells if the parameter can be written by manager devices.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum ParameterAccess {
+    ReadOnly,
+    ReadWrite,
+}
+
+impl core::fmt::Debug for ParameterAccess {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParameterAccess::ReadOnly => write!(f, "ReadOnly"),
+            ParameterAccess::ReadWrite => write!(f, "ReadWrite"),
+        }
+    }
+}
+
+/// Reasons, why the parameter was not read or written.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum ParameterFault {
+    UnknownParameter,
+    ReadOnly,
+    InvalidValue,
+    Denied,
+}
+
+/// Status of the remote call, which was made by `Node` `get_remote_parameter`
+/// or `set_remote_parameter` method.
+#[derive(PartialEq, Eq, Clone)]
+pub enum ParameterCallStatus {
+    /// Response is not received yet.
+    Pending,
+
+    /// Current value of the parameter. Once the parameter is written -
+    /// it is the value, which was applied by the called device.
+    Done(ParameterValue),
+
+    /// The called device does not expose the parameter.
+    UnknownParameter,
+
+    /// The parameter can not be written remotely.
+    ReadOnly,
+
+    /// The value has other type, than the parameter, or is out of the allowed range.
+    InvalidValue,
+
+    /// The calling device is not the manager of the called device.
+    Denied,
+
+    /// Response is not received during the timeout.
+    TimedOut,
+}
+
+impl core::fmt::Debug for ParameterCallStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParameterCallStatus::Pending => write!(f, "Pending"),
+            ParameterCallStatus::Done(value) => write!(f, "Done({:?})", value),
+            ParameterCallStatus::UnknownParameter => write!(f, "UnknownParameter"),
+            ParameterCallStatus::ReadOnly => write!(f, "ReadOnly"),
+            ParameterCallStatus::InvalidValue => write!(f, "InvalidValue"),
+            ParameterCallStatus::Denied => write!(f, "Denied"),
+            ParameterCallStatus::TimedOut => write!(f, "TimedOut"),
+        }
+    }
+}
+
+/// Operations are kept as method ids of the remote call.
+pub const GET_OPERATION: RpcMethodId = 0;
+pub const SET_OPERATION: RpcMethodId = 1;
+
+const RESULT_OK: u8 = 0;
+const RESULT_UNKNOWN_PARAMETER: u8 = 1;
+const RESULT_READ_ONLY: u8 = 2;
+const RESULT_INVALID_VALUE: u8 = 3;
+const RESULT_DENIED: u8 = 4;
+
+/// Operation, requested by the manager device.
+pub enum ParameterOperation {
+    Get(ParameterId),
+    Set(ParameterId, ParameterValue),
+}
+
+impl ParameterOperation {
+    pub fn code(&self) -> RpcMethodId {
+        match self {
+            ParameterOperation::Get(_) => GET_OPERATION,
+            ParameterOperation::Set(_, _) => SET_OPERATION,
+        }
+    }
+}
+
+/// Makes the content of `ParameterRequest` packet. The content is laid out
+/// the same way, as the content of `RpcRequest` packet, so responses are
+/// correlated with calls by the same means.
+pub fn make_request_data(operation: &ParameterOperation) -> PacketDataBytes {
+    let mut arguments = RpcArguments::new();
+    match operation {
+        ParameterOperation::Get(parameter) => {
+            let _ = arguments.push(*parameter);
+        }
+        ParameterOperation::Set(parameter, value) => {
+            let _ = arguments.push(*parameter);
+            value.encode(&mut arguments);
+        }
+    }
+    rpc::make_request_data(operation.code(), &arguments)
+}
+
+/// Returns the operation, requested by the `request`.
+pub fn parse_request(request: &Packet) -> Option<ParameterOperation> {
+    match rpc::parse_request(request)? {
+        (GET_OPERATION, [parameter]) => Some(ParameterOperation::Get(*parameter)),
+        (SET_OPERATION, [parameter, value @ ..]) => Some(ParameterOperation::Set(
+            *parameter,
+            ParameterValue::decode(value)?,
+        )),
+        _ => None,
+    }
+}
+
+/// Makes the response to the `request` by it's mutation,
+/// so the response is addressed to the manager device, and has the same id.
+pub fn make_answer(
+    request: &Packet,
+    result: Result<ParameterValue, ParameterFault>,
+) -> Option<Packet> {
+    let (operation, _) = rpc::parse_request(request)?;
+    let mut payload = RpcPayload::new();
+    match result {
+        Ok(value) => {
+            let _ = payload.push(RESULT_OK);
+            value.encode(&mut payload);
+        }
+        Err(fault) => {
+            let _ = payload.push(match fault {
+                ParameterFault::UnknownParameter => RESULT_UNKNOWN_PARAMETER,
+                ParameterFault::ReadOnly => RESULT_READ_ONLY,
+                ParameterFault::InvalidValue => RESULT_INVALID_VALUE,
+                ParameterFault::Denied => RESULT_DENIED,
+            });
+        }
+    }
+
+    let mut response = request.clone().mutated().ok()?;
+    response.set_data(rpc::make_response_data(
+        operation,
+        RpcCallStatus::Answered(payload),
+    ));
+    Some(response)
+}
+
+/// Tells the status of the parameter call by the status of the remote call,
+/// which carries it.
+pub fn call_status_of(status: RpcCallStatus) -> ParameterCallStatus {
+    let payload = match status {
+        RpcCallStatus::Pending => return ParameterCallStatus::Pending,
+        RpcCallStatus::TimedOut => return ParameterCallStatus::TimedOut,
+        RpcCallStatus::UnknownMethod | RpcCallStatus::Failed => {
+            return ParameterCallStatus::InvalidValue
+        }
+        RpcCallStatus::Answered(payload) => payload,
+    };
+    match payload.split_first() {
+        Some((&RESULT_OK, value)) => match ParameterValue::decode(value) {
+            Some(value) => ParameterCallStatus::Done(value),
+            None => ParameterCallStatus::InvalidValue,
+        },
+        Some((&RESULT_UNKNOWN_PARAMETER, _)) => ParameterCallStatus::UnknownParameter,
+        Some((&RESULT_READ_ONLY, _)) => ParameterCallStatus::ReadOnly,
+        Some((&RESULT_DENIED, _)) => ParameterCallStatus::Denied,
+        _ => ParameterCallStatus::InvalidValue,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::packet::PacketState;
+
+    fn make_request(operation: &ParameterOperation) -> Packet {
+        Packet::new(
+            1,
+            2,
+            7,
+            3,
+            PacketState::ParameterRequest,
+            true,
+            make_request_data(operation),
+        )
+    }
+
+    /// Status, which the manager device gets from the `response`.
+    fn status_of(response: &Packet) -> ParameterCallStatus {
+        match rpc::parse_response(response) {
+            Some((_, status)) => call_status_of(status),
+            None => panic!("Response shall be parsed"),
+        }
+    }
+
+    #[test]
+    fn values_are_encoded_and_decoded_back() {
+        let values = [
+            ParameterValue::Bool(true),
+            ParameterValue::U8(200),
+            ParameterValue::U16(50_000),
+            ParameterValue::U32(4_000_000_000),
+            ParameterValue::I32(-5),
+            ParameterValue::Time(1500),
+        ];
+        for value in values {
+            let mut bytes: Vec<u8, 16> = Vec::new();
+            value.encode(&mut bytes);
+            assert_eq!(ParameterValue::decode(&bytes), Some(value));
+        }
+        assert!(ParameterValue::decode(&[U32_TAG, 1, 2]).is_none());
+        assert!(ParameterValue::decode(&[U32_TAG, 1, 2, 3, 4, 5]).is_none());
+        assert!(ParameterValue::decode(&[U8_TAG, 1, 2]).is_none());
+        assert!(ParameterValue::decode(&[BOOL_TAG, 1, 0]).is_none());
+        assert!(ParameterValue::decode(&[BOOL_TAG, 2]).is_none());
+        assert!(ParameterValue::decode(&[42, 1]).is_none());
+    }
+
+    #[test]
+    fn time_is_encoded_as_32_bit_value() {
+        let mut bytes: Vec<u8, 16> = Vec::new();
+        ParameterValue::Time(1500).encode(&mut bytes);
+
+        assert_eq!(bytes.as_slice(), &[TIME_TAG, 0, 0, 0x05, 0xDC]);
+        assert_eq!(
+            ParameterValue::time_of(1500),
+            Some(ParameterValue::Time(1500))
+        );
+    }
+
+    #[cfg(feature = "time64")]
+    #[test]
+    fn time_beyond_32_bits_is_rejected() {
+        assert_eq!(ParameterValue::time_of(u32::MAX as ms + 1), None);
+    }
+
+    #[test]
+    fn request_with_extra_bytes_is_rejected() {
+        let request = Packet::new(
+            1,
+            2,
+            7,
+            3,
+            PacketState::ParameterRequest,
+            true,
+            rpc::make_request_data(GET_OPERATION, &RpcArguments::from_slice(&[3, 0]).unwrap()),
+        );
+
+        assert!(parse_request(&request).is_none());
+    }
+
+    #[test]
+    fn set_request_is_parsed() {
+        let request = make_request(&ParameterOperation::Set(
+            LISTEN_PERIOD_PARAMETER,
+            ParameterValue::Time(250),
+        ));
+
+        assert!(matches!(
+            parse_request(&request),
+            Some(ParameterOperation::Set(
+                LISTEN_PERIOD_PARAMETER,
+                ParameterValue::Time(250)
+            ))
+        ));
+    }
+
+    #[test]
+    fn answer_is_sent_back_with_same_id() {
+        let request = make_request(&ParameterOperation::Get(3));
+
+        let answer =
+            make_answer(&request, Ok(ParameterValue::U16(512))).expect("Request shall be answered");
+
+        assert!(answer.get_spec_state() == PacketState::ParameterResponse);
+        assert_eq!(answer.source_device_identifier, 2);
+        assert_eq!(answer.get_destination_device_identifier(), 1);
+        assert_eq!(answer.get_id(), 7);
+        assert!(status_of(&answer) == ParameterCallStatus::Done(ParameterValue::U16(512)));
+    }
+
+    #[test]
+    fn faults_are_answered() {
+        let request = make_request(&ParameterOperation::Set(3, ParameterValue::Bool(true)));
+        let cases = [
+            (
+                ParameterFault::UnknownParameter,
+                ParameterCallStatus::UnknownParameter,
+            ),
+            (ParameterFault::ReadOnly, ParameterCallStatus::ReadOnly),
+            (
+                ParameterFault::InvalidValue,
+                ParameterCallStatus::InvalidValue,
+            ),
+            (ParameterFault::Denied, ParameterCallStatus::Denied),
+        ];
+        for (fault, expected) in cases {
+            let answer = make_answer(&request, Err(fault)).expect("Request shall be answered");
+            assert!(status_of(&answer) == expected);
+        }
+    }
+}
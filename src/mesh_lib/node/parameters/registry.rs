@@ -0,0 +1,178 @@
+use heapless::Vec;
+
+use super::super::constants::PARAMETERS_COUNT;
+use super::{ParameterAccess, ParameterFault, ParameterId, ParameterValue};
+
+pub struct ParametersLimitReached;
+
+struct Parameter {
+    id: ParameterId,
+    value: ParameterValue,
+    access: ParameterAccess,
+}
+
+/// Keeps parameters, which are exposed by the application
+/// to manager devices.
+pub struct Parameters {
+    parameters: Vec<Parameter, PARAMETERS_COUNT>,
+}
+
+impl Parameters {
+    pub fn new() -> Parameters {
+        Parameters {
+            parameters: Vec::new(),
+        }
+    }
+
+    /// Registers the parameter with initial `value`.
+    /// Previously registered parameter with the same id is replaced.
+    pub fn register(
+        &mut self,
+        id: ParameterId,
+        value: ParameterValue,
+        access: ParameterAccess,
+    ) -> Result<(), ParametersLimitReached> {
+        if let Some(parameter) = self
+            .parameters
+            .iter_mut()
+            .find(|parameter| parameter.id == id)
+        {
+            parameter.value = value;
+            parameter.access = access;
+            return Ok(());
+        }
+        match self.parameters.push(Parameter { id, value, access }) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(ParametersLimitReached),
+        }
+    }
+
+    pub fn get(&self, id: ParameterId) -> Option<ParameterValue> {
+        self.parameters
+            .iter()
+            .find(|parameter| parameter.id == id)
+            .map(|parameter| parameter.value)
+    }
+
+    /// Replaces the value of the parameter, regardless of it's access.
+    /// The value shall have the same type, as the parameter has.
+    pub fn set(&mut self, id: ParameterId, value: ParameterValue) -> Result<(), ParameterFault> {
+        let parameter = match self
+            .parameters
+            .iter_mut()
+            .find(|parameter| parameter.id == id)
+        {
+            Some(parameter) => parameter,
+            None => return Err(ParameterFault::UnknownParameter),
+        };
+        if !parameter.value.is_same_type(&value) {
+            return Err(ParameterFault::InvalidValue);
+        }
+        parameter.value = value;
+        Ok(())
+    }
+
+    /// Same as `set`, but is made on behalf of the manager device,
+    /// so read-only parameters are kept unchanged.
+    pub fn write(&mut self, id: ParameterId, value: ParameterValue) -> Result<(), ParameterFault> {
+        match self.parameters.iter().find(|parameter| parameter.id == id) {
+            Some(parameter) if parameter.access == ParameterAccess::ReadOnly => {
+                Err(ParameterFault::ReadOnly)
+            }
+            _ => self.set(id, value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLD: ParameterId = 1;
+    const FIRMWARE_VERSION: ParameterId = 2;
+
+    fn make_parameters() -> Parameters {
+        let mut parameters = Parameters::new();
+        let _ = parameters.register(
+            THRESHOLD,
+            ParameterValue::U16(100),
+            ParameterAccess::ReadWrite,
+        );
+        let _ = parameters.register(
+            FIRMWARE_VERSION,
+            ParameterValue::U8(3),
+            ParameterAccess::ReadOnly,
+        );
+        parameters
+    }
+
+    #[test]
+    fn written_value_is_kept() {
+        let mut parameters = make_parameters();
+
+        assert!(parameters
+            .write(THRESHOLD, ParameterValue::U16(150))
+            .is_ok());
+
+        assert_eq!(parameters.get(THRESHOLD), Some(ParameterValue::U16(150)));
+    }
+
+    #[test]
+    fn value_of_other_type_is_rejected() {
+        let mut parameters = make_parameters();
+
+        assert!(
+            parameters.write(THRESHOLD, ParameterValue::U32(150))
+                == Err(ParameterFault::InvalidValue)
+        );
+        assert_eq!(parameters.get(THRESHOLD), Some(ParameterValue::U16(100)));
+    }
+
+    #[test]
+    fn read_only_parameter_is_set_only_locally() {
+        let mut parameters = make_parameters();
+
+        assert!(
+            parameters.write(FIRMWARE_VERSION, ParameterValue::U8(4))
+                == Err(ParameterFault::ReadOnly)
+        );
+        assert!(parameters
+            .set(FIRMWARE_VERSION, ParameterValue::U8(4))
+            .is_ok());
+        assert_eq!(
+            parameters.get(FIRMWARE_VERSION),
+            Some(ParameterValue::U8(4))
+        );
+    }
+
+    #[test]
+    fn unknown_parameter_is_rejected() {
+        let mut parameters = make_parameters();
+
+        assert!(parameters.get(42).is_none());
+        assert!(
+            parameters.write(42, ParameterValue::U8(1)) == Err(ParameterFault::UnknownParameter)
+        );
+    }
+
+    #[test]
+    fn parameters_are_limited() {
+        let mut parameters = Parameters::new();
+        for id in 0..PARAMETERS_COUNT as ParameterId {
+            assert!(parameters
+                .register(id, ParameterValue::Bool(false), ParameterAccess::ReadWrite)
+                .is_ok());
+        }
+        // Parameter with the same id is replaced.
+        assert!(parameters
+            .register(0, ParameterValue::Bool(true), ParameterAccess::ReadWrite)
+            .is_ok());
+        assert!(parameters
+            .register(
+                PARAMETERS_COUNT as ParameterId,
+                ParameterValue::Bool(false),
+                ParameterAccess::ReadWrite,
+            )
+            .is_err());
+    }
+}
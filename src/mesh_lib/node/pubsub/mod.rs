@@ -0,0 +1,183 @@
+mod topics;
+
+use heapless::{Deque, Vec};
+
+use super::constants::PUBLICATION_QUEUE_SIZE;
+use super::packet::CONTENT_SIZE;
+use super::{
+    ExactAddressType, GeneralAddressType, LifeTimeType, Packet, PacketDataBytes, PacketState,
+};
+
+pub use topics::{Topics, TopicsLimitReached};
+
+/// Identifier of the topic, which values are published to.
+pub type TopicId = u8;
+
+/// Value, published to the topic. The content of the `Publish`
+/// packet keeps the topic id, followed by the value.
+pub type TopicPayload = Vec<u8, { CONTENT_SIZE - TOPIC_ID_SIZE }>;
+
+const TOPIC_ID_SIZE: usize = 1;
+
+pub type PublicationQueue = Deque<Publication, PUBLICATION_QUEUE_SIZE>;
+
+/// Value of the topic, which is returned by `Node` `receive_publication` method.
+#[derive(Clone)]
+pub struct Publication {
+    pub topic: TopicId,
+
+    /// Device, which has published the value, or the broker device,
+    /// which has sent the retained value.
+    pub source: ExactAddressType,
+
+    pub payload: TopicPayload,
+
+    /// Tells if the value was kept by the broker device, and is sent
+    /// on request, made by `Node` `request_retained` method.
+    pub is_retained: bool,
+}
+
+/// Makes the content of `Publish` packet.
+pub fn make_publish_data(topic: TopicId, payload: &[u8]) -> PacketDataBytes {
+    let mut data = PacketDataBytes::new();
+    let _ = data.push(topic);
+    let _ = data.extend_from_slice(payload);
+    data
+}
+
+/// Makes the content of `RetainedRequest` packet. The broker device
+/// sends the value back with the `lifetime`, the requesting device has chosen.
+pub fn make_retained_request_data(topic: TopicId, lifetime: LifeTimeType) -> PacketDataBytes {
+    make_publish_data(topic, &[lifetime])
+}
+
+/// Makes the `Publish` packet, which sends the retained value of the topic
+/// back to the device, which has sent the `request`. Returns `None` in case
+/// if no value of the topic is retained by this device.
+pub fn make_retained_answer(
+    request: &Packet,
+    topics: &Topics,
+    my_address: ExactAddressType,
+) -> Option<Packet> {
+    let (topic, lifetime) = match content_of(request) {
+        [topic, lifetime, ..] => (*topic, *lifetime),
+        _ => return None,
+    };
+    let requester = ExactAddressType::new(request.source_device_identifier)?;
+    let value = topics.retained_value(topic)?;
+    Some(Packet::new(
+        my_address.into(),
+        requester.into(),
+        0,
+        lifetime,
+        PacketState::Publish,
+        true,
+        make_publish_data(topic, value),
+    ))
+}
+
+/// Returns the topic, which the `Publish` or `RetainedRequest` packet is related to.
+pub fn topic_of(packet: &Packet) -> Option<TopicId> {
+    match packet.get_spec_state() {
+        PacketState::Publish | PacketState::RetainedRequest => content_of(packet).first().copied(),
+        _ => None,
+    }
+}
+
+/// Returns the publication, kept by the `Publish` packet.
+/// Values, which are sent to exact device - are the retained ones.
+pub fn parse_publication(packet: &Packet) -> Option<Publication> {
+    if packet.get_spec_state() != PacketState::Publish {
+        return None;
+    }
+    let (topic, payload) = content_of(packet).split_first()?;
+    Some(Publication {
+        topic: *topic,
+        source: ExactAddressType::new(packet.source_device_identifier)?,
+        payload: TopicPayload::from_slice(payload).ok()?,
+        is_retained: !packet.is_destination_reached(GeneralAddressType::Broadcast),
+    })
+}
+
+fn content_of(packet: &Packet) -> &[u8] {
+    &packet.data[..packet.get_data_length().min(packet.data.len())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_packet(destination: u8, state: PacketState, data: PacketDataBytes) -> Packet {
+        Packet::new(1, destination, 4, 3, state, true, data)
+    }
+
+    #[test]
+    fn publication_is_parsed() {
+        let packet = make_packet(0, PacketState::Publish, make_publish_data(7, b"21.5"));
+
+        let publication = parse_publication(&packet).expect("Publication shall be parsed");
+
+        assert_eq!(topic_of(&packet), Some(7));
+        assert_eq!(publication.topic, 7);
+        assert_eq!(publication.source.get(), 1);
+        assert_eq!(publication.payload, b"21.5");
+        assert!(!publication.is_retained);
+    }
+
+    #[test]
+    fn publication_to_exact_device_is_retained() {
+        let packet = make_packet(2, PacketState::Publish, make_publish_data(7, b"21.5"));
+
+        assert!(matches!(
+            parse_publication(&packet),
+            Some(publication) if publication.is_retained
+        ));
+    }
+
+    #[test]
+    fn other_packets_have_no_topic() {
+        let packet = make_packet(0, PacketState::Normal, make_publish_data(7, b"21.5"));
+        assert!(topic_of(&packet).is_none());
+        assert!(parse_publication(&packet).is_none());
+
+        let empty = make_packet(0, PacketState::Publish, PacketDataBytes::new());
+        assert!(topic_of(&empty).is_none());
+        assert!(parse_publication(&empty).is_none());
+
+        let request = make_packet(
+            0,
+            PacketState::RetainedRequest,
+            make_retained_request_data(7, 3),
+        );
+        assert_eq!(topic_of(&request), Some(7));
+        assert!(parse_publication(&request).is_none());
+    }
+
+    #[test]
+    fn retained_value_is_sent_back_to_requester() {
+        let mut topics = Topics::new();
+        let _ = topics.retain(7);
+        let request = make_packet(
+            0,
+            PacketState::RetainedRequest,
+            make_retained_request_data(7, 5),
+        );
+        let broker = ExactAddressType::new(9).unwrap();
+
+        assert!(make_retained_answer(&request, &topics, broker).is_none());
+
+        let publish = make_packet(0, PacketState::Publish, make_publish_data(7, b"21.5"));
+        topics.store(&parse_publication(&publish).unwrap());
+
+        let answer =
+            make_retained_answer(&request, &topics, broker).expect("Retained value shall be sent");
+        assert!(answer.get_spec_state() == PacketState::Publish);
+        assert_eq!(answer.source_device_identifier, 9);
+        assert_eq!(answer.get_destination_device_identifier(), 1);
+        assert_eq!(answer.get_lifetime(), 5);
+
+        let publication = parse_publication(&answer).unwrap();
+        assert_eq!(publication.payload, b"21.5");
+        assert!(publication.is_retained);
+    }
+}
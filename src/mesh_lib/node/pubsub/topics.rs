@@ -0,0 +1,165 @@
+use heapless::Vec;
+
+use super::super::constants::{RETAINED_TOPICS_COUNT, SUBSCRIBED_TOPICS_COUNT};
+use super::{Publication, TopicId, TopicPayload};
+
+pub struct TopicsLimitReached;
+
+/// Keeps topics, which the device is subscribed to, and
+/// topics, which the device retains last values of as the broker.
+///
+/// Is used by the router, so `Publish` packets of other
+/// topics are only forwarded, and are not handled by the device.
+pub struct Topics {
+    subscribed: Vec<TopicId, SUBSCRIBED_TOPICS_COUNT>,
+    retained: Vec<(TopicId, Option<TopicPayload>), RETAINED_TOPICS_COUNT>,
+}
+
+impl Topics {
+    pub fn new() -> Topics {
+        Topics {
+            subscribed: Vec::new(),
+            retained: Vec::new(),
+        }
+    }
+
+    pub fn subscribe(&mut self, topic: TopicId) -> Result<(), TopicsLimitReached> {
+        if self.is_subscribed(topic) {
+            return Ok(());
+        }
+        match self.subscribed.push(topic) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(TopicsLimitReached),
+        }
+    }
+
+    pub fn unsubscribe(&mut self, topic: TopicId) {
+        self.subscribed.retain(|subscribed| *subscribed != topic);
+    }
+
+    pub fn is_subscribed(&self, topic: TopicId) -> bool {
+        self.subscribed.contains(&topic)
+    }
+
+    /// Starts keeping the last value of the `topic`.
+    pub fn retain(&mut self, topic: TopicId) -> Result<(), TopicsLimitReached> {
+        if self.is_retained(topic) {
+            return Ok(());
+        }
+        match self.retained.push((topic, None)) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(TopicsLimitReached),
+        }
+    }
+
+    /// Stops keeping the last value of the `topic`. The kept value is dropped.
+    pub fn release(&mut self, topic: TopicId) {
+        self.retained.retain(|(retained, _)| *retained != topic);
+    }
+
+    pub fn is_retained(&self, topic: TopicId) -> bool {
+        self.retained.iter().any(|(retained, _)| *retained == topic)
+    }
+
+    /// Tells if `Publish` packets of the `topic` shall be handled by the device.
+    pub fn is_interested(&self, topic: TopicId) -> bool {
+        self.is_subscribed(topic) || self.is_retained(topic)
+    }
+
+    /// Keeps the value of the `publication`, in case if it's topic is retained.
+    /// Values, which are retained by other brokers - are not kept, as they may be outdated.
+    pub fn store(&mut self, publication: &Publication) {
+        if publication.is_retained {
+            return;
+        }
+        if let Some((_, value)) = self
+            .retained
+            .iter_mut()
+            .find(|(retained, _)| *retained == publication.topic)
+        {
+            *value = Some(publication.payload.clone());
+        }
+    }
+
+    pub fn retained_value(&self, topic: TopicId) -> Option<&TopicPayload> {
+        self.retained
+            .iter()
+            .find(|(retained, _)| *retained == topic)
+            .and_then(|(_, value)| value.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::ExactAddressType;
+
+    fn make_publication(topic: TopicId, payload: &[u8], is_retained: bool) -> Publication {
+        Publication {
+            topic,
+            source: ExactAddressType::new(1).unwrap(),
+            payload: TopicPayload::from_slice(payload).unwrap(),
+            is_retained,
+        }
+    }
+
+    #[test]
+    fn subscribed_topic_is_interesting() {
+        let mut topics = Topics::new();
+        let _ = topics.subscribe(3);
+
+        assert!(topics.is_interested(3));
+        assert!(!topics.is_interested(4));
+
+        topics.unsubscribe(3);
+        assert!(!topics.is_interested(3));
+    }
+
+    #[test]
+    fn last_value_of_retained_topic_is_kept() {
+        let mut topics = Topics::new();
+        let _ = topics.retain(3);
+        assert!(topics.is_interested(3));
+        assert!(!topics.is_subscribed(3));
+        assert!(topics.retained_value(3).is_none());
+
+        topics.store(&make_publication(3, b"first", false));
+        topics.store(&make_publication(3, b"second", false));
+        topics.store(&make_publication(4, b"other", false));
+
+        assert_eq!(topics.retained_value(3).unwrap(), b"second");
+        assert!(topics.retained_value(4).is_none());
+    }
+
+    #[test]
+    fn value_retained_by_other_broker_is_not_kept() {
+        let mut topics = Topics::new();
+        let _ = topics.retain(3);
+
+        topics.store(&make_publication(3, b"fresh", false));
+        topics.store(&make_publication(3, b"outdated", true));
+
+        assert_eq!(topics.retained_value(3).unwrap(), b"fresh");
+
+        topics.release(3);
+        assert!(topics.retained_value(3).is_none());
+    }
+
+    #[test]
+    fn topics_are_limited() {
+        let mut topics = Topics::new();
+        for topic in 0..SUBSCRIBED_TOPICS_COUNT as TopicId {
+            assert!(topics.subscribe(topic).is_ok());
+        }
+        // Subscription to the same topic takes no place.
+        assert!(topics.subscribe(0).is_ok());
+        assert!(topics
+            .subscribe(SUBSCRIBED_TOPICS_COUNT as TopicId)
+            .is_err());
+
+        for topic in 0..RETAINED_TOPICS_COUNT as TopicId {
+            assert!(topics.retain(topic).is_ok());
+        }
+        assert!(topics.retain(RETAINED_TOPICS_COUNT as TopicId).is_err());
+    }
+}
@@ -0,0 +1,80 @@
+use super::ms;
+
+/// Source of random numbers, which is used by the `Node`
+/// to spread it's transmissions in time. So the devices,
+/// which are going to speak simultaneously - are less likely
+/// to jam each other.
+///
+/// Can be implemented over hardware random number generator,
+/// noise of floating analog pin, or any other source of entropy
+/// available on the platform.
+pub trait RandomNumberGenerator {
+    /// Returns next random number.
+    fn next_u32(&mut self) -> u32;
+
+    /// Returns random period of time in range `0..=max_period`.
+    fn next_period(&mut self, max_period: ms) -> ms {
+        match max_period.checked_add(1) {
+            Some(range) => ms::from(self.next_u32()) % range,
+            None => ms::from(self.next_u32()),
+        }
+    }
+}
+
+/// Tiny pseudo random number generator, which is used
+/// by the `Node` in case if no other generator is provided.
+/// It is based on xorshift32 algorithm.
+pub struct XorShiftRng {
+    state: u32,
+}
+
+impl XorShiftRng {
+    /// Creates new generator. Devices seeded with different
+    /// values will produce different sequences of numbers.
+    pub fn new(seed: u32) -> Self {
+        // Scramble the seed, so small seeds like device addresses,
+        // produce well distinguishable sequences from the start.
+        // Zero state is forbidden for xorshift, it would produce zeroes forever.
+        let state = seed.wrapping_mul(0x9E37_79B9) ^ 0x85EB_CA6B;
+        Self {
+            state: if state == 0 { 0x85EB_CA6B } else { state },
+        }
+    }
+}
+
+impl RandomNumberGenerator for XorShiftRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_period_stays_within_range() {
+        let mut rng = XorShiftRng::new(1);
+        for _ in 0..1000 {
+            assert!(rng.next_period(10) <= 10);
+        }
+    }
+
+    #[test]
+    fn zero_max_period_gives_zero() {
+        let mut rng = XorShiftRng::new(1);
+        assert_eq!(rng.next_period(0), 0);
+    }
+
+    #[test]
+    fn different_seeds_give_different_sequences() {
+        let mut rng_1 = XorShiftRng::new(1);
+        let mut rng_2 = XorShiftRng::new(2);
+        assert_ne!(rng_1.next_u32(), rng_2.next_u32());
+    }
+}
@@ -0,0 +1,106 @@
+use super::ms;
+
+/// Load of the ether, observed during one window of time.
+#[derive(Clone, Default)]
+pub struct ChannelLoad {
+    /// Count of bytes heard from the ether.
+    pub bytes_heard: u32,
+
+    /// Count of duplicated packets heard from the ether.
+    pub duplicates_heard: u32,
+}
+
+/// Counts bytes and duplicated packets heard from the ether
+/// during consecutive windows of time.
+pub struct ChannelLoadEstimator {
+    window: ms,
+    window_started_at: ms,
+    current_load: ChannelLoad,
+    last_load: ChannelLoad,
+    is_last_load_taken: bool,
+}
+
+impl ChannelLoadEstimator {
+    pub fn new(window: ms) -> ChannelLoadEstimator {
+        ChannelLoadEstimator {
+            window,
+            window_started_at: 0,
+            current_load: ChannelLoad::default(),
+            last_load: ChannelLoad::default(),
+            is_last_load_taken: true,
+        }
+    }
+
+    pub fn record_byte(&mut self) {
+        self.current_load.bytes_heard = self.current_load.bytes_heard.saturating_add(1);
+    }
+
+    pub fn record_duplicate(&mut self) {
+        self.current_load.duplicates_heard = self.current_load.duplicates_heard.saturating_add(1);
+    }
+
+    /// Finishes current window, if it is over.
+    pub fn update(&mut self, current_time: ms) {
+        if current_time.wrapping_sub(self.window_started_at) < self.window {
+            return;
+        }
+        self.last_load = core::mem::take(&mut self.current_load);
+        self.window_started_at = current_time;
+        self.is_last_load_taken = false;
+    }
+
+    /// Load of the last finished window.
+    pub fn last_load(&self) -> &ChannelLoad {
+        &self.last_load
+    }
+
+    /// Returns load of the last finished window,
+    /// only once per each finished window.
+    pub fn take_last_load(&mut self) -> Option<ChannelLoad> {
+        if self.is_last_load_taken {
+            return None;
+        }
+        self.is_last_load_taken = true;
+        Some(self.last_load.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: ms = 100;
+
+    #[test]
+    fn load_is_not_reported_before_window_ends() {
+        let mut estimator = ChannelLoadEstimator::new(WINDOW);
+        estimator.record_byte();
+        estimator.update(WINDOW - 1);
+        assert!(estimator.take_last_load().is_none());
+    }
+
+    #[test]
+    fn load_is_reported_once_per_window() {
+        let mut estimator = ChannelLoadEstimator::new(WINDOW);
+        estimator.record_byte();
+        estimator.record_byte();
+        estimator.record_duplicate();
+        estimator.update(WINDOW);
+
+        let load = estimator
+            .take_last_load()
+            .expect("Window shall be finished");
+        assert_eq!(load.bytes_heard, 2);
+        assert_eq!(load.duplicates_heard, 1);
+        assert!(estimator.take_last_load().is_none());
+    }
+
+    #[test]
+    fn next_window_starts_from_zero() {
+        let mut estimator = ChannelLoadEstimator::new(WINDOW);
+        estimator.record_byte();
+        estimator.update(WINDOW);
+        estimator.update(2 * WINDOW);
+        assert_eq!(estimator.last_load().bytes_heard, 0);
+    }
+}
@@ -1,20 +1,40 @@
-mod packet_bytes_parser;
+#[cfg(feature = "adaptive-listen-period")]
+mod channel_load;
+pub(super) mod packet_bytes_parser;
 mod packet_filter;
 
-use super::constants::RECEIVER_READER_BUFFER_SIZE;
+use super::constants::{MAX_INTERFACES_COUNT, RECEIVER_READER_BUFFER_SIZE};
 
 use super::ms;
 
+#[cfg(feature = "adaptive-listen-period")]
+pub use self::channel_load::ChannelLoad;
+
+#[cfg(feature = "adaptive-listen-period")]
+use self::channel_load::ChannelLoadEstimator;
+
 use self::{
     packet_bytes_parser::PacketBytesParser,
     packet_filter::{Filter, RegistrationError},
 };
 
-use super::Packet;
+use super::{Interface, Packet};
 
 pub struct Receiver {
     packet_filter: Filter,
-    packet_bytes_parser: PacketBytesParser,
+    packet_bytes_parsers: [PacketBytesParser; MAX_INTERFACES_COUNT],
+    #[cfg(feature = "carrier-sense")]
+    last_byte_received_at: Option<ms>,
+    #[cfg(feature = "adaptive-listen-period")]
+    channel_load_estimator: ChannelLoadEstimator,
+    /// Duplicated packets are kept only for subsystems, which look at them.
+    #[cfg(any(
+        feature = "sniffer",
+        feature = "addressing",
+        feature = "diagnostics",
+        feature = "link-ack"
+    ))]
+    heard_duplicate: Option<(Packet, usize)>,
 }
 
 enum ReceiverError {
@@ -23,10 +43,21 @@ enum ReceiverError {
 }
 
 impl Receiver {
-    pub fn new() -> Receiver {
+    pub fn new(#[cfg(feature = "adaptive-listen-period")] channel_load_window: ms) -> Receiver {
         Receiver {
             packet_filter: Filter::new(),
-            packet_bytes_parser: PacketBytesParser::new(),
+            packet_bytes_parsers: core::array::from_fn(|_| PacketBytesParser::new()),
+            #[cfg(feature = "carrier-sense")]
+            last_byte_received_at: None,
+            #[cfg(feature = "adaptive-listen-period")]
+            channel_load_estimator: ChannelLoadEstimator::new(channel_load_window),
+            #[cfg(any(
+                feature = "sniffer",
+                feature = "addressing",
+                feature = "diagnostics",
+                feature = "link-ack"
+            ))]
+            heard_duplicate: None,
         }
     }
 
@@ -41,9 +72,11 @@ impl Receiver {
     ) -> Result<Packet, ReceiverError> {
         match self
             .packet_filter
-            .filter_out_duplicated(packet, current_time)
+            .filter_out_duplicated(packet, &current_time)
         {
             Err(RegistrationError::DuplicationFound) => {
+                #[cfg(feature = "adaptive-listen-period")]
+                self.channel_load_estimator.record_duplicate();
                 return Err(ReceiverError::PacketDuplication);
             }
             Err(RegistrationError::RegistrationLimitExceeded) => {
@@ -54,54 +87,129 @@ impl Receiver {
     }
 
     /// Does the following:
-    /// - reads byte from serial
+    /// - reads byte from each of the interfaces
     /// - checks if the packet can be built from with new received byte.
-    pub fn update<I>(&mut self, current_time: ms, interface_driver: &mut I)
-    where
-        I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
-    {
-        self._receive_byte(interface_driver);
-        self.packet_filter.update(current_time);
+    ///
+    /// Interfaces beyond `MAX_INTERFACES_COUNT` are not listened.
+    pub fn update(&mut self, current_time: ms, interfaces: &mut [&mut dyn Interface]) {
+        for (parser, interface) in self
+            .packet_bytes_parsers
+            .iter_mut()
+            .zip(interfaces.iter_mut())
+        {
+            if Self::_receive_byte(parser, *interface) {
+                #[cfg(feature = "carrier-sense")]
+                self.last_byte_received_at.replace(current_time);
+                #[cfg(feature = "adaptive-listen-period")]
+                self.channel_load_estimator.record_byte();
+            }
+        }
+        self.packet_filter.update(&current_time);
+        #[cfg(feature = "adaptive-listen-period")]
+        self.channel_load_estimator.update(current_time);
+    }
+
+    /// Period of time since `current_time`, after which
+    /// the duplication filter needs to be updated.
+    pub fn time_till_next_filter_expiration(&self, current_time: ms) -> Option<ms> {
+        self.packet_filter.time_till_next_expiration(&current_time)
+    }
+
+    /// Count of packets, registered by the duplication filter.
+    #[cfg(feature = "fuzzing")]
+    pub fn filter_len(&self) -> usize {
+        self.packet_filter.len()
+    }
+
+    /// Load of the ether, observed during the last finished window.
+    #[cfg(feature = "adaptive-listen-period")]
+    pub fn channel_load(&self) -> &ChannelLoad {
+        self.channel_load_estimator.last_load()
+    }
+
+    /// Returns load of the ether once the window of observation is over.
+    #[cfg(feature = "adaptive-listen-period")]
+    pub fn take_channel_load(&mut self) -> Option<ChannelLoad> {
+        self.channel_load_estimator.take_last_load()
+    }
+
+    /// Tells if any byte was heard from the ether
+    /// during the last `quiet_window` period of time.
+    #[cfg(feature = "carrier-sense")]
+    pub fn is_ether_busy(&self, current_time: ms, quiet_window: ms) -> bool {
+        match self.last_byte_received_at {
+            Some(received_at) => current_time.wrapping_sub(received_at) < quiet_window,
+            None => false,
+        }
     }
 
     /// Checks, if parser has packet being parsed, and then
     /// cheks if packet is not duplicated.
-    /// Returns packet if all checks were passed, or None otherwise.
-    pub fn receive(&mut self, current_time: ms) -> Option<Packet> {
-        let packet = match self.packet_bytes_parser.get_packet() {
-            None => return None,
-            Some(packet) => packet,
-        };
+    /// Returns packet along with the index of the interface, it came from,
+    /// if all checks were passed, or None otherwise.
+    /// Packets are filtered by one filter, so the packet heard trough
+    /// different interfaces is handled only once.
+    pub fn receive(&mut self, current_time: ms) -> Option<(Packet, usize)> {
+        let (packet, interface) =
+            self.packet_bytes_parsers
+                .iter_mut()
+                .enumerate()
+                .find_map(|(interface, parser)| {
+                    parser.get_packet().map(|packet| (packet, interface))
+                })?;
+
+        #[cfg(any(
+            feature = "sniffer",
+            feature = "addressing",
+            feature = "diagnostics",
+            feature = "link-ack"
+        ))]
+        let heard_packet = packet.clone();
 
         let packet = match self.filter_out_duplicated(packet, current_time) {
-            Err(_) => return None,
+            Err(ReceiverError::PacketDuplication) => {
+                #[cfg(any(
+                    feature = "sniffer",
+                    feature = "addressing",
+                    feature = "diagnostics",
+                    feature = "link-ack"
+                ))]
+                self.heard_duplicate.replace((heard_packet, interface));
+                return None;
+            }
+            Err(ReceiverError::DuplicationFilterOverloaded) => return None,
             Ok(packet) => packet,
         };
 
-        Some(packet)
+        Some((packet, interface))
     }
 
-    fn _receive_byte<I>(&mut self, interface_driver: &mut I)
-    where
-        I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
-    {
-        // No read guard
-        match interface_driver.read_ready() {
-            Ok(false) | Err(_) => return,
-            Ok(true) => (),
-        }
+    /// Returns the last packet, which was filtered out as duplicated one,
+    /// along with the index of the interface, it came from.
+    /// Such packets still tell, what the neighbour devices are doing.
+    #[cfg(any(
+        feature = "sniffer",
+        feature = "addressing",
+        feature = "diagnostics",
+        feature = "link-ack"
+    ))]
+    pub fn take_heard_duplicate(&mut self) -> Option<(Packet, usize)> {
+        self.heard_duplicate.take()
+    }
 
+    /// Reads bytes from the interface and feeds them to the parser.
+    /// Returns true if any byte was read.
+    fn _receive_byte(parser: &mut PacketBytesParser, interface: &mut dyn Interface) -> bool {
         let mut buf = [0u8; RECEIVER_READER_BUFFER_SIZE];
 
         // Empty read guard
-        if let Ok(red_count) = interface_driver.read(&mut buf) {
-            if red_count < RECEIVER_READER_BUFFER_SIZE {
-                return;
-            }
+        if interface.read_bytes(&mut buf) < RECEIVER_READER_BUFFER_SIZE {
+            return false;
         }
 
         for b in buf {
-            self.packet_bytes_parser.push_byte(b);
+            parser.push_byte(b);
         }
+        true
     }
 }
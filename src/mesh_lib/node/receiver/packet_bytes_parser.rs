@@ -34,16 +34,24 @@ impl PacketBytesParser {
             return;
         }
 
-        for _ in 0..PACKET_START_BYTES_COUNT {
-            self.bytes_buffer.pop_front().unwrap_or_else(|| 0u8);
-        }
-
-        let parsing_buffer: PacketSerializedBytes = self.bytes_buffer.iter().map(|b| *b).collect();
+        let parsing_buffer: PacketSerializedBytes = self
+            .bytes_buffer
+            .iter()
+            .skip(PACKET_START_BYTES_COUNT)
+            .map(|b| *b)
+            .collect();
 
         let got_packet = <Packet as Serializer>::deserialize(parsing_buffer);
 
         if got_packet.is_checksum_correct() && got_packet.has_correct_source_device_identifier() {
             self.parsed_packet.replace(got_packet);
+            // Bytes of the parsed packet shall not be mistaken
+            // for the start of the next one.
+            self.bytes_buffer.clear();
+        } else {
+            // Start bytes might be the tail of the previous packet
+            // followed by start bytes of the real one.
+            self.bytes_buffer.pop_front();
         }
     }
 
@@ -59,3 +67,72 @@ impl PacketBytesParser {
         self.parsed_packet.take()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::packet::{GeneralAddressType, PacketDataBytes, PacketState};
+
+    fn push_packet(parser: &mut PacketBytesParser, packet: Packet) {
+        for _ in 0..PACKET_START_BYTES_COUNT {
+            parser.push_byte(PACKET_START_BYTE);
+        }
+        for byte in packet.summarized().serialized() {
+            parser.push_byte(byte);
+        }
+    }
+
+    fn make_packet(id: u8, data: &[u8]) -> Packet {
+        Packet::new(
+            1,
+            GeneralAddressType::Broadcast.into(),
+            id,
+            1,
+            PacketState::Normal,
+            true,
+            PacketDataBytes::from_slice(data).unwrap(),
+        )
+    }
+
+    #[test]
+    fn packet_is_parsed_after_one_ending_with_start_byte() {
+        let mut parser = PacketBytesParser::new();
+
+        // Data byte is picked, so the checksum of the packet is equal to the start byte.
+        let zero_data_checksum = *make_packet(1, &[0])
+            .summarized()
+            .serialized()
+            .last()
+            .unwrap();
+        let first = make_packet(1, &[PACKET_START_BYTE.wrapping_sub(zero_data_checksum)]);
+        assert_eq!(
+            first.clone().summarized().serialized().last(),
+            Some(&PACKET_START_BYTE)
+        );
+        push_packet(&mut parser, first);
+        assert_eq!(parser.get_packet().map(|packet| packet.get_id()), Some(1));
+
+        push_packet(&mut parser, make_packet(2, &[0]));
+        assert_eq!(parser.get_packet().map(|packet| packet.get_id()), Some(2));
+    }
+
+    #[test]
+    fn packet_is_parsed_after_corrupted_one() {
+        let mut parser = PacketBytesParser::new();
+
+        for _ in 0..PACKET_START_BYTES_COUNT {
+            parser.push_byte(PACKET_START_BYTE);
+        }
+        let mut corrupted = make_packet(1, &[0]).summarized().serialized();
+        if let Some(checksum) = corrupted.last_mut() {
+            *checksum = checksum.wrapping_add(1);
+        }
+        for byte in corrupted {
+            parser.push_byte(byte);
+        }
+        assert!(parser.get_packet().is_none());
+
+        push_packet(&mut parser, make_packet(2, &[0]));
+        assert_eq!(parser.get_packet().map(|packet| packet.get_id()), Some(2));
+    }
+}
@@ -2,6 +2,7 @@ use super::ms;
 use heapless::Vec;
 
 use crate::mesh_lib::node::{
+    clock::Clock,
     constants::{RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD, RECEIVER_FILTER_REGISTRATION_SIZE},
     packet::{Packet, PacketFlagOps, PacketUniqueId, UniqueIdExtractor},
 };
@@ -30,17 +31,17 @@ impl Filter {
         }
     }
 
-    pub fn filter_out_duplicated(
+    pub fn filter_out_duplicated<C: Clock>(
         &mut self,
         packet: Packet,
-        current_time: ms,
+        clock: &C,
     ) -> Result<Packet, RegistrationError> {
         if !packet.is_ignore_duplication_flag_set() {
             Ok(packet)
         } else {
             match self._register_packet_entry(
                 <Packet as UniqueIdExtractor>::get_unique_id(&packet),
-                current_time,
+                clock.now(),
             ) {
                 Ok(()) => Ok(packet),
                 Err(error) => Err(error),
@@ -48,7 +49,8 @@ impl Filter {
         }
     }
 
-    pub fn update(&mut self, current_time: ms) {
+    pub fn update<C: Clock>(&mut self, clock: &C) {
+        let current_time = clock.now();
         let mut index_to_remove: Option<usize> = None;
 
         for (index, entry) in self.entry_registration_vec.iter().enumerate() {
@@ -65,6 +67,25 @@ impl Filter {
         }
     }
 
+    /// Period of time since the time of the `clock`, after which
+    /// the earliest registered entry expires.
+    pub fn time_till_next_expiration<C: Clock>(&self, clock: &C) -> Option<ms> {
+        let current_time = clock.now();
+        self.entry_registration_vec
+            .iter()
+            .map(|entry| {
+                (RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD + 1)
+                    .saturating_sub(current_time.wrapping_sub(entry.registered_at))
+            })
+            .min()
+    }
+
+    /// Count of registered entries.
+    #[cfg(feature = "fuzzing")]
+    pub fn len(&self) -> usize {
+        self.entry_registration_vec.len()
+    }
+
     fn _is_entry_present(&self, packet_id: PacketUniqueId) -> bool {
         self.entry_registration_vec
             .iter()
@@ -116,10 +137,10 @@ mod tests {
         let mut filter = Filter::new();
         let t: ms = 1000;
         let packet = make_packet(1, 0);
-        assert!(filter.filter_out_duplicated(packet.clone(), t).is_ok());
-        filter.update(t + RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD - 1);
+        assert!(filter.filter_out_duplicated(packet.clone(), &t).is_ok());
+        filter.update(&(t + RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD - 1));
         assert!(filter
-            .filter_out_duplicated(packet, t + RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD - 1)
+            .filter_out_duplicated(packet, &(t + RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD - 1))
             .is_err());
     }
 
@@ -128,11 +149,11 @@ mod tests {
         let mut filter = Filter::new();
         let t: ms = 1000;
         let packet = make_packet(1, 0);
-        assert!(filter.filter_out_duplicated(packet.clone(), t).is_ok());
-        filter.update(t + RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD + 1);
+        assert!(filter.filter_out_duplicated(packet.clone(), &t).is_ok());
+        filter.update(&(t + RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD + 1));
         // Entry gone — same packet can be re-registered
         assert!(filter
-            .filter_out_duplicated(packet, t + RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD + 1)
+            .filter_out_duplicated(packet, &(t + RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD + 1))
             .is_ok());
     }
 
@@ -140,16 +161,16 @@ mod tests {
     fn duplicate_detected_within_period_across_u32_wraparound() {
         let mut filter = Filter::new();
         // 500ms before overflow
-        let near_max: ms = u32::MAX - 500;
+        let near_max: ms = ms::MAX - 500;
         let packet = make_packet(1, 0);
         assert!(filter
-            .filter_out_duplicated(packet.clone(), near_max)
+            .filter_out_duplicated(packet.clone(), &near_max)
             .is_ok());
 
         let t_100ms = near_max.wrapping_add(100);
-        filter.update(t_100ms);
+        filter.update(&t_100ms);
         assert!(
-            filter.filter_out_duplicated(packet, t_100ms).is_err(),
+            filter.filter_out_duplicated(packet, &t_100ms).is_err(),
             "entry evicted after 100ms; old code overflows deadline to 499 then fires immediately"
         );
     }
@@ -158,17 +179,30 @@ mod tests {
     fn entry_expires_after_period_across_u32_wraparound() {
         let mut filter = Filter::new();
         // 500ms before overflow
-        let near_max: ms = u32::MAX - 500;
+        let near_max: ms = ms::MAX - 500;
         let packet = make_packet(1, 0);
         assert!(filter
-            .filter_out_duplicated(packet.clone(), near_max)
+            .filter_out_duplicated(packet.clone(), &near_max)
             .is_ok());
 
         let t_after = near_max.wrapping_add(RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD + 1);
-        filter.update(t_after);
+        filter.update(&t_after);
         assert!(
-            filter.filter_out_duplicated(packet, t_after).is_ok(),
+            filter.filter_out_duplicated(packet, &t_after).is_ok(),
             "entry not evicted after 1001ms across wraparound"
         );
     }
+
+    #[test]
+    fn time_till_next_expiration_is_taken_from_earliest_entry() {
+        let mut filter = Filter::new();
+        assert!(filter.time_till_next_expiration(&(1000 as ms)).is_none());
+
+        let _ = filter.filter_out_duplicated(make_packet(1, 0), &(1000 as ms));
+        let _ = filter.filter_out_duplicated(make_packet(1, 1), &(1200 as ms));
+        assert_eq!(
+            filter.time_till_next_expiration(&(1300 as ms)),
+            Some(RECEIVER_FILTER_DUPLICATE_IGNORE_PERIOD + 1 - 300)
+        );
+    }
 }
@@ -0,0 +1,292 @@
+use heapless::Vec;
+
+use super::constants::{RELIABLE_SENDS_COUNT, RELIABLE_SEND_MAX_RETRIES};
+use super::{ms, IdType, Packet};
+
+/// Status of delivery of the packet, which was sent
+/// by `Node` `send_reliable` method.
+#[derive(PartialEq, Eq, Clone)]
+pub enum DeliveryStatus {
+    /// Acknowledgement is not received yet, and the
+    /// packet will be retransmitted if needed.
+    Pending,
+
+    /// Acknowledgement is received from the end-receiver device.
+    Delivered,
+
+    /// Acknowledgement is not received after all the retransmissions.
+    Failed,
+}
+
+impl core::fmt::Debug for DeliveryStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DeliveryStatus::Pending => write!(f, "Pending"),
+            DeliveryStatus::Delivered => write!(f, "Delivered"),
+            DeliveryStatus::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+type AttemptIds = Vec<IdType, { RELIABLE_SEND_MAX_RETRIES as usize + 1 }>;
+
+struct ReliableSend {
+    delivery_id: IdType,
+    packet: Packet,
+    /// Each retransmission gets it's own id, so it is not
+    /// filtered out as duplication by other devices.
+    attempt_ids: AttemptIds,
+    retries_left: u8,
+    ack_timeout: ms,
+    /// Is set during the next `update`, after the attempt is made.
+    attempted_at: Option<ms>,
+    status: DeliveryStatus,
+}
+
+impl ReliableSend {
+    /// Timeout is doubled after each attempt.
+    fn current_ack_timeout(&self) -> ms {
+        let attempt = self.attempt_ids.len().saturating_sub(1) as u32;
+        self.ack_timeout.saturating_mul(1 << attempt)
+    }
+
+    fn time_till_timeout(&self, current_time: ms) -> Option<ms> {
+        self.attempted_at.map(|attempted_at| {
+            self.current_ack_timeout()
+                .saturating_sub(current_time.wrapping_sub(attempted_at))
+        })
+    }
+}
+
+pub struct ReliableSendsLimitReached;
+
+/// Tracks delivery of reliably sent packets, and tells when
+/// the packet needs to be retransmitted.
+///
+/// Finished deliveries are kept, till their status is overwritten by the new ones.
+pub struct ReliableSends {
+    sends: Vec<ReliableSend, RELIABLE_SENDS_COUNT>,
+}
+
+impl ReliableSends {
+    pub fn new() -> ReliableSends {
+        ReliableSends { sends: Vec::new() }
+    }
+
+    /// Tells if one more delivery can be tracked.
+    pub fn has_free_slot(&self) -> bool {
+        !self.sends.is_full()
+            || self
+                .sends
+                .iter()
+                .any(|send| send.status != DeliveryStatus::Pending)
+    }
+
+    /// Starts tracking the delivery of the `packet`, which
+    /// is already sent, and has it's id being set.
+    pub fn register(
+        &mut self,
+        packet: Packet,
+        ack_timeout: ms,
+        retries: u8,
+    ) -> Result<(), ReliableSendsLimitReached> {
+        let delivery_id = packet.get_id();
+
+        // Ids are reused after overflow.
+        self.sends.retain(|send| send.delivery_id != delivery_id);
+
+        if self.sends.is_full() {
+            match self
+                .sends
+                .iter()
+                .position(|send| send.status != DeliveryStatus::Pending)
+            {
+                Some(index) => {
+                    self.sends.remove(index);
+                }
+                None => return Err(ReliableSendsLimitReached),
+            }
+        }
+
+        let mut attempt_ids = AttemptIds::new();
+        let _ = attempt_ids.push(delivery_id);
+
+        match self.sends.push(ReliableSend {
+            delivery_id,
+            packet,
+            attempt_ids,
+            retries_left: retries.min(RELIABLE_SEND_MAX_RETRIES),
+            ack_timeout,
+            attempted_at: None,
+            status: DeliveryStatus::Pending,
+        }) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(ReliableSendsLimitReached),
+        }
+    }
+
+    /// Marks the delivery as finished, in case if the `ack`
+    /// acknowledges any of it's attempts.
+    pub fn acknowledge(&mut self, ack: &Packet) {
+        if let Some(send) = self.sends.iter_mut().find(|send| {
+            send.status == DeliveryStatus::Pending
+                && send
+                    .packet
+                    .is_destination_reached(ack.source_device_identifier.into())
+                && send.attempt_ids.contains(&ack.get_id())
+        }) {
+            send.status = DeliveryStatus::Delivered;
+        }
+    }
+
+    pub fn status(&self, delivery_id: IdType) -> Option<DeliveryStatus> {
+        self.sends
+            .iter()
+            .find(|send| send.delivery_id == delivery_id)
+            .map(|send| send.status.clone())
+    }
+
+    /// Stamps newly made attempts with `current_time`, and marks deliveries,
+    /// which have no retransmissions left - as failed.
+    pub fn update(&mut self, current_time: ms) {
+        for send in self.sends.iter_mut() {
+            if send.status != DeliveryStatus::Pending {
+                continue;
+            }
+            send.attempted_at.get_or_insert(current_time);
+            if send.retries_left == 0 && send.time_till_timeout(current_time) == Some(0) {
+                send.status = DeliveryStatus::Failed;
+            }
+        }
+    }
+
+    /// Returns copy of the packet, which acknowledgement timed out,
+    /// together with it's delivery id.
+    pub fn next_retransmission(&self, current_time: ms) -> Option<(IdType, Packet)> {
+        self.sends
+            .iter()
+            .find(|send| {
+                send.status == DeliveryStatus::Pending
+                    && send.retries_left > 0
+                    && send.time_till_timeout(current_time) == Some(0)
+            })
+            .map(|send| (send.delivery_id, send.packet.clone()))
+    }
+
+    /// Records retransmission of the packet with new `attempt_id`.
+    pub fn record_retransmission(&mut self, delivery_id: IdType, attempt_id: IdType) {
+        if let Some(send) = self
+            .sends
+            .iter_mut()
+            .find(|send| send.delivery_id == delivery_id)
+        {
+            send.retries_left = send.retries_left.saturating_sub(1);
+            send.attempted_at = None;
+            let _ = send.attempt_ids.push(attempt_id);
+        }
+    }
+
+    /// Period of time since `current_time`, after which
+    /// the nearest acknowledgement timeout happens.
+    pub fn time_till_next_timeout(&self, current_time: ms) -> Option<ms> {
+        self.sends
+            .iter()
+            .filter(|send| send.status == DeliveryStatus::Pending)
+            .map(|send| send.time_till_timeout(current_time).unwrap_or(0))
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::packet::{PacketDataBytes, PacketState};
+
+    const ACK_TIMEOUT: ms = 100;
+
+    fn make_packet(id: IdType) -> Packet {
+        Packet::new(
+            1,
+            2,
+            id,
+            1,
+            PacketState::ReliableSend,
+            true,
+            PacketDataBytes::new(),
+        )
+    }
+
+    fn make_ack(id: IdType) -> Packet {
+        match make_packet(id).mutated() {
+            Ok(ack) => ack,
+            Err(_) => panic!("Packet is addressed to exact device"),
+        }
+    }
+
+    #[test]
+    fn ack_finishes_delivery() {
+        let mut sends = ReliableSends::new();
+        let _ = sends.register(make_packet(5), ACK_TIMEOUT, 1);
+        sends.update(0);
+
+        assert!(sends.status(5) == Some(DeliveryStatus::Pending));
+        sends.acknowledge(&make_ack(5));
+        assert!(sends.status(5) == Some(DeliveryStatus::Delivered));
+        assert!(sends.next_retransmission(ACK_TIMEOUT).is_none());
+    }
+
+    #[test]
+    fn ack_of_earlier_attempt_finishes_delivery() {
+        let mut sends = ReliableSends::new();
+        let _ = sends.register(make_packet(5), ACK_TIMEOUT, 1);
+        sends.update(0);
+
+        let (delivery_id, _) = sends
+            .next_retransmission(ACK_TIMEOUT)
+            .expect("Packet shall be retransmitted");
+        sends.record_retransmission(delivery_id, 6);
+
+        sends.acknowledge(&make_ack(5));
+        assert!(sends.status(5) == Some(DeliveryStatus::Delivered));
+    }
+
+    #[test]
+    fn timeout_is_doubled_on_retransmission() {
+        let mut sends = ReliableSends::new();
+        let _ = sends.register(make_packet(5), ACK_TIMEOUT, 2);
+        sends.update(0);
+        assert!(sends.next_retransmission(ACK_TIMEOUT - 1).is_none());
+
+        sends.record_retransmission(5, 6);
+        sends.update(ACK_TIMEOUT);
+        assert_eq!(
+            sends.time_till_next_timeout(ACK_TIMEOUT),
+            Some(2 * ACK_TIMEOUT)
+        );
+    }
+
+    #[test]
+    fn delivery_fails_without_retries_left() {
+        let mut sends = ReliableSends::new();
+        let _ = sends.register(make_packet(5), ACK_TIMEOUT, 0);
+        sends.update(0);
+        sends.update(ACK_TIMEOUT);
+
+        assert!(sends.status(5) == Some(DeliveryStatus::Failed));
+        assert!(sends.time_till_next_timeout(ACK_TIMEOUT).is_none());
+    }
+
+    #[test]
+    fn finished_delivery_gives_place_to_new_one() {
+        let mut sends = ReliableSends::new();
+        for id in 0..RELIABLE_SENDS_COUNT as IdType {
+            let _ = sends.register(make_packet(id), ACK_TIMEOUT, 0);
+        }
+        assert!(!sends.has_free_slot());
+
+        sends.acknowledge(&make_ack(0));
+        assert!(sends.has_free_slot());
+        assert!(sends.register(make_packet(100), ACK_TIMEOUT, 0).is_ok());
+        assert!(sends.status(0).is_none());
+    }
+}
@@ -3,6 +3,8 @@ use crate::{ExactAddressType, GeneralAddressType};
 pub use super::packet::PacketState;
 
 use super::packet::{Packet, PacketLifetimeEnded, RespondToBroadcastAddressError};
+#[cfg(feature = "pubsub")]
+use super::pubsub::{topic_of, Topics};
 
 /// Does the Packet routing of the network.
 ///
@@ -11,8 +13,11 @@ use super::packet::{Packet, PacketLifetimeEnded, RespondToBroadcastAddressError}
 ///   transactions, and does their further processing.
 /// * Catches packets, that were send to this device.
 /// * Transits packets, that were sent to other devices.
+/// * Catches published values only of topics, the device is interested in.
 pub struct Router {
     current_device_identifier: ExactAddressType,
+    #[cfg(feature = "pubsub")]
+    topics: Topics,
 }
 
 pub enum RouteResult {
@@ -42,9 +47,28 @@ impl Router {
     pub fn new(current_device_identifier: ExactAddressType) -> Self {
         Self {
             current_device_identifier,
+            #[cfg(feature = "pubsub")]
+            topics: Topics::new(),
         }
     }
 
+    #[cfg(feature = "pubsub")]
+    pub fn topics(&self) -> &Topics {
+        &self.topics
+    }
+
+    #[cfg(feature = "pubsub")]
+    pub fn topics_mut(&mut self) -> &mut Topics {
+        &mut self.topics
+    }
+
+    /// Changes the address of the current device, which
+    /// is used to tell if the packet has reached it's destination.
+    #[cfg(feature = "addressing")]
+    pub fn set_current_device_identifier(&mut self, current_device_identifier: ExactAddressType) {
+        self.current_device_identifier = current_device_identifier;
+    }
+
     /// This method is used to handle the packet, that was sent to the all
     /// devices of the network.
     ///
@@ -53,16 +77,44 @@ impl Router {
     /// reached it's destination, and
     /// * Checks if packet can be transferred further, and if so - transfers it further into the
     /// network.
+    ///
+    /// Packets of topics, which the device is not interested in - are only transferred further,
+    /// so the device is not woken up by them.
     fn handle_broadcast(&self, packet: Packet) -> Result<RouteResult, RouteError> {
-        let received = packet.clone();
+        let received = match self.is_interested(&packet) {
+            true => Some(packet.clone()),
+            false => None,
+        };
         let transit: Option<Packet> = match packet.deacrease_lifetime() {
             Ok(packet) => Some(packet),
             Err(PacketLifetimeEnded) => None,
         };
-        if let Some(transit) = transit {
-            return Ok(RouteResult::ReceivedAndTransit { received, transit });
+        match (received, transit) {
+            (Some(received), Some(transit)) => {
+                Ok(RouteResult::ReceivedAndTransit { received, transit })
+            }
+            (Some(received), None) => Ok(RouteResult::ReceivedOnly(received)),
+            (None, Some(transit)) => Ok(RouteResult::TransitOnly(transit)),
+            (None, None) => Err(RouteError::PacketLifetimeEnded),
+        }
+    }
+
+    /// Tells if the broadcasted packet shall be handled by the device.
+    fn is_interested(&self, packet: &Packet) -> bool {
+        match packet.get_spec_state() {
+            #[cfg(feature = "pubsub")]
+            PacketState::Publish => {
+                matches!(topic_of(packet), Some(topic) if self.topics.is_interested(topic))
+            }
+            #[cfg(feature = "pubsub")]
+            PacketState::RetainedRequest => {
+                matches!(topic_of(packet), Some(topic) if self.topics.is_retained(topic))
+            }
+            // Device, which has no topics, is not interested in any of them.
+            #[cfg(not(feature = "pubsub"))]
+            PacketState::Publish | PacketState::RetainedRequest => false,
+            _ => true,
         }
-        return Ok(RouteResult::ReceivedOnly(received));
     }
 
     fn keep_copy_and_prepare_transit(&self, packet: Packet) -> Result<RouteResult, RouteError> {
@@ -91,6 +143,23 @@ impl Router {
                 PacketState::AcceptTransaction => Ok(RouteResult::TransitOnly(packet.mutated()?)),
                 PacketState::InitTransaction => self.keep_copy_and_prepare_transit(packet),
                 PacketState::FinishTransaction => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::PollMailbox => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::ReliableSend => self.keep_copy_and_prepare_transit(packet),
+                PacketState::ReliableAck => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::LinkAck => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::TimeSync => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::AddressProbe => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::AddressConflict => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::AddressLeaseRequest => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::AddressLease => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::RpcRequest => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::RpcResponse => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::Publish => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::RetainedRequest => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::ParameterRequest => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::ParameterResponse => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::DiagnosticsRequest => Ok(RouteResult::ReceivedOnly(packet)),
+                PacketState::DiagnosticsResponse => Ok(RouteResult::ReceivedOnly(packet)),
             };
         }
 
@@ -104,3 +173,102 @@ impl Router {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "pubsub")]
+    use crate::mesh_lib::node::pubsub::{make_publish_data, make_retained_request_data};
+    use crate::mesh_lib::node::PacketDataBytes;
+    use crate::LifeTimeType;
+
+    fn make_router() -> Router {
+        Router::new(ExactAddressType::new(2).unwrap())
+    }
+
+    fn make_broadcast(lifetime: LifeTimeType, state: PacketState, data: PacketDataBytes) -> Packet {
+        Packet::new(
+            1,
+            GeneralAddressType::Broadcast.into(),
+            0,
+            lifetime,
+            state,
+            true,
+            data,
+        )
+    }
+
+    #[test]
+    #[cfg(feature = "pubsub")]
+    fn publication_of_subscribed_topic_is_received_and_forwarded() {
+        let mut router = make_router();
+        let _ = router.topics_mut().subscribe(7);
+
+        let packet = make_broadcast(2, PacketState::Publish, make_publish_data(7, b"on"));
+
+        assert!(matches!(
+            router.route(packet),
+            Ok(RouteResult::ReceivedAndTransit { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "pubsub")]
+    fn publication_of_other_topic_is_only_forwarded() {
+        let mut router = make_router();
+        let _ = router.topics_mut().subscribe(7);
+
+        let packet = make_broadcast(2, PacketState::Publish, make_publish_data(8, b"on"));
+        assert!(matches!(
+            router.route(packet),
+            Ok(RouteResult::TransitOnly(_))
+        ));
+
+        let last_hop = make_broadcast(1, PacketState::Publish, make_publish_data(8, b"on"));
+        assert!(matches!(
+            router.route(last_hop),
+            Err(RouteError::PacketLifetimeEnded)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "pubsub")]
+    fn retained_request_is_received_by_broker_only() {
+        let mut router = make_router();
+        let _ = router.topics_mut().subscribe(7);
+
+        let request = || {
+            make_broadcast(
+                2,
+                PacketState::RetainedRequest,
+                make_retained_request_data(7, 2),
+            )
+        };
+        assert!(matches!(
+            router.route(request()),
+            Ok(RouteResult::TransitOnly(_))
+        ));
+
+        let _ = router.topics_mut().retain(7);
+        assert!(matches!(
+            router.route(request()),
+            Ok(RouteResult::ReceivedAndTransit { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(not(feature = "pubsub"))]
+    fn publication_is_only_forwarded_without_pubsub() {
+        let router = make_router();
+
+        let packet = make_broadcast(
+            2,
+            PacketState::Publish,
+            PacketDataBytes::from_slice(&[7, b'o', b'n']).unwrap(),
+        );
+        assert!(matches!(
+            router.route(packet),
+            Ok(RouteResult::TransitOnly(_))
+        ));
+    }
+}
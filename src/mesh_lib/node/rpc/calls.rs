@@ -0,0 +1,276 @@
+use heapless::Vec;
+
+use super::super::constants::RPC_CALLS_COUNT;
+use super::super::packet::PacketState;
+use super::super::{ms, ExactAddressType, IdType, Packet};
+use super::{parse_response, RpcCallStatus, RpcMethodId};
+
+struct RpcCall {
+    /// Id of the request, which is kept by the response as well.
+    request_id: IdType,
+    destination: ExactAddressType,
+    method: RpcMethodId,
+    /// Type of the response packet, so calls of RPC methods
+    /// and calls of parameters are not mistaken for each other.
+    response_state: PacketState,
+    timeout: ms,
+    /// Is set during the next `update`, after the request is sent.
+    called_at: Option<ms>,
+    status: RpcCallStatus,
+}
+
+impl RpcCall {
+    fn time_till_timeout(&self, current_time: ms) -> Option<ms> {
+        self.called_at.map(|called_at| {
+            self.timeout
+                .saturating_sub(current_time.wrapping_sub(called_at))
+        })
+    }
+}
+
+pub struct RpcCallsLimitReached;
+
+/// Tracks remote calls, which wait for responses, and
+/// correlates responses with calls by id of the request.
+///
+/// Finished calls are kept, till their status is overwritten by the new ones.
+pub struct RpcCalls {
+    calls: Vec<RpcCall, RPC_CALLS_COUNT>,
+}
+
+impl RpcCalls {
+    pub fn new() -> RpcCalls {
+        RpcCalls { calls: Vec::new() }
+    }
+
+    /// Tells if one more call can be tracked.
+    pub fn has_free_slot(&self) -> bool {
+        !self.calls.is_full()
+            || self
+                .calls
+                .iter()
+                .any(|call| call.status != RpcCallStatus::Pending)
+    }
+
+    /// Starts waiting for the response of `response_state` type
+    /// to the request with `request_id`.
+    pub fn register(
+        &mut self,
+        request_id: IdType,
+        destination: ExactAddressType,
+        method: RpcMethodId,
+        response_state: PacketState,
+        timeout: ms,
+    ) -> Result<(), RpcCallsLimitReached> {
+        // Ids are reused after overflow.
+        self.calls.retain(|call| call.request_id != request_id);
+
+        if self.calls.is_full() {
+            match self
+                .calls
+                .iter()
+                .position(|call| call.status != RpcCallStatus::Pending)
+            {
+                Some(index) => {
+                    self.calls.remove(index);
+                }
+                None => return Err(RpcCallsLimitReached),
+            }
+        }
+
+        match self.calls.push(RpcCall {
+            request_id,
+            destination,
+            method,
+            response_state,
+            timeout,
+            called_at: None,
+            status: RpcCallStatus::Pending,
+        }) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(RpcCallsLimitReached),
+        }
+    }
+
+    /// Finishes the call, which the `response` is made for.
+    pub fn handle_response(&mut self, response: &Packet) {
+        let (method, status) = match parse_response(response) {
+            Some(parsed) => parsed,
+            None => return,
+        };
+        if let Some(call) = self.calls.iter_mut().find(|call| {
+            call.status == RpcCallStatus::Pending
+                && call.request_id == response.get_id()
+                && call.destination.get() == response.source_device_identifier
+                && call.method == method
+                && call.response_state == response.get_spec_state()
+        }) {
+            call.status = status;
+        }
+    }
+
+    /// Tells the status of the call, which waits for the response of `response_state` type.
+    pub fn status(&self, request_id: IdType, response_state: PacketState) -> Option<RpcCallStatus> {
+        self.calls
+            .iter()
+            .find(|call| call.request_id == request_id && call.response_state == response_state)
+            .map(|call| call.status.clone())
+    }
+
+    /// Stamps newly made calls with `current_time`, and marks
+    /// calls, which were not answered in time - as timed out.
+    pub fn update(&mut self, current_time: ms) {
+        for call in self.calls.iter_mut() {
+            if call.status != RpcCallStatus::Pending {
+                continue;
+            }
+            call.called_at.get_or_insert(current_time);
+            if call.time_till_timeout(current_time) == Some(0) {
+                call.status = RpcCallStatus::TimedOut;
+            }
+        }
+    }
+
+    /// Period of time since `current_time`, after which
+    /// the nearest call times out.
+    pub fn time_till_next_timeout(&self, current_time: ms) -> Option<ms> {
+        self.calls
+            .iter()
+            .filter(|call| call.status == RpcCallStatus::Pending)
+            .map(|call| call.time_till_timeout(current_time).unwrap_or(0))
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{make_request_data, make_response_data, RpcArguments, RpcPayload};
+    use super::*;
+    use crate::mesh_lib::node::packet::PacketState;
+
+    const TIMEOUT: ms = 100;
+    const READ_SENSOR: RpcMethodId = 3;
+
+    fn server() -> ExactAddressType {
+        ExactAddressType::new(2).unwrap()
+    }
+
+    fn make_response(source: u8, id: IdType, method: RpcMethodId, status: RpcCallStatus) -> Packet {
+        let request = Packet::new(
+            1,
+            source,
+            id,
+            3,
+            PacketState::RpcRequest,
+            true,
+            make_request_data(method, &RpcArguments::new()),
+        );
+        let mut response = match request.mutated() {
+            Ok(response) => response,
+            Err(_) => panic!("Packet is addressed to exact device"),
+        };
+        response.set_data(make_response_data(method, status));
+        response
+    }
+
+    fn answered(payload: &[u8]) -> RpcCallStatus {
+        RpcCallStatus::Answered(RpcPayload::from_slice(payload).unwrap())
+    }
+
+    #[test]
+    fn response_finishes_call() {
+        let mut calls = RpcCalls::new();
+        let _ = calls.register(5, server(), READ_SENSOR, PacketState::RpcResponse, TIMEOUT);
+        calls.update(0);
+        assert!(calls.status(5, PacketState::RpcResponse) == Some(RpcCallStatus::Pending));
+
+        calls.handle_response(&make_response(2, 5, READ_SENSOR, answered(&[42])));
+
+        assert!(calls.status(5, PacketState::RpcResponse) == Some(answered(&[42])));
+        assert!(calls.time_till_next_timeout(0).is_none());
+    }
+
+    #[test]
+    fn response_is_correlated_by_request_id() {
+        let mut calls = RpcCalls::new();
+        let _ = calls.register(5, server(), READ_SENSOR, PacketState::RpcResponse, TIMEOUT);
+        let _ = calls.register(6, server(), READ_SENSOR, PacketState::RpcResponse, TIMEOUT);
+        calls.update(0);
+
+        calls.handle_response(&make_response(2, 6, READ_SENSOR, answered(&[6])));
+        calls.handle_response(&make_response(2, 5, READ_SENSOR, answered(&[5])));
+
+        assert!(calls.status(5, PacketState::RpcResponse) == Some(answered(&[5])));
+        assert!(calls.status(6, PacketState::RpcResponse) == Some(answered(&[6])));
+    }
+
+    #[test]
+    fn response_of_other_device_is_ignored() {
+        let mut calls = RpcCalls::new();
+        let _ = calls.register(5, server(), READ_SENSOR, PacketState::RpcResponse, TIMEOUT);
+        calls.update(0);
+
+        calls.handle_response(&make_response(3, 5, READ_SENSOR, answered(&[42])));
+        calls.handle_response(&make_response(2, 5, READ_SENSOR + 1, answered(&[42])));
+
+        assert!(calls.status(5, PacketState::RpcResponse) == Some(RpcCallStatus::Pending));
+    }
+
+    #[test]
+    fn parameter_response_does_not_finish_rpc_call() {
+        let mut calls = RpcCalls::new();
+        let _ = calls.register(5, server(), READ_SENSOR, PacketState::RpcResponse, TIMEOUT);
+        calls.update(0);
+
+        let mut response = make_response(2, 5, READ_SENSOR, answered(&[42]));
+        response.set_spec_state(PacketState::ParameterResponse);
+        calls.handle_response(&response);
+
+        assert!(calls.status(5, PacketState::RpcResponse) == Some(RpcCallStatus::Pending));
+        assert!(calls.status(5, PacketState::ParameterResponse).is_none());
+    }
+
+    #[test]
+    fn unanswered_call_times_out() {
+        let mut calls = RpcCalls::new();
+        let _ = calls.register(5, server(), READ_SENSOR, PacketState::RpcResponse, TIMEOUT);
+        calls.update(10);
+        assert_eq!(calls.time_till_next_timeout(10), Some(TIMEOUT));
+
+        calls.update(10 + TIMEOUT - 1);
+        assert!(calls.status(5, PacketState::RpcResponse) == Some(RpcCallStatus::Pending));
+        calls.update(10 + TIMEOUT);
+        assert!(calls.status(5, PacketState::RpcResponse) == Some(RpcCallStatus::TimedOut));
+
+        // Late response does not change the status.
+        calls.handle_response(&make_response(2, 5, READ_SENSOR, answered(&[42])));
+        assert!(calls.status(5, PacketState::RpcResponse) == Some(RpcCallStatus::TimedOut));
+    }
+
+    #[test]
+    fn finished_call_gives_place_to_new_one() {
+        let mut calls = RpcCalls::new();
+        for id in 0..RPC_CALLS_COUNT as IdType {
+            let _ = calls.register(id, server(), READ_SENSOR, PacketState::RpcResponse, TIMEOUT);
+        }
+        assert!(!calls.has_free_slot());
+
+        calls.handle_response(&make_response(
+            2,
+            0,
+            READ_SENSOR,
+            RpcCallStatus::UnknownMethod,
+        ));
+        assert!(calls.has_free_slot());
+        assert!(calls
+            .register(
+                100,
+                server(),
+                READ_SENSOR,
+                PacketState::RpcResponse,
+                TIMEOUT
+            )
+            .is_ok());
+        assert!(calls.status(0, PacketState::RpcResponse).is_none());
+    }
+}
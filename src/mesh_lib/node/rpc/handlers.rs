@@ -0,0 +1,164 @@
+use heapless::Vec;
+
+use super::super::constants::RPC_HANDLERS_COUNT;
+use super::super::Packet;
+use super::{make_response_data, parse_request, RpcCallStatus, RpcHandler, RpcMethodId};
+
+pub struct RpcHandlersLimitReached;
+
+/// Keeps handlers of methods, which other devices can call,
+/// and answers their requests.
+pub struct RpcHandlers {
+    handlers: Vec<(RpcMethodId, RpcHandler), RPC_HANDLERS_COUNT>,
+}
+
+impl RpcHandlers {
+    pub fn new() -> RpcHandlers {
+        RpcHandlers {
+            handlers: Vec::new(),
+        }
+    }
+
+    /// Registers the `handler` of the `method`.
+    /// Previously registered handler of the same method is replaced.
+    pub fn register(
+        &mut self,
+        method: RpcMethodId,
+        handler: RpcHandler,
+    ) -> Result<(), RpcHandlersLimitReached> {
+        if let Some(registered) = self.handlers.iter_mut().find(|(id, _)| *id == method) {
+            registered.1 = handler;
+            return Ok(());
+        }
+        match self.handlers.push((method, handler)) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(RpcHandlersLimitReached),
+        }
+    }
+
+    pub fn unregister(&mut self, method: RpcMethodId) {
+        self.handlers.retain(|(id, _)| *id != method);
+    }
+
+    /// Calls the handler of the method, requested by the `request`, and
+    /// returns the response packet. The response is made by mutation of the request,
+    /// so it is addressed to the calling device, and has the same id.
+    ///
+    /// Returns `None` in case if the request has no method id, or it is addressed
+    /// to all devices, which shall not answer.
+    pub fn answer(&self, request: &Packet) -> Option<Packet> {
+        let (method, arguments) = parse_request(request)?;
+        let status = match self.handlers.iter().find(|(id, _)| *id == method) {
+            Some((_, handler)) => match handler(arguments) {
+                Some(payload) => RpcCallStatus::Answered(payload),
+                None => RpcCallStatus::Failed,
+            },
+            None => RpcCallStatus::UnknownMethod,
+        };
+
+        let mut response = request.clone().mutated().ok()?;
+        response.set_data(make_response_data(method, status));
+        Some(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{make_request_data, parse_response, RpcArguments, RpcPayload};
+    use super::*;
+    use crate::mesh_lib::node::packet::PacketState;
+
+    const READ_SENSOR: RpcMethodId = 3;
+
+    fn read_sensor(arguments: &[u8]) -> Option<RpcPayload> {
+        match arguments {
+            [channel] => RpcPayload::from_slice(&[*channel, 42]).ok(),
+            _ => None,
+        }
+    }
+
+    fn make_request(destination: u8, method: RpcMethodId, arguments: &[u8]) -> Packet {
+        Packet::new(
+            1,
+            destination,
+            7,
+            3,
+            PacketState::RpcRequest,
+            true,
+            make_request_data(method, &RpcArguments::from_slice(arguments).unwrap()),
+        )
+    }
+
+    #[test]
+    fn handler_result_is_responded() {
+        let mut handlers = RpcHandlers::new();
+        let _ = handlers.register(READ_SENSOR, read_sensor);
+
+        let response = handlers
+            .answer(&make_request(2, READ_SENSOR, &[5]))
+            .expect("Request shall be answered");
+
+        assert!(response.get_spec_state() == PacketState::RpcResponse);
+        assert_eq!(response.source_device_identifier, 2);
+        assert_eq!(response.get_destination_device_identifier(), 1);
+        assert_eq!(response.get_id(), 7);
+        assert!(matches!(
+            parse_response(&response),
+            Some((READ_SENSOR, RpcCallStatus::Answered(payload))) if payload == [5, 42]
+        ));
+    }
+
+    #[test]
+    fn failure_of_handler_is_responded() {
+        let mut handlers = RpcHandlers::new();
+        let _ = handlers.register(READ_SENSOR, read_sensor);
+
+        let response = handlers
+            .answer(&make_request(2, READ_SENSOR, &[]))
+            .expect("Request shall be answered");
+
+        assert!(matches!(
+            parse_response(&response),
+            Some((READ_SENSOR, RpcCallStatus::Failed))
+        ));
+    }
+
+    #[test]
+    fn unknown_method_is_responded() {
+        let mut handlers = RpcHandlers::new();
+        let _ = handlers.register(READ_SENSOR, read_sensor);
+        handlers.unregister(READ_SENSOR);
+
+        let response = handlers
+            .answer(&make_request(2, READ_SENSOR, &[5]))
+            .expect("Request shall be answered");
+
+        assert!(matches!(
+            parse_response(&response),
+            Some((READ_SENSOR, RpcCallStatus::UnknownMethod))
+        ));
+    }
+
+    #[test]
+    fn broadcast_request_is_not_answered() {
+        let mut handlers = RpcHandlers::new();
+        let _ = handlers.register(READ_SENSOR, read_sensor);
+
+        assert!(handlers
+            .answer(&make_request(0, READ_SENSOR, &[5]))
+            .is_none());
+    }
+
+    #[test]
+    fn handlers_are_limited() {
+        let mut handlers = RpcHandlers::new();
+        for method in 0..RPC_HANDLERS_COUNT as RpcMethodId {
+            assert!(handlers.register(method, read_sensor).is_ok());
+        }
+        // Handler of already registered method is replaced.
+        assert!(handlers.register(0, read_sensor).is_ok());
+        assert!(handlers
+            .register(RPC_HANDLERS_COUNT as RpcMethodId, read_sensor)
+            .is_err());
+    }
+}
@@ -0,0 +1,119 @@
+mod calls;
+mod handlers;
+
+use heapless::Vec;
+
+use super::packet::CONTENT_SIZE;
+use super::{Packet, PacketDataBytes};
+
+pub use calls::{RpcCalls, RpcCallsLimitReached};
+pub use handlers::{RpcHandlers, RpcHandlersLimitReached};
+
+/// Identifier of the method, which is called remotely.
+pub type RpcMethodId = u8;
+
+/// Arguments of the remote call. The content of the request
+/// keeps the method id, followed by the arguments.
+pub type RpcArguments = Vec<u8, { CONTENT_SIZE - METHOD_ID_SIZE }>;
+
+/// Result of the remote call. The content of the response
+/// keeps the method id and the status, followed by the result.
+pub type RpcPayload = Vec<u8, { CONTENT_SIZE - METHOD_ID_SIZE - STATUS_SIZE }>;
+
+/// Handler of the method, which is registered by `Node` `register_rpc_handler` method.
+/// Takes arguments of the call, and returns the result, or `None` in case if it has failed.
+pub type RpcHandler = fn(arguments: &[u8]) -> Option<RpcPayload>;
+
+const METHOD_ID_SIZE: usize = 1;
+const STATUS_SIZE: usize = 1;
+
+const STATUS_OK: u8 = 0;
+const STATUS_UNKNOWN_METHOD: u8 = 1;
+const STATUS_FAILED: u8 = 2;
+
+/// Status of the remote call, which was made by `Node` `send_rpc_request` method.
+#[derive(PartialEq, Eq, Clone)]
+pub enum RpcCallStatus {
+    /// Response is not received yet.
+    Pending,
+
+    /// Handler of the method has produced the result.
+    Answered(RpcPayload),
+
+    /// The called device has no handler registered for the method.
+    UnknownMethod,
+
+    /// Handler of the method has failed.
+    Failed,
+
+    /// Response is not received during the timeout.
+    TimedOut,
+}
+
+impl core::fmt::Debug for RpcCallStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RpcCallStatus::Pending => write!(f, "Pending"),
+            RpcCallStatus::Answered(payload) => write!(f, "Answered({:?})", payload),
+            RpcCallStatus::UnknownMethod => write!(f, "UnknownMethod"),
+            RpcCallStatus::Failed => write!(f, "Failed"),
+            RpcCallStatus::TimedOut => write!(f, "TimedOut"),
+        }
+    }
+}
+
+/// Makes the content of the request.
+pub fn make_request_data(method: RpcMethodId, arguments: &RpcArguments) -> PacketDataBytes {
+    let mut data = PacketDataBytes::new();
+    let _ = data.push(method);
+    let _ = data.extend_from_slice(arguments);
+    data
+}
+
+/// Makes the content of the response. `status` is the one,
+/// which the calling device will get.
+pub fn make_response_data(method: RpcMethodId, status: RpcCallStatus) -> PacketDataBytes {
+    let mut data = PacketDataBytes::new();
+    let _ = data.push(method);
+    match status {
+        RpcCallStatus::Answered(payload) => {
+            let _ = data.push(STATUS_OK);
+            let _ = data.extend_from_slice(&payload);
+        }
+        RpcCallStatus::UnknownMethod => {
+            let _ = data.push(STATUS_UNKNOWN_METHOD);
+        }
+        RpcCallStatus::Pending | RpcCallStatus::Failed | RpcCallStatus::TimedOut => {
+            let _ = data.push(STATUS_FAILED);
+        }
+    }
+    data
+}
+
+/// Returns the method id and the arguments, kept by the request.
+pub fn parse_request(request: &Packet) -> Option<(RpcMethodId, &[u8])> {
+    let content = content_of(request);
+    let (method, arguments) = content.split_first()?;
+    Some((*method, arguments))
+}
+
+/// Returns the method id and the status of the call, kept by the response.
+pub fn parse_response(response: &Packet) -> Option<(RpcMethodId, RpcCallStatus)> {
+    let content = content_of(response);
+    if content.len() < METHOD_ID_SIZE + STATUS_SIZE {
+        return None;
+    }
+    let status = match content[METHOD_ID_SIZE] {
+        STATUS_OK => RpcCallStatus::Answered(
+            RpcPayload::from_slice(&content[METHOD_ID_SIZE + STATUS_SIZE..]).ok()?,
+        ),
+        STATUS_UNKNOWN_METHOD => RpcCallStatus::UnknownMethod,
+        STATUS_FAILED => RpcCallStatus::Failed,
+        _ => return None,
+    };
+    Some((content[0], status))
+}
+
+fn content_of(packet: &Packet) -> &[u8] {
+    &packet.data[..packet.get_data_length().min(packet.data.len())]
+}
@@ -0,0 +1,110 @@
+use heapless::Deque;
+
+use super::constants::SNIFFED_PACKET_QUEUE_SIZE;
+use super::{ms, Packet};
+
+/// Sniffer configuration.
+/// With this configuration being set, the node exposes every valid
+/// packet it hears, including transit and duplicated ones.
+/// Sniffed packets are taken with `Node` `sniff` method.
+pub struct SnifferConfig {
+    /// Tells if the node still forwards packets, addressed to other devices.
+    /// Monitors, which shall not change the traffic of the network, set it to false.
+    /// Packets, addressed to the node itself, like pings, are still answered.
+    pub forward_transit: bool,
+}
+
+/// Packet heard from the ether, along with the circumstances of hearing.
+/// Is returned by `Node` `sniff` method.
+#[derive(Clone)]
+pub struct SniffedPacket {
+    /// The packet as it was heard, with lifetime decreased by
+    /// all the devices, which forwarded it before.
+    pub packet: Packet,
+
+    /// Time in milliseconds, when the packet was heard.
+    pub heard_at: ms,
+
+    /// Index of the interface, trough which the packet was heard.
+    pub interface: usize,
+
+    /// Tells if the packet was already heard during the duplicate ignore period,
+    /// so the node has not handled it.
+    pub is_duplicate: bool,
+}
+
+/// Keeps packets heard from the ether till they are taken.
+/// Once the queue is full - the oldest packet is dropped.
+pub struct Sniffer {
+    config: SnifferConfig,
+    sniffed_packets: Deque<SniffedPacket, SNIFFED_PACKET_QUEUE_SIZE>,
+}
+
+impl Sniffer {
+    pub fn new(config: SnifferConfig) -> Sniffer {
+        Sniffer {
+            config,
+            sniffed_packets: Deque::new(),
+        }
+    }
+
+    pub fn forwards_transit(&self) -> bool {
+        self.config.forward_transit
+    }
+
+    pub fn record(&mut self, packet: &Packet, heard_at: ms, interface: usize, is_duplicate: bool) {
+        if self.sniffed_packets.is_full() {
+            self.sniffed_packets.pop_front();
+        }
+        let _ = self.sniffed_packets.push_back(SniffedPacket {
+            packet: packet.clone(),
+            heard_at,
+            interface,
+            is_duplicate,
+        });
+    }
+
+    pub fn take(&mut self) -> Option<SniffedPacket> {
+        self.sniffed_packets.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{GeneralAddressType, PacketDataBytes, PacketState};
+    use super::*;
+
+    fn make_packet(id: u8) -> Packet {
+        Packet::new(
+            1,
+            GeneralAddressType::Broadcast.into(),
+            id,
+            1,
+            PacketState::Normal,
+            true,
+            PacketDataBytes::new(),
+        )
+    }
+
+    #[test]
+    fn oldest_packet_is_dropped_when_full() {
+        let mut sniffer = Sniffer::new(SnifferConfig {
+            forward_transit: false,
+        });
+
+        for id in 0..=SNIFFED_PACKET_QUEUE_SIZE as u8 {
+            sniffer.record(&make_packet(id), id as ms, 0, id % 2 == 1);
+        }
+
+        let first = sniffer.take().expect("Packet shall be sniffed");
+        assert_eq!(first.packet.get_id(), 1);
+        assert_eq!(first.heard_at, 1);
+        assert!(first.is_duplicate);
+
+        let mut count = 1;
+        while sniffer.take().is_some() {
+            count += 1;
+        }
+        assert_eq!(count, SNIFFED_PACKET_QUEUE_SIZE);
+    }
+}
@@ -0,0 +1,50 @@
+use super::ms;
+
+/// Snapshot of the node's internal state.
+/// Is returned by `Node` `stats` method.
+#[derive(Clone)]
+pub struct NodeStats {
+    /// Listen period in milliseconds, which is currently used by the node.
+    /// It differs from configured `listen_period` in case if
+    /// adaptive listen period is configured. Jitter is not included.
+    pub effective_listen_period: ms,
+
+    /// Count of bytes heard from the ether during the last
+    /// window of observation of the ether's load.
+    #[cfg(feature = "adaptive-listen-period")]
+    pub channel_load_bytes: u32,
+
+    /// Count of duplicated packets heard from the ether during the last
+    /// window of observation of the ether's load.
+    #[cfg(feature = "adaptive-listen-period")]
+    pub channel_load_duplicates: u32,
+
+    /// Remaining airtime budget in milliseconds, as of the last call of
+    /// `Node` `update` method. Is `None` in case if airtime is not limited.
+    #[cfg(feature = "airtime-limit")]
+    pub remaining_airtime: Option<ms>,
+
+    /// Count of packets heard from the ether, which carry the address
+    /// of this device as the source one, but were not sent by it.
+    /// Non zero value means, that other device has the same address.
+    #[cfg(feature = "addressing")]
+    pub address_conflicts: u32,
+
+    /// Count of received packets, which were dropped, as the queue of received
+    /// packets, publications or diagnostics reports was full.
+    #[cfg(feature = "diagnostics")]
+    pub dropped_received_packets: u32,
+
+    /// Count of packets, which were not forwarded or answered,
+    /// as the transit queue was full. Answers to retained values requests,
+    /// which did not fit into the send queue, are counted too.
+    #[cfg(feature = "diagnostics")]
+    pub dropped_transit_packets: u32,
+
+    /// Count of neighbour devices, which were heard during the last minute,
+    /// as of the last call of `Node` `update` method.
+    /// Neighbours are recognised by their link acknowledgements,
+    /// so only neighbours with `link_ack` being configured are counted.
+    #[cfg(feature = "diagnostics")]
+    pub neighbours: u32,
+}
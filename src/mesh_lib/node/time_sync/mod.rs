@@ -0,0 +1,244 @@
+use super::{
+    ms, ExactAddressType, GeneralAddressType, LifeTimeType, Packet, PacketDataBytes, PacketState,
+};
+
+const TIME_BYTES_SIZE: usize = core::mem::size_of::<ms>();
+
+const PARTS_PER_MILLION: i64 = 1_000_000;
+
+/// Drift of cheap RC oscillators hardly exceeds 5%,
+/// bigger values are treated as measurement errors.
+const MAX_DRIFT_PPM: i64 = 50_000;
+
+/// Time synchronisation configuration.
+/// One device of the network is the reference one, and
+/// periodically broadcasts it's time. Other devices estimate
+/// the offset between the reference time and their own time.
+///
+/// All devices of the network shall have this configuration set,
+/// because devices, which forward the time, update it right before
+/// the transmission. Devices, which can not update the time, because
+/// they have no configuration set, or have not got the reference time
+/// yet, do not forward it, so the stale time is never passed further.
+pub struct TimeSyncConfig {
+    /// Tells if the time of this device is the reference one.
+    pub is_reference: bool,
+
+    /// Period of time in milliseconds between broadcasts of
+    /// the reference time. Is used by reference device only.
+    pub sync_period: ms,
+
+    /// Count of devices, which the reference time is able to pass.
+    /// Is used by reference device only.
+    pub lifetime: LifeTimeType,
+
+    /// Period of time in milliseconds, which the packet spends in the ether
+    /// during each hop. For example it is about 45 ms at 9600 baud.
+    pub transmission_delay: ms,
+
+    /// Tells if the device shall also estimate the difference
+    /// of speed between it's own clock and the reference one.
+    pub estimate_drift: bool,
+}
+
+struct SyncSample {
+    local_time: ms,
+    reference_time: ms,
+}
+
+/// Keeps the estimation of the reference time.
+pub struct TimeSync {
+    config: TimeSyncConfig,
+    last_broadcast_at: Option<ms>,
+    last_sample: Option<SyncSample>,
+    drift_ppm: i64,
+}
+
+impl TimeSync {
+    pub fn new(config: TimeSyncConfig) -> TimeSync {
+        TimeSync {
+            config,
+            last_broadcast_at: None,
+            last_sample: None,
+            drift_ppm: 0,
+        }
+    }
+
+    /// Creates the packet, which will keep the reference time.
+    /// The time itself is written by `stamp` method right before the transmission.
+    pub fn make_broadcast_packet(
+        &self,
+        source_device_identifier: ExactAddressType,
+    ) -> Option<Packet> {
+        if !self.config.is_reference {
+            return None;
+        }
+        let mut data = PacketDataBytes::new();
+        let _ = data.extend_from_slice(&[0; TIME_BYTES_SIZE]);
+        Some(Packet::new(
+            source_device_identifier.into(),
+            GeneralAddressType::Broadcast.into(),
+            0,
+            self.config.lifetime,
+            PacketState::TimeSync,
+            true,
+            data,
+        ))
+    }
+
+    /// Period of time since `current_time`, after which
+    /// the reference time shall be broadcasted.
+    pub fn time_till_broadcast(&self, current_time: ms) -> Option<ms> {
+        if !self.config.is_reference {
+            return None;
+        }
+        match self.last_broadcast_at {
+            Some(last_broadcast_at) => Some(
+                self.config
+                    .sync_period
+                    .saturating_sub(current_time.wrapping_sub(last_broadcast_at)),
+            ),
+            None => Some(0),
+        }
+    }
+
+    pub fn record_broadcast(&mut self, current_time: ms) {
+        self.last_broadcast_at.replace(current_time);
+    }
+
+    /// Writes the current estimation of the reference time into the `packet`.
+    pub fn stamp(&self, packet: &mut Packet, local_time: ms) {
+        if let Some(reference_time) = self.network_time(local_time) {
+            for (byte, time_byte) in packet
+                .data
+                .iter_mut()
+                .zip(reference_time.to_be_bytes().iter())
+            {
+                *byte = *time_byte;
+            }
+        }
+    }
+
+    /// Updates the estimation with the reference time kept by the `packet`.
+    pub fn handle_sync(&mut self, packet: &Packet, local_time: ms) {
+        if self.config.is_reference {
+            return;
+        }
+        let reference_time = match packet
+            .data
+            .get(0..TIME_BYTES_SIZE)
+            .and_then(|time_bytes| time_bytes.try_into().ok())
+        {
+            Some(time_bytes) => ms::from_be_bytes(time_bytes),
+            None => return,
+        };
+        let reference_time = reference_time.wrapping_add(self.config.transmission_delay);
+
+        if let (true, Some(last_sample)) = (self.config.estimate_drift, &self.last_sample) {
+            let local_elapsed = local_time.wrapping_sub(last_sample.local_time) as i64;
+            let reference_elapsed = reference_time.wrapping_sub(last_sample.reference_time) as i64;
+            if local_elapsed > 0 {
+                let measured_drift_ppm = ((reference_elapsed - local_elapsed) * PARTS_PER_MILLION
+                    / local_elapsed)
+                    .clamp(-MAX_DRIFT_PPM, MAX_DRIFT_PPM);
+                // Smoothing makes single delayed sample less harmful.
+                self.drift_ppm = (self.drift_ppm + measured_drift_ppm) / 2;
+            }
+        }
+
+        self.last_sample.replace(SyncSample {
+            local_time,
+            reference_time,
+        });
+    }
+
+    /// Estimation of the reference time at `local_time`.
+    /// Returns `None` in case if no reference time was received yet.
+    pub fn network_time(&self, local_time: ms) -> Option<ms> {
+        if self.config.is_reference {
+            return Some(local_time);
+        }
+        let last_sample = self.last_sample.as_ref()?;
+        let local_elapsed = local_time.wrapping_sub(last_sample.local_time);
+        let drift_correction = local_elapsed as i64 * self.drift_ppm / PARTS_PER_MILLION;
+        Some(
+            last_sample
+                .reference_time
+                .wrapping_add(local_elapsed)
+                .wrapping_add(drift_correction as ms),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRANSMISSION_DELAY: ms = 5;
+
+    fn make_time_sync(is_reference: bool, estimate_drift: bool) -> TimeSync {
+        TimeSync::new(TimeSyncConfig {
+            is_reference,
+            sync_period: 1000,
+            lifetime: 1,
+            transmission_delay: TRANSMISSION_DELAY,
+            estimate_drift,
+        })
+    }
+
+    fn make_sync_packet(reference_time: ms) -> Packet {
+        let reference = make_time_sync(true, false);
+        let mut packet = reference
+            .make_broadcast_packet(ExactAddressType::new(1).unwrap())
+            .expect("Reference device makes the packet");
+        reference.stamp(&mut packet, reference_time);
+        packet
+    }
+
+    #[test]
+    fn time_is_unknown_before_sync() {
+        let time_sync = make_time_sync(false, false);
+        assert!(time_sync.network_time(100).is_none());
+    }
+
+    #[test]
+    fn offset_is_estimated() {
+        let mut time_sync = make_time_sync(false, false);
+        time_sync.handle_sync(&make_sync_packet(10_000), 500);
+        assert_eq!(
+            time_sync.network_time(600),
+            Some(10_100 + TRANSMISSION_DELAY)
+        );
+    }
+
+    #[test]
+    fn offset_survives_time_overflow() {
+        let mut time_sync = make_time_sync(false, false);
+        time_sync.handle_sync(&make_sync_packet(10), ms::MAX - 10);
+        assert_eq!(time_sync.network_time(10), Some(31 + TRANSMISSION_DELAY));
+    }
+
+    #[test]
+    fn drift_is_estimated() {
+        let mut time_sync = make_time_sync(false, true);
+        // Local clock is 1% slower than the reference one.
+        time_sync.handle_sync(&make_sync_packet(0), 0);
+        time_sync.handle_sync(&make_sync_packet(10_100), 10_000);
+        time_sync.handle_sync(&make_sync_packet(20_200), 20_000);
+
+        let estimated = time_sync.network_time(30_000).expect("Device is synced");
+        let expected = 30_300 + TRANSMISSION_DELAY;
+        assert!(estimated.abs_diff(expected) <= 30);
+    }
+
+    #[test]
+    fn reference_broadcasts_periodically() {
+        let mut time_sync = make_time_sync(true, false);
+        assert_eq!(time_sync.time_till_broadcast(0), Some(0));
+        time_sync.record_broadcast(0);
+        assert_eq!(time_sync.time_till_broadcast(400), Some(600));
+        assert!(make_time_sync(false, false)
+            .time_till_broadcast(0)
+            .is_none());
+    }
+}
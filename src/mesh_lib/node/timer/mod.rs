@@ -1,8 +1,67 @@
-use super::ms;
+#[cfg(feature = "adaptive-listen-period")]
+use super::{constants::DUPLICATE_STORM_THRESHOLD, receiver::ChannelLoad};
+use super::{ms, Clock, RandomNumberGenerator};
+
+/// Listen-before-talk configuration.
+/// Once it is time to speak, the device checks if the ether
+/// was quiet for `quiet_window` period of time. If something
+/// was heard during that period - the device postpones speaking
+/// for random period of time in range `0..=max_backoff`, and
+/// then checks the ether again.
+#[cfg(feature = "carrier-sense")]
+pub struct CarrierSenseConfig {
+    /// Period of time in milliseconds, during which the ether
+    /// shall be quiet, before the device is allowed to speak.
+    pub quiet_window: ms,
+
+    /// Maximal period of time in milliseconds, for which the
+    /// device postpones speaking, in case if the ether is busy.
+    pub max_backoff: ms,
+}
+
+/// Adaptive listen period configuration.
+/// The device observes the load of the ether during `load_window`
+/// periods of time. Once the window is over:
+/// * If nothing was heard - the listen period is shortened.
+/// * If more than `heavy_load_bytes` bytes or storm of duplicated
+///   packets were heard - the listen period is lengthened.
+///
+/// The listen period always stays within `min_listen_period..=max_listen_period`.
+/// If `min_listen_period` is greater than `max_listen_period` - the bounds are swapped.
+#[cfg(feature = "adaptive-listen-period")]
+pub struct AdaptiveListenPeriodConfig {
+    /// Shortest listen period in milliseconds.
+    pub min_listen_period: ms,
+
+    /// Longest listen period in milliseconds.
+    pub max_listen_period: ms,
+
+    /// Period of time in milliseconds, during which
+    /// the load of the ether is observed.
+    pub load_window: ms,
+
+    /// Count of bytes heard during `load_window`,
+    /// which is treated as heavy load of the ether.
+    pub heavy_load_bytes: u32,
+}
+
+#[cfg(feature = "carrier-sense")]
+struct Backoff {
+    started_at: ms,
+    period: ms,
+}
 
 pub struct Timer {
     listen_period: ms,
+    #[cfg(feature = "adaptive-listen-period")]
+    adaptive_listen_period: Option<AdaptiveListenPeriodConfig>,
+    #[cfg(feature = "listen-period-jitter")]
+    listen_period_jitter: ms,
+    #[cfg(feature = "listen-period-jitter")]
+    current_listen_period: ms,
     last_speak_time: ms,
+    #[cfg(feature = "carrier-sense")]
+    backoff: Option<Backoff>,
 }
 
 /// Structure which keeps track of listening / speaking timings.
@@ -10,52 +69,235 @@ impl Timer {
     pub fn new(listen_period: ms) -> Self {
         Self {
             listen_period,
+            #[cfg(feature = "adaptive-listen-period")]
+            adaptive_listen_period: None,
+            #[cfg(feature = "listen-period-jitter")]
+            listen_period_jitter: 0,
+            #[cfg(feature = "listen-period-jitter")]
+            current_listen_period: listen_period,
             last_speak_time: 0,
+            #[cfg(feature = "carrier-sense")]
+            backoff: None,
+        }
+    }
+
+    /// Makes the listen period to be extended by random
+    /// jitter in range `0..=listen_period_jitter`.
+    #[cfg(feature = "listen-period-jitter")]
+    pub fn with_listen_period_jitter(mut self, listen_period_jitter: ms) -> Self {
+        self.listen_period_jitter = listen_period_jitter;
+        self
+    }
+
+    /// Makes the listen period to be adapted to the load of the ether.
+    /// The listen period is moved within bounds right away.
+    #[cfg(feature = "adaptive-listen-period")]
+    pub fn with_adaptive_listen_period(mut self, mut adaptive: AdaptiveListenPeriodConfig) -> Self {
+        if adaptive.min_listen_period > adaptive.max_listen_period {
+            core::mem::swap(
+                &mut adaptive.min_listen_period,
+                &mut adaptive.max_listen_period,
+            );
         }
+        self.adaptive_listen_period.replace(adaptive);
+        self.set_listen_period(self.listen_period);
+        #[cfg(feature = "listen-period-jitter")]
+        {
+            self.current_listen_period = self.listen_period;
+        }
+        self
+    }
+
+    /// Picks period of listening till the next speak.
+    /// It is `listen_period` extended by random jitter
+    /// in range `0..=listen_period_jitter`.
+    #[cfg(feature = "listen-period-jitter")]
+    pub fn pick_listen_period<R: RandomNumberGenerator>(&mut self, rng: &mut R) {
+        let jitter = match self.listen_period_jitter {
+            0 => 0,
+            listen_period_jitter => rng.next_period(listen_period_jitter),
+        };
+        self.current_listen_period = self.listen_period.saturating_add(jitter);
+    }
+
+    /// Listen period, which is used currently, without jitter.
+    pub fn listen_period(&self) -> ms {
+        self.listen_period
+    }
+
+    /// Replaces the listen period. It is kept within bounds of adaptive
+    /// listen period, and takes effect since the next speak.
+    /// Bounds are ordered by `with_adaptive_listen_period`, so they never panic the clamp.
+    #[cfg(any(feature = "parameters", feature = "adaptive-listen-period"))]
+    pub fn set_listen_period(&mut self, listen_period: ms) {
+        #[cfg(feature = "adaptive-listen-period")]
+        let listen_period = match self.adaptive_listen_period {
+            Some(ref adaptive) => {
+                listen_period.clamp(adaptive.min_listen_period, adaptive.max_listen_period)
+            }
+            None => listen_period,
+        };
+        self.listen_period = listen_period;
+    }
+
+    #[cfg(feature = "listen-period-jitter")]
+    pub fn listen_period_jitter(&self) -> ms {
+        self.listen_period_jitter
+    }
+
+    /// Replaces the upper bound of jitter. Takes effect since the next speak.
+    #[cfg(all(feature = "parameters", feature = "listen-period-jitter"))]
+    pub fn set_listen_period_jitter(&mut self, listen_period_jitter: ms) {
+        self.listen_period_jitter = listen_period_jitter;
+    }
+
+    /// Adjusts listen period according to the load of the ether.
+    /// Does nothing if adaptive listen period is not configured.
+    #[cfg(feature = "adaptive-listen-period")]
+    pub fn adapt_listen_period(&mut self, load: &ChannelLoad) {
+        let adaptive = match self.adaptive_listen_period {
+            Some(ref adaptive) => adaptive,
+            None => return,
+        };
+
+        let is_heavy_load = load.bytes_heard > adaptive.heavy_load_bytes
+            || load.duplicates_heard >= DUPLICATE_STORM_THRESHOLD;
+
+        let new_listen_period = if is_heavy_load {
+            self.listen_period.saturating_mul(2)
+        } else if load.bytes_heard == 0 {
+            self.listen_period - self.listen_period / 4
+        } else {
+            return;
+        };
+
+        self.listen_period =
+            new_listen_period.clamp(adaptive.min_listen_period, adaptive.max_listen_period);
     }
 
     /// Tells if the time since last speak is enough to speak
-    /// into the ether again.
-    pub fn is_time_to_speak(&self, current_time: ms) -> bool {
-        current_time.wrapping_sub(self.last_speak_time) >= self.listen_period
+    /// into the ether again, and if the postponed speaking
+    /// is not pending.
+    pub fn is_time_to_speak<C: Clock>(&self, clock: &C) -> bool {
+        self.time_till_speak(clock) == 0
+    }
+
+    /// Period of time since the time of the `clock`, after which it will
+    /// be the time to speak. Zero means it is the time to speak already.
+    pub fn time_till_speak<C: Clock>(&self, clock: &C) -> ms {
+        let current_time = clock.now();
+        let till_listen_period_end = self
+            .current_listen_period()
+            .saturating_sub(current_time.wrapping_sub(self.last_speak_time));
+
+        till_listen_period_end.max(self.time_till_backoff_end(current_time))
     }
 
-    /// Records current time as last speak time.
-    pub fn record_speak_time(&mut self, current_time: ms) {
-        self.last_speak_time = current_time;
+    /// Postpones speaking for `period` of time since the time of the `clock`.
+    #[cfg(feature = "carrier-sense")]
+    pub fn back_off<C: Clock>(&mut self, clock: &C, period: ms) {
+        self.backoff.replace(Backoff {
+            started_at: clock.now(),
+            period,
+        });
+    }
+
+    /// Records current time as last speak time,
+    /// and picks period of listening till the next speak.
+    #[cfg_attr(not(feature = "listen-period-jitter"), allow(unused_variables))]
+    pub fn record_speak_time<C: Clock, R: RandomNumberGenerator>(
+        &mut self,
+        clock: &C,
+        rng: &mut R,
+    ) {
+        self.last_speak_time = clock.now();
+        #[cfg(feature = "carrier-sense")]
+        {
+            self.backoff = None;
+        }
+        #[cfg(feature = "listen-period-jitter")]
+        self.pick_listen_period(rng);
+    }
+
+    /// Listen period till the next speak, including jitter.
+    #[cfg(feature = "listen-period-jitter")]
+    fn current_listen_period(&self) -> ms {
+        self.current_listen_period
+    }
+
+    /// Listen period till the next speak.
+    #[cfg(not(feature = "listen-period-jitter"))]
+    fn current_listen_period(&self) -> ms {
+        self.listen_period
+    }
+
+    /// Period of time since `current_time`, after which
+    /// the postponed speaking is allowed.
+    #[cfg(feature = "carrier-sense")]
+    fn time_till_backoff_end(&self, current_time: ms) -> ms {
+        match self.backoff {
+            Some(ref backoff) => backoff
+                .period
+                .saturating_sub(current_time.wrapping_sub(backoff.started_at)),
+            None => 0,
+        }
+    }
+
+    /// Speaking is never postponed without carrier sense.
+    #[cfg(not(feature = "carrier-sense"))]
+    fn time_till_backoff_end(&self, _current_time: ms) -> ms {
+        0
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mesh_lib::node::XorShiftRng;
 
     const PERIOD: ms = 100;
+    #[cfg(feature = "listen-period-jitter")]
+    const JITTER: ms = 30;
+
+    /// Clock, which is stopped at `time`.
+    fn at(time: ms) -> ms {
+        time
+    }
+
+    #[cfg(feature = "listen-period-jitter")]
+    struct FixedRng(u32);
+
+    #[cfg(feature = "listen-period-jitter")]
+    impl RandomNumberGenerator for FixedRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0
+        }
+    }
 
     #[test]
     fn does_not_speak_before_period_elapses() {
         let mut timer = Timer::new(PERIOD);
-        timer.record_speak_time(1000);
-        assert!(!timer.is_time_to_speak(1099));
+        timer.record_speak_time(&at(1000), &mut XorShiftRng::new(0));
+        assert!(!timer.is_time_to_speak(&at(1099)));
     }
 
     #[test]
     fn speaks_when_period_elapses() {
         let mut timer = Timer::new(PERIOD);
-        timer.record_speak_time(1000);
-        assert!(timer.is_time_to_speak(1100));
+        timer.record_speak_time(&at(1000), &mut XorShiftRng::new(0));
+        assert!(timer.is_time_to_speak(&at(1100)));
     }
 
     #[test]
     fn does_not_speak_before_period_elapses_across_u32_wraparound() {
         let mut timer = Timer::new(PERIOD);
-        // last_speak 50ms before u32 overflow
-        let last_speak: ms = u32::MAX - 50;
-        timer.record_speak_time(last_speak);
-        // 99ms later — wraps to u32::MAX - 50 + 99 = 48
+        // last_speak 50ms before time overflow
+        let last_speak: ms = ms::MAX - 50;
+        timer.record_speak_time(&at(last_speak), &mut XorShiftRng::new(0));
+        // 99ms later — wraps to ms::MAX - 50 + 99 = 48
         let current = last_speak.wrapping_add(PERIOD - 1);
         assert!(
-            !timer.is_time_to_speak(current),
+            !timer.is_time_to_speak(&at(current)),
             "fired after only {}ms; old code: ({} + {}) overflows to {}, then {} > {} is true",
             PERIOD - 1,
             last_speak,
@@ -69,10 +311,197 @@ mod tests {
     #[test]
     fn speaks_after_period_elapses_across_u32_wraparound() {
         let mut timer = Timer::new(PERIOD);
-        let last_speak: ms = u32::MAX - 50;
-        timer.record_speak_time(last_speak);
+        let last_speak: ms = ms::MAX - 50;
+        timer.record_speak_time(&at(last_speak), &mut XorShiftRng::new(0));
         // exactly 100ms later — wraps to 49
         let current = last_speak.wrapping_add(PERIOD);
-        assert!(timer.is_time_to_speak(current));
+        assert!(timer.is_time_to_speak(&at(current)));
+    }
+
+    #[test]
+    #[cfg(feature = "carrier-sense")]
+    fn does_not_speak_during_backoff() {
+        let mut timer = Timer::new(PERIOD);
+        timer.record_speak_time(&at(1000), &mut XorShiftRng::new(0));
+        timer.back_off(&at(1100), 50);
+        assert!(!timer.is_time_to_speak(&at(1149)));
+    }
+
+    #[test]
+    #[cfg(feature = "carrier-sense")]
+    fn speaks_when_backoff_elapses() {
+        let mut timer = Timer::new(PERIOD);
+        timer.record_speak_time(&at(1000), &mut XorShiftRng::new(0));
+        timer.back_off(&at(1100), 50);
+        assert!(timer.is_time_to_speak(&at(1150)));
+    }
+
+    #[test]
+    #[cfg(feature = "carrier-sense")]
+    fn speaking_clears_backoff() {
+        let mut timer = Timer::new(PERIOD);
+        timer.back_off(&at(1000), 500);
+        timer.record_speak_time(&at(1000), &mut XorShiftRng::new(0));
+        assert!(timer.is_time_to_speak(&at(1000 + PERIOD)));
+    }
+
+    #[test]
+    #[cfg(feature = "listen-period-jitter")]
+    fn jitter_extends_listen_period() {
+        let mut timer = Timer::new(PERIOD).with_listen_period_jitter(JITTER);
+        timer.record_speak_time(&at(1000), &mut FixedRng(JITTER as u32));
+        assert!(!timer.is_time_to_speak(&at(1000 + PERIOD + JITTER - 1)));
+        assert!(timer.is_time_to_speak(&at(1000 + PERIOD + JITTER)));
+    }
+
+    #[test]
+    #[cfg(feature = "listen-period-jitter")]
+    fn jitter_stays_within_bounds() {
+        let mut timer = Timer::new(PERIOD).with_listen_period_jitter(JITTER);
+        let mut rng = XorShiftRng::new(1);
+        for _ in 0..1000 {
+            timer.record_speak_time(&at(1000), &mut rng);
+            assert!(!timer.is_time_to_speak(&at(1000 + PERIOD - 1)));
+            assert!(timer.is_time_to_speak(&at(1000 + PERIOD + JITTER)));
+        }
+    }
+
+    #[cfg(feature = "adaptive-listen-period")]
+    fn adaptive_timer() -> Timer {
+        Timer::new(PERIOD).with_adaptive_listen_period(AdaptiveListenPeriodConfig {
+            min_listen_period: PERIOD / 2,
+            max_listen_period: PERIOD * 4,
+            load_window: 1000,
+            heavy_load_bytes: 100,
+        })
+    }
+
+    #[cfg(feature = "adaptive-listen-period")]
+    fn load(bytes_heard: u32, duplicates_heard: u32) -> ChannelLoad {
+        ChannelLoad {
+            bytes_heard,
+            duplicates_heard,
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "adaptive-listen-period")]
+    fn idle_ether_shortens_listen_period_down_to_min() {
+        let mut timer = adaptive_timer();
+        timer.adapt_listen_period(&load(0, 0));
+        assert!(timer.listen_period() < PERIOD);
+        for _ in 0..100 {
+            timer.adapt_listen_period(&load(0, 0));
+        }
+        assert_eq!(timer.listen_period(), PERIOD / 2);
+    }
+
+    #[test]
+    #[cfg(feature = "adaptive-listen-period")]
+    fn heavy_load_lengthens_listen_period_up_to_max() {
+        let mut timer = adaptive_timer();
+        timer.adapt_listen_period(&load(101, 0));
+        assert_eq!(timer.listen_period(), PERIOD * 2);
+        for _ in 0..100 {
+            timer.adapt_listen_period(&load(101, 0));
+        }
+        assert_eq!(timer.listen_period(), PERIOD * 4);
+    }
+
+    #[test]
+    #[cfg(feature = "adaptive-listen-period")]
+    fn duplicate_storm_lengthens_listen_period() {
+        let mut timer = adaptive_timer();
+        timer.adapt_listen_period(&load(1, DUPLICATE_STORM_THRESHOLD));
+        assert_eq!(timer.listen_period(), PERIOD * 2);
+    }
+
+    #[test]
+    #[cfg(feature = "adaptive-listen-period")]
+    fn moderate_load_keeps_listen_period() {
+        let mut timer = adaptive_timer();
+        timer.adapt_listen_period(&load(50, 0));
+        assert_eq!(timer.listen_period(), PERIOD);
+    }
+
+    #[test]
+    #[cfg(feature = "adaptive-listen-period")]
+    fn not_adaptive_timer_keeps_listen_period() {
+        let mut timer = Timer::new(PERIOD);
+        timer.adapt_listen_period(&load(0, 0));
+        assert_eq!(timer.listen_period(), PERIOD);
+    }
+
+    #[test]
+    #[cfg(feature = "parameters")]
+    fn replaced_listen_period_takes_effect_since_next_speak() {
+        let mut timer = Timer::new(PERIOD);
+        timer.record_speak_time(&at(1000), &mut XorShiftRng::new(0));
+        timer.set_listen_period(PERIOD * 2);
+        assert!(timer.is_time_to_speak(&at(1000 + PERIOD)));
+
+        timer.record_speak_time(&at(1000 + PERIOD), &mut XorShiftRng::new(0));
+        assert!(!timer.is_time_to_speak(&at(1000 + PERIOD * 3 - 1)));
+        assert!(timer.is_time_to_speak(&at(1000 + PERIOD * 3)));
+    }
+
+    #[test]
+    #[cfg(feature = "adaptive-listen-period")]
+    fn replaced_listen_period_is_kept_within_adaptive_bounds() {
+        let mut timer = adaptive_timer();
+        timer.set_listen_period(PERIOD * 10);
+        assert_eq!(timer.listen_period(), PERIOD * 4);
+        timer.set_listen_period(1);
+        assert_eq!(timer.listen_period(), PERIOD / 2);
+    }
+
+    #[test]
+    #[cfg(feature = "adaptive-listen-period")]
+    fn swapped_adaptive_bounds_are_reordered() {
+        let mut timer =
+            Timer::new(PERIOD * 10).with_adaptive_listen_period(AdaptiveListenPeriodConfig {
+                min_listen_period: PERIOD * 4,
+                max_listen_period: PERIOD / 2,
+                load_window: 1000,
+                heavy_load_bytes: 100,
+            });
+        assert_eq!(timer.listen_period(), PERIOD * 4);
+        for _ in 0..100 {
+            timer.adapt_listen_period(&load(0, 0));
+        }
+        assert_eq!(timer.listen_period(), PERIOD / 2);
+    }
+
+    #[test]
+    #[cfg(feature = "adaptive-listen-period")]
+    fn set_listen_period_is_clamped_by_swapped_adaptive_bounds() {
+        let mut timer =
+            Timer::new(PERIOD).with_adaptive_listen_period(AdaptiveListenPeriodConfig {
+                min_listen_period: PERIOD * 4,
+                max_listen_period: PERIOD / 2,
+                load_window: 1000,
+                heavy_load_bytes: 100,
+            });
+        timer.set_listen_period(PERIOD * 10);
+        assert_eq!(timer.listen_period(), PERIOD * 4);
+        timer.set_listen_period(1);
+        assert_eq!(timer.listen_period(), PERIOD / 2);
+    }
+
+    #[test]
+    fn time_till_speak_counts_listen_period() {
+        let mut timer = Timer::new(PERIOD);
+        timer.record_speak_time(&at(1000), &mut XorShiftRng::new(0));
+        assert_eq!(timer.time_till_speak(&at(1040)), PERIOD - 40);
+        assert_eq!(timer.time_till_speak(&at(1000 + PERIOD + 1)), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "carrier-sense")]
+    fn time_till_speak_counts_backoff() {
+        let mut timer = Timer::new(PERIOD);
+        timer.record_speak_time(&at(1000), &mut XorShiftRng::new(0));
+        timer.back_off(&at(1100), 50);
+        assert_eq!(timer.time_till_speak(&at(1120)), 30);
     }
 }
@@ -0,0 +1,197 @@
+use super::super::{
+    constants::{PACKET_START_BYTES_COUNT, UART_BITS_PER_BYTE},
+    ms,
+    packet::PACKET_BYTES_SIZE,
+};
+
+const MICROSECONDS_PER_SECOND: u64 = 1_000_000;
+const MICROSECONDS_PER_MILLISECOND: u64 = 1_000;
+
+/// Airtime limit configuration.
+/// Some radio bands, like EU 868 MHz, legally limit the share
+/// of time during which the device is allowed to transmit.
+/// With this limit being set, the device keeps it's packets
+/// in queues, until enough airtime budget is restored.
+pub struct AirtimeLimitConfig {
+    /// Baud rate of the serial interface of the radio module.
+    /// Is used to calculate time on air of each packet.
+    pub baud_rate: u32,
+
+    /// Allowed share of airtime in permille (tenths of percent).
+    /// For example `10` stands for 1% duty cycle.
+    pub duty_cycle_permille: u32,
+
+    /// Period of time in milliseconds, which duty cycle is calculated for.
+    /// For example `3_600_000` stands for 1 hour.
+    pub window: ms,
+}
+
+/// Keeps track of airtime budget in the manner of token bucket.
+/// The budget is restored continuously with the speed of duty cycle,
+/// and is limited by the budget of one whole window.
+pub struct AirtimeAccountant {
+    /// Airtime of single packet in microseconds.
+    frame_airtime: u64,
+    /// Budget of one window in microseconds.
+    capacity: u64,
+    /// Currently available budget in microseconds.
+    available: u64,
+    /// Restored budget in microseconds per each millisecond.
+    restore_rate: u64,
+    last_restore_time: Option<ms>,
+}
+
+impl AirtimeAccountant {
+    // Conversion of `ms` is useless, if `time64` feature is turned on.
+    #[allow(clippy::useless_conversion)]
+    pub fn new(config: &AirtimeLimitConfig) -> AirtimeAccountant {
+        let frame_bits =
+            ((PACKET_START_BYTES_COUNT + PACKET_BYTES_SIZE) * UART_BITS_PER_BYTE) as u64;
+        let frame_airtime =
+            (frame_bits * MICROSECONDS_PER_SECOND).div_ceil(config.baud_rate.max(1) as u64);
+
+        // permille of each millisecond is exactly the amount of microseconds.
+        let restore_rate = config.duty_cycle_permille as u64;
+        let capacity = u64::from(config.window) * restore_rate;
+
+        AirtimeAccountant {
+            frame_airtime,
+            capacity,
+            available: capacity,
+            restore_rate,
+            last_restore_time: None,
+        }
+    }
+
+    /// Restores the budget for the time passed since previous call.
+    // Conversion of `ms` is useless, if `time64` feature is turned on.
+    #[allow(clippy::useless_conversion)]
+    pub fn restore(&mut self, current_time: ms) {
+        if let Some(last_restore_time) = self.last_restore_time {
+            let elapsed = u64::from(current_time.wrapping_sub(last_restore_time));
+            self.available = self
+                .available
+                .saturating_add(elapsed.saturating_mul(self.restore_rate))
+                .min(self.capacity);
+        }
+        self.last_restore_time.replace(current_time);
+    }
+
+    /// Tells if the budget is enough to send one more packet.
+    pub fn is_frame_affordable(&self) -> bool {
+        self.available >= self.frame_airtime
+    }
+
+    /// Takes airtime of one packet out of the budget.
+    pub fn spend_frame(&mut self) {
+        self.available = self.available.saturating_sub(self.frame_airtime);
+    }
+
+    /// Remaining budget in milliseconds.
+    pub fn remaining(&self) -> ms {
+        (self.available / MICROSECONDS_PER_MILLISECOND) as ms
+    }
+
+    /// Period of time since `current_time`, after which the budget
+    /// will be enough to send one more packet.
+    /// Returns `None` in case if one packet exceeds whole budget.
+    pub fn time_till_frame_affordable(&self, current_time: ms) -> Option<ms> {
+        if self.frame_airtime > self.capacity || self.restore_rate == 0 {
+            return None;
+        }
+        let lack = self.frame_airtime.saturating_sub(self.available);
+        let till_affordable = lack.div_ceil(self.restore_rate) as ms;
+
+        let elapsed = match self.last_restore_time {
+            Some(last_restore_time) => current_time.wrapping_sub(last_restore_time),
+            None => 0,
+        };
+        Some(till_affordable.saturating_sub(elapsed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 43 bytes per frame, 10 bits per byte at 9600 baud - is 44.8 ms.
+    // 1% of 10 seconds gives 100 ms of airtime, which is enough for 2 packets.
+    fn accountant() -> AirtimeAccountant {
+        AirtimeAccountant::new(&AirtimeLimitConfig {
+            baud_rate: 9600,
+            duty_cycle_permille: 10,
+            window: 10_000,
+        })
+    }
+
+    #[test]
+    fn budget_is_full_at_start() {
+        let mut accountant = accountant();
+        accountant.restore(0);
+        assert_eq!(accountant.remaining(), 100);
+    }
+
+    #[test]
+    fn budget_is_exhausted_by_packets() {
+        let mut accountant = accountant();
+        accountant.restore(0);
+        accountant.spend_frame();
+        assert!(accountant.is_frame_affordable());
+        accountant.spend_frame();
+        assert!(!accountant.is_frame_affordable());
+    }
+
+    #[test]
+    fn budget_is_restored_with_time() {
+        let mut accountant = accountant();
+        accountant.restore(0);
+        accountant.spend_frame();
+        accountant.spend_frame();
+
+        // 10.4 ms of budget is left, 34.4 ms more is needed, which
+        // is restored at speed of 10 microseconds per millisecond.
+        accountant.restore(3437);
+        assert!(!accountant.is_frame_affordable());
+        accountant.restore(3438);
+        assert!(accountant.is_frame_affordable());
+    }
+
+    #[test]
+    fn time_till_frame_affordable_counts_restoration() {
+        let mut accountant = accountant();
+        accountant.restore(0);
+        assert_eq!(accountant.time_till_frame_affordable(0), Some(0));
+        accountant.spend_frame();
+        accountant.spend_frame();
+        assert_eq!(accountant.time_till_frame_affordable(0), Some(3438));
+        assert_eq!(accountant.time_till_frame_affordable(1000), Some(2438));
+    }
+
+    #[test]
+    fn too_long_frame_is_never_affordable() {
+        let accountant = AirtimeAccountant::new(&AirtimeLimitConfig {
+            baud_rate: 9600,
+            duty_cycle_permille: 1,
+            window: 10_000,
+        });
+        assert_eq!(accountant.time_till_frame_affordable(0), None);
+    }
+
+    #[test]
+    fn budget_does_not_exceed_one_window() {
+        let mut accountant = accountant();
+        accountant.restore(0);
+        accountant.restore(100_000);
+        assert_eq!(accountant.remaining(), 100);
+    }
+
+    #[test]
+    fn budget_is_restored_across_u32_wraparound() {
+        let mut accountant = accountant();
+        accountant.restore(ms::MAX - 10);
+        accountant.spend_frame();
+        accountant.spend_frame();
+        accountant.restore((ms::MAX - 10).wrapping_add(10_000));
+        assert_eq!(accountant.remaining(), 100);
+    }
+}
@@ -0,0 +1,216 @@
+use heapless::Vec;
+
+use super::super::{
+    constants::LINK_ACK_PACKETS_COUNT,
+    ms,
+    packet::{GeneralAddressType, Packet, PacketState},
+};
+
+/// Link acknowledgement configuration.
+/// With this configuration being set, the device expects each
+/// packet sent to the exact device to be confirmed by the neighbour device,
+/// which has caught it. The confirmation is either overheard
+/// forwarding of the packet further, or `LinkAck` packet sent by
+/// the end-receiver device. Unconfirmed packets are retransmitted.
+///
+/// The last hop is confirmed by `LinkAck` only, which is not confirmed
+/// itself. If it is lost - the packet is retransmitted, and the
+/// end-receiver confirms the duplicate again. Packets, which lifetime
+/// ends before they reach the end-receiver, are never confirmed,
+/// so they are retransmitted `retries` times.
+///
+/// All devices of the network shall have the same configuration.
+pub struct LinkAckConfig {
+    /// Period of time in milliseconds, during which the confirmation is awaited.
+    /// It shall be longer than the listen period of the neighbour devices.
+    pub ack_timeout: ms,
+
+    /// Count of retransmissions of the unconfirmed packet.
+    pub retries: u8,
+}
+
+struct AwaitingPacket {
+    packet: Packet,
+    sent_at: ms,
+    retries_left: u8,
+}
+
+/// Keeps copies of sent packets, till they are confirmed by neighbours.
+/// In case if there is no place for one more packet - the oldest one is forgotten.
+pub struct LinkAckTracker {
+    ack_timeout: ms,
+    retries: u8,
+    awaiting: Vec<AwaitingPacket, LINK_ACK_PACKETS_COUNT>,
+}
+
+impl LinkAckTracker {
+    pub fn new(config: &LinkAckConfig) -> LinkAckTracker {
+        LinkAckTracker {
+            ack_timeout: config.ack_timeout,
+            retries: config.retries,
+            awaiting: Vec::new(),
+        }
+    }
+
+    /// Starts waiting for confirmation of just sent packet.
+    /// Packets addressed to all devices, and link acknowledgements
+    /// themselves - are not confirmed.
+    pub fn track(&mut self, packet: &Packet, current_time: ms) {
+        if packet.is_destination_reached(GeneralAddressType::Broadcast)
+            || packet.get_spec_state() == PacketState::LinkAck
+            || self.retries == 0
+        {
+            return;
+        }
+        if self.awaiting.is_full() {
+            self.awaiting.remove(0);
+        }
+        let _ = self.awaiting.push(AwaitingPacket {
+            packet: packet.clone(),
+            sent_at: current_time,
+            retries_left: self.retries,
+        });
+    }
+
+    /// Forgets packets, which are confirmed by the `heard` packet.
+    pub fn acknowledge(&mut self, heard: &Packet) {
+        if heard.get_spec_state() == PacketState::LinkAck {
+            let (source_device_identifier, id) = match heard.data.get(0..2) {
+                Some(&[source_device_identifier, id]) => (source_device_identifier, id),
+                _ => return,
+            };
+            self.awaiting.retain(|awaiting| {
+                !(awaiting.packet.source_device_identifier == source_device_identifier
+                    && awaiting.packet.get_id() == id)
+            });
+            return;
+        }
+
+        // Forwarding of the packet further, made by the neighbour.
+        self.awaiting.retain(|awaiting| {
+            !(awaiting.packet.source_device_identifier == heard.source_device_identifier
+                && awaiting.packet.get_id() == heard.get_id()
+                && awaiting.packet.get_spec_state() == heard.get_spec_state()
+                && awaiting.packet.get_lifetime() > heard.get_lifetime())
+        });
+    }
+
+    /// Returns copy of the packet, which confirmation is timed out.
+    /// Packets with no retransmissions left - are forgotten.
+    pub fn take_retransmission(&mut self, current_time: ms) -> Option<Packet> {
+        let ack_timeout = self.ack_timeout;
+        let is_timed_out =
+            |awaiting: &AwaitingPacket| current_time.wrapping_sub(awaiting.sent_at) >= ack_timeout;
+
+        self.awaiting
+            .retain(|awaiting| awaiting.retries_left > 0 || !is_timed_out(awaiting));
+
+        let awaiting = self
+            .awaiting
+            .iter_mut()
+            .find(|awaiting| is_timed_out(awaiting))?;
+        awaiting.retries_left -= 1;
+        awaiting.sent_at = current_time;
+        Some(awaiting.packet.clone())
+    }
+
+    /// Period of time since `current_time`, after which
+    /// the nearest confirmation times out.
+    pub fn time_till_next_timeout(&self, current_time: ms) -> Option<ms> {
+        self.awaiting
+            .iter()
+            .map(|awaiting| {
+                self.ack_timeout
+                    .saturating_sub(current_time.wrapping_sub(awaiting.sent_at))
+            })
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::packet::PacketDataBytes;
+
+    const ACK_TIMEOUT: ms = 100;
+
+    fn make_tracker(retries: u8) -> LinkAckTracker {
+        LinkAckTracker::new(&LinkAckConfig {
+            ack_timeout: ACK_TIMEOUT,
+            retries,
+        })
+    }
+
+    fn make_packet(lifetime: u8) -> Packet {
+        Packet::new(
+            1,
+            3,
+            5,
+            lifetime,
+            PacketState::Normal,
+            true,
+            PacketDataBytes::new(),
+        )
+    }
+
+    #[test]
+    fn unconfirmed_packet_is_retransmitted() {
+        let mut tracker = make_tracker(1);
+        tracker.track(&make_packet(3), 0);
+
+        assert!(tracker.take_retransmission(ACK_TIMEOUT - 1).is_none());
+        assert!(tracker.take_retransmission(ACK_TIMEOUT).is_some());
+        assert!(tracker.take_retransmission(2 * ACK_TIMEOUT).is_none());
+        assert!(tracker.time_till_next_timeout(2 * ACK_TIMEOUT).is_none());
+    }
+
+    #[test]
+    fn forwarding_confirms_packet() {
+        let mut tracker = make_tracker(1);
+        tracker.track(&make_packet(3), 0);
+
+        // Same lifetime means the echo from the other previous hop.
+        tracker.acknowledge(&make_packet(3));
+        assert_eq!(tracker.time_till_next_timeout(0), Some(ACK_TIMEOUT));
+
+        tracker.acknowledge(&make_packet(2));
+        assert!(tracker.time_till_next_timeout(0).is_none());
+    }
+
+    #[test]
+    fn link_ack_confirms_packet() {
+        let mut tracker = make_tracker(1);
+        tracker.track(&make_packet(1), 0);
+
+        let mut data = PacketDataBytes::new();
+        let _ = data.extend_from_slice(&[1, 5]);
+        tracker.acknowledge(&Packet::new(
+            3,
+            GeneralAddressType::Broadcast.into(),
+            0,
+            1,
+            PacketState::LinkAck,
+            false,
+            data,
+        ));
+        assert!(tracker.take_retransmission(ACK_TIMEOUT).is_none());
+    }
+
+    #[test]
+    fn broadcast_is_not_tracked() {
+        let mut tracker = make_tracker(1);
+        tracker.track(
+            &Packet::new(
+                1,
+                GeneralAddressType::Broadcast.into(),
+                5,
+                3,
+                PacketState::Normal,
+                true,
+                PacketDataBytes::new(),
+            ),
+            0,
+        );
+        assert!(tracker.time_till_next_timeout(0).is_none());
+    }
+}
@@ -0,0 +1,458 @@
+#[cfg(feature = "airtime-limit")]
+mod airtime;
+#[cfg(feature = "link-ack")]
+mod link_ack;
+#[cfg(feature = "addressing")]
+mod sent_history;
+
+use super::constants::{PACKET_START_BYTE, PACKET_START_BYTES_COUNT};
+use super::ms;
+use super::packet::{IdType, Packet, Serializer};
+
+use super::types::PacketQueue;
+
+#[cfg(feature = "airtime-limit")]
+use self::airtime::AirtimeAccountant;
+#[cfg(feature = "airtime-limit")]
+pub use self::airtime::AirtimeLimitConfig;
+#[cfg(feature = "link-ack")]
+pub use self::link_ack::LinkAckConfig;
+#[cfg(feature = "link-ack")]
+use self::link_ack::LinkAckTracker;
+#[cfg(feature = "addressing")]
+use self::sent_history::SentHistory;
+
+/// Tells, which packet is dropped, once the transit queue is full.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum QueuePolicy {
+    /// The packet, which does not fit into the queue, is dropped.
+    /// Packets, which wait in the queue for long, are forwarded first.
+    DropNewest,
+
+    /// The oldest packet in the queue is dropped, so the fresh one fits.
+    /// Suits the traffic of periodic readings, where only the latest value matters.
+    DropOldest,
+}
+
+impl core::fmt::Debug for QueuePolicy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            QueuePolicy::DropNewest => write!(f, "DropNewest"),
+            QueuePolicy::DropOldest => write!(f, "DropOldest"),
+        }
+    }
+}
+
+const DROP_NEWEST_CODE: u8 = 0;
+const DROP_OLDEST_CODE: u8 = 1;
+
+impl QueuePolicy {
+    /// Code of the policy, which is used by remote parameters.
+    pub fn code(&self) -> u8 {
+        match self {
+            QueuePolicy::DropNewest => DROP_NEWEST_CODE,
+            QueuePolicy::DropOldest => DROP_OLDEST_CODE,
+        }
+    }
+
+    /// Returns the policy with the `code`, or `None` if there is no such one.
+    pub fn from_code(code: u8) -> Option<QueuePolicy> {
+        match code {
+            DROP_NEWEST_CODE => Some(QueuePolicy::DropNewest),
+            DROP_OLDEST_CODE => Some(QueuePolicy::DropOldest),
+            _ => None,
+        }
+    }
+}
+
+pub struct Transmitter {
+    packet_queue: PacketQueue,
+    transit_queue: PacketQueue,
+    transit_queue_policy: QueuePolicy,
+    id_counter: IdType,
+    #[cfg(feature = "airtime-limit")]
+    airtime_accountant: Option<AirtimeAccountant>,
+    #[cfg(feature = "link-ack")]
+    link_ack_tracker: Option<LinkAckTracker>,
+    #[cfg(feature = "addressing")]
+    sent_history: SentHistory,
+}
+
+pub struct PacketQueueIsFull;
+pub struct PacketTransitQueueIsFull;
+
+struct QueuePushError;
+
+impl Transmitter {
+    pub fn new(
+        transit_queue_policy: QueuePolicy,
+        #[cfg(feature = "airtime-limit")] airtime_limit: Option<AirtimeLimitConfig>,
+        #[cfg(feature = "link-ack")] link_ack: Option<LinkAckConfig>,
+    ) -> Transmitter {
+        Transmitter {
+            packet_queue: PacketQueue::new(),
+            transit_queue: PacketQueue::new(),
+            transit_queue_policy,
+            id_counter: IdType::default(),
+            #[cfg(feature = "airtime-limit")]
+            airtime_accountant: airtime_limit.as_ref().map(AirtimeAccountant::new),
+            #[cfg(feature = "link-ack")]
+            link_ack_tracker: link_ack.as_ref().map(LinkAckTracker::new),
+            #[cfg(feature = "addressing")]
+            sent_history: SentHistory::new(),
+        }
+    }
+
+    /// Remaining airtime budget in milliseconds.
+    /// Returns `None` in case if airtime is not limited.
+    #[cfg(feature = "airtime-limit")]
+    pub fn remaining_airtime(&self) -> Option<ms> {
+        self.airtime_accountant
+            .as_ref()
+            .map(|airtime_accountant| airtime_accountant.remaining())
+    }
+
+    /// Restores airtime budget for the time passed since previous
+    /// restoration, so the remaining budget is actual even while
+    /// the device is not speaking.
+    #[cfg(feature = "airtime-limit")]
+    pub fn restore_airtime(&mut self, current_time: ms) {
+        if let Some(ref mut airtime_accountant) = self.airtime_accountant {
+            airtime_accountant.restore(current_time);
+        }
+    }
+
+    /// Tells if there are packets waiting to be sent.
+    pub fn has_pending_packets(&self) -> bool {
+        !self.packet_queue.is_empty() || !self.transit_queue.is_empty()
+    }
+
+    /// Period of time since `current_time`, after which the airtime
+    /// budget will be enough to send one more packet.
+    /// Returns `None` in case if the packet never fits into the budget.
+    #[cfg(feature = "airtime-limit")]
+    pub fn time_till_airtime_available(&self, current_time: ms) -> Option<ms> {
+        match self.airtime_accountant {
+            Some(ref airtime_accountant) => {
+                airtime_accountant.time_till_frame_affordable(current_time)
+            }
+            None => Some(0),
+        }
+    }
+
+    /// Count of packets, which are waiting to be sent by this device.
+    #[cfg(feature = "diagnostics")]
+    pub fn queued_count(&self) -> usize {
+        self.packet_queue.len()
+    }
+
+    /// Count of packets, which are waiting to be forwarded.
+    #[cfg(feature = "diagnostics")]
+    pub fn queued_transit_count(&self) -> usize {
+        self.transit_queue.len()
+    }
+
+    /// Tells if there is no more space for packets to be sent.
+    #[cfg(feature = "reliable")]
+    pub fn is_queue_full(&self) -> bool {
+        self.packet_queue.is_full()
+    }
+
+    /// Tells if there is no more space for packets to be forwarded.
+    #[cfg(feature = "mailbox")]
+    pub fn is_transit_queue_full(&self) -> bool {
+        self.transit_queue.is_full()
+    }
+
+    /// Counts of packets, queued to be sent, and to be forwarded.
+    #[cfg(feature = "fuzzing")]
+    pub fn queue_lens(&self) -> (usize, usize) {
+        (self.packet_queue.len(), self.transit_queue.len())
+    }
+
+    /// Generates id for the next packet, which is sent by this device.
+    pub fn generate_id(&mut self) -> IdType {
+        let (new_val, _) = self.id_counter.overflowing_add(1);
+        self.id_counter = new_val;
+        self.id_counter
+    }
+
+    /// Packets, which are waiting to be sent.
+    #[cfg(feature = "time-sync")]
+    pub fn queued_packets_mut(&mut self) -> impl Iterator<Item = &mut Packet> {
+        self.transit_queue
+            .iter_mut()
+            .chain(self.packet_queue.iter_mut())
+    }
+
+    /// Tells if the `heard` packet is one of recently sent by this device,
+    /// including those ones, which are forwarded by neighbours.
+    #[cfg(feature = "addressing")]
+    pub fn has_sent(&self, heard: &Packet, current_time: ms) -> bool {
+        self.sent_history.has_sent(heard, current_time)
+    }
+
+    /// Tells if link acknowledgements are expected.
+    #[cfg(feature = "link-ack")]
+    pub fn is_link_ack_enabled(&self) -> bool {
+        self.link_ack_tracker.is_some()
+    }
+
+    /// Stops retransmission of sent packets, which are
+    /// confirmed by the `heard` packet.
+    #[cfg(feature = "link-ack")]
+    pub fn acknowledge_link(&mut self, heard: &Packet) {
+        if let Some(ref mut link_ack_tracker) = self.link_ack_tracker {
+            link_ack_tracker.acknowledge(heard);
+        }
+    }
+
+    /// Period of time since `current_time`, after which
+    /// the nearest unconfirmed packet needs to be retransmitted.
+    #[cfg(feature = "link-ack")]
+    pub fn time_till_link_retransmission(&self, current_time: ms) -> Option<ms> {
+        self.link_ack_tracker
+            .as_ref()
+            .and_then(|link_ack_tracker| link_ack_tracker.time_till_next_timeout(current_time))
+    }
+
+    /// Policy, which tells, which packet is dropped, once the transit queue is full.
+    #[cfg(feature = "parameters")]
+    pub fn transit_queue_policy(&self) -> QueuePolicy {
+        self.transit_queue_policy
+    }
+
+    /// Replaces the policy of the transit queue. Is applied since the next packet.
+    #[cfg(feature = "parameters")]
+    pub fn set_transit_queue_policy(&mut self, policy: QueuePolicy) {
+        self.transit_queue_policy = policy;
+    }
+
+    /// Puts the packet into the transit queue. Returns error in case if the
+    /// queue was full, so one packet was dropped. It is either the `packet`,
+    /// or the oldest queued one, as the policy of the transit queue tells.
+    pub fn send_transit(&mut self, packet: Packet) -> Result<(), PacketTransitQueueIsFull> {
+        if self.transit_queue_policy == QueuePolicy::DropOldest && self.transit_queue.is_full() {
+            let _ = self.transit_queue.pop_front();
+            let _ = self._send(packet, false);
+            return Err(PacketTransitQueueIsFull);
+        }
+        match self._send(packet, false) {
+            Ok(_) => Ok(()),
+            Err(QueuePushError) => Err(PacketTransitQueueIsFull),
+        }
+    }
+
+    pub fn send(&mut self, packet: Packet) -> Result<IdType, PacketQueueIsFull> {
+        match self._send(packet, true) {
+            Ok(generated_packet_id) => Ok(generated_packet_id),
+            Err(QueuePushError) => Err(PacketQueueIsFull),
+        }
+    }
+
+    fn _send(
+        &mut self,
+        mut packet: Packet,
+        update_id_counter: bool,
+    ) -> Result<IdType, QueuePushError> {
+        // Packet transition does not require id incrementing.
+        // But every other senging method requires it.
+        if update_id_counter {
+            packet.set_id(self.generate_id());
+        }
+
+        let generated_packet_id = packet.get_id().clone();
+
+        // Packets in transition are kept apart, so they do not take
+        // the space of packets, sent by this device.
+        let queue = if update_id_counter {
+            &mut self.packet_queue
+        } else {
+            &mut self.transit_queue
+        };
+
+        match queue.push_back(packet) {
+            Ok(_) => Ok(generated_packet_id),
+            Err(_) => Err(QueuePushError),
+        }
+    }
+
+    #[inline]
+    fn send_start_byte_sequence<I>(&self, interface_driver: &mut I)
+    where
+        I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
+    {
+        for _ in 0..PACKET_START_BYTES_COUNT {
+            let _ = interface_driver.write(&[PACKET_START_BYTE]);
+        }
+    }
+
+    /// Sends one packet from the queues.
+    /// `hop_period` is the period of time, for which each device is expected
+    /// to hold the packet before forwarding it. Sent packets are remembered
+    /// for as long as they can travel trough the network and come back.
+    #[cfg_attr(
+        not(any(
+            feature = "airtime-limit",
+            feature = "link-ack",
+            feature = "addressing"
+        )),
+        allow(unused_variables)
+    )]
+    pub fn update<I>(&mut self, interface_driver: &mut I, current_time: ms, hop_period: ms)
+    where
+        I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
+    {
+        #[cfg(feature = "airtime-limit")]
+        if let Some(ref mut airtime_accountant) = self.airtime_accountant {
+            airtime_accountant.restore(current_time);
+            if !airtime_accountant.is_frame_affordable() {
+                return; // Packets are kept in queues till the budget is restored.
+            }
+        }
+
+        // Retransmit unconfirmed packet.
+        #[cfg(feature = "link-ack")]
+        if let Some(ref mut link_ack_tracker) = self.link_ack_tracker {
+            if let Some(packet) = link_ack_tracker.take_retransmission(current_time) {
+                self.transmit(packet, interface_driver, current_time, hop_period);
+                return; // This return makes sending one packet per listen period
+            }
+        }
+
+        // Send transit queue.
+        while let Some(packet) = self.transit_queue.pop_front() {
+            #[cfg(feature = "link-ack")]
+            self.track_link_ack(&packet, current_time);
+            self.transmit(packet, interface_driver, current_time, hop_period);
+            return; // This return makes sending one packet per listen period
+        }
+
+        // Send packet queue.
+        while let Some(packet) = self.packet_queue.pop_front() {
+            #[cfg(feature = "link-ack")]
+            self.track_link_ack(&packet, current_time);
+            self.transmit(packet, interface_driver, current_time, hop_period);
+            return; // This return makes sending one packet per listen period
+        }
+    }
+
+    /// Writes the packet into the interface, and spends the airtime for it.
+    #[cfg_attr(not(feature = "addressing"), allow(unused_variables))]
+    fn transmit<I>(
+        &mut self,
+        packet: Packet,
+        interface_driver: &mut I,
+        current_time: ms,
+        hop_period: ms,
+    ) where
+        I: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
+    {
+        #[cfg(feature = "addressing")]
+        self.sent_history.record(&packet, current_time, hop_period);
+        self.send_start_byte_sequence(interface_driver);
+        let _ = interface_driver.write_all(&packet.summarized().serialized());
+        #[cfg(feature = "airtime-limit")]
+        self.spend_frame_airtime();
+    }
+
+    #[cfg(feature = "link-ack")]
+    fn track_link_ack(&mut self, packet: &Packet, current_time: ms) {
+        if let Some(ref mut link_ack_tracker) = self.link_ack_tracker {
+            link_ack_tracker.track(packet, current_time);
+        }
+    }
+
+    #[cfg(feature = "airtime-limit")]
+    fn spend_frame_airtime(&mut self) {
+        if let Some(ref mut airtime_accountant) = self.airtime_accountant {
+            airtime_accountant.spend_frame();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::constants::PACKET_QUEUE_SIZE;
+    use crate::mesh_lib::node::packet::PacketState;
+    use crate::mesh_lib::node::PacketDataBytes;
+
+    fn make_transmitter(transit_queue_policy: QueuePolicy) -> Transmitter {
+        Transmitter::new(
+            transit_queue_policy,
+            #[cfg(feature = "airtime-limit")]
+            None,
+            #[cfg(feature = "link-ack")]
+            None,
+        )
+    }
+
+    fn make_transit_packet(id: IdType) -> Packet {
+        Packet::new(
+            1,
+            2,
+            id,
+            3,
+            PacketState::Normal,
+            true,
+            PacketDataBytes::new(),
+        )
+    }
+
+    /// Fills the transit queue with packets, which have ids since 1.
+    fn fill_transit_queue(transmitter: &mut Transmitter) {
+        for id in 1..=PACKET_QUEUE_SIZE as IdType {
+            assert!(transmitter.send_transit(make_transit_packet(id)).is_ok());
+        }
+    }
+
+    #[test]
+    fn newest_packet_is_dropped_from_full_transit_queue() {
+        let mut transmitter = make_transmitter(QueuePolicy::DropNewest);
+        fill_transit_queue(&mut transmitter);
+
+        let newest_id = PACKET_QUEUE_SIZE as IdType + 1;
+        assert!(transmitter
+            .send_transit(make_transit_packet(newest_id))
+            .is_err());
+
+        assert_eq!(
+            transmitter.transit_queue.front().map(Packet::get_id),
+            Some(1)
+        );
+        assert_eq!(
+            transmitter.transit_queue.back().map(Packet::get_id),
+            Some(newest_id - 1)
+        );
+    }
+
+    #[test]
+    fn oldest_packet_is_dropped_from_full_transit_queue() {
+        let mut transmitter = make_transmitter(QueuePolicy::DropOldest);
+        fill_transit_queue(&mut transmitter);
+
+        let newest_id = PACKET_QUEUE_SIZE as IdType + 1;
+        assert!(transmitter
+            .send_transit(make_transit_packet(newest_id))
+            .is_err());
+
+        assert_eq!(transmitter.transit_queue.len(), PACKET_QUEUE_SIZE);
+        assert_eq!(
+            transmitter.transit_queue.front().map(Packet::get_id),
+            Some(2)
+        );
+        assert_eq!(
+            transmitter.transit_queue.back().map(Packet::get_id),
+            Some(newest_id)
+        );
+    }
+
+    #[test]
+    fn queue_policy_is_restored_from_code() {
+        for policy in [QueuePolicy::DropNewest, QueuePolicy::DropOldest] {
+            assert_eq!(QueuePolicy::from_code(policy.code()), Some(policy));
+        }
+        assert_eq!(QueuePolicy::from_code(42), None);
+    }
+}
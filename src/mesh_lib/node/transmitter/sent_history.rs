@@ -0,0 +1,218 @@
+use heapless::Deque;
+
+use super::super::{
+    constants::{SENT_PACKETS_HISTORY_SIZE, SENT_PACKET_MEMORY_PERIOD},
+    ms,
+    packet::{AddressType, IdType, Packet, PacketState},
+};
+
+/// Identity of the packet, which was written into the interface.
+/// Lifetime is not kept, as neighbours decrease it while forwarding.
+struct SentPacket {
+    source_device_identifier: AddressType,
+    id: IdType,
+    spec_state: PacketState,
+    sent_at: ms,
+    memory_period: ms,
+}
+
+impl SentPacket {
+    fn is_same(&self, packet: &Packet) -> bool {
+        self.source_device_identifier == packet.source_device_identifier
+            && self.id == packet.get_id()
+            && self.spec_state == packet.get_spec_state()
+    }
+
+    /// Period of time since `current_time`, after which the packet is forgotten.
+    fn time_till_forgotten(&self, current_time: ms) -> ms {
+        self.memory_period
+            .saturating_sub(current_time.wrapping_sub(self.sent_at))
+    }
+
+    fn is_remembered(&self, current_time: ms) -> bool {
+        self.time_till_forgotten(current_time) > 0
+    }
+}
+
+/// Period of time, during which the packet can come back to this device.
+/// The packet is forwarded `lifetime` times, and each forwarding device
+/// is expected to hold it for no longer than `hop_period`.
+fn memory_period(packet: &Packet, hop_period: ms) -> ms {
+    let travel_period = (packet.get_lifetime() as ms).saturating_mul(hop_period);
+    travel_period.max(SENT_PACKET_MEMORY_PERIOD)
+}
+
+/// Remembers packets, sent by this device, for as long as they can
+/// travel trough the network and come back, but not shorter than
+/// `SENT_PACKET_MEMORY_PERIOD`, in order to tell them apart from
+/// packets of other device with the same address.
+///
+/// In case if more than `SENT_PACKETS_HISTORY_SIZE` packets are remembered
+/// simultaneously - the earliest one is forgotten, and till it's period is over,
+/// every heard packet is treated as sent by this device.
+pub struct SentHistory {
+    sent: Deque<SentPacket, SENT_PACKETS_HISTORY_SIZE>,
+    forgotten: Option<SentPacket>,
+}
+
+impl SentHistory {
+    pub fn new() -> SentHistory {
+        SentHistory {
+            sent: Deque::new(),
+            forgotten: None,
+        }
+    }
+
+    /// Remembers the packet, which is sent at `current_time`.
+    /// `hop_period` is the period of time, for which each device
+    /// is expected to hold the packet before forwarding it.
+    pub fn record(&mut self, packet: &Packet, current_time: ms, hop_period: ms) {
+        while let Some(earliest) = self.sent.front() {
+            if earliest.is_remembered(current_time) {
+                break;
+            }
+            self.sent.pop_front();
+        }
+
+        if self.sent.is_full() {
+            if let Some(forgotten) = self.sent.pop_front() {
+                // Packets are remembered for different periods of time,
+                // so the one, which is forgotten the latest, is kept.
+                let is_remembered_longer = match self.forgotten {
+                    Some(ref previous) => {
+                        forgotten.time_till_forgotten(current_time)
+                            > previous.time_till_forgotten(current_time)
+                    }
+                    None => true,
+                };
+                if is_remembered_longer {
+                    self.forgotten.replace(forgotten);
+                }
+            }
+        }
+
+        let _ = self.sent.push_back(SentPacket {
+            source_device_identifier: packet.source_device_identifier,
+            id: packet.get_id(),
+            spec_state: packet.get_spec_state(),
+            sent_at: current_time,
+            memory_period: memory_period(packet, hop_period),
+        });
+    }
+
+    /// Tells if the `heard` packet was sent by this device recently.
+    pub fn has_sent(&self, heard: &Packet, current_time: ms) -> bool {
+        if let Some(ref forgotten) = self.forgotten {
+            if forgotten.is_remembered(current_time) {
+                return true;
+            }
+        }
+        self.sent
+            .iter()
+            .any(|sent| sent.is_remembered(current_time) && sent.is_same(heard))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_lib::node::packet::{LifeTimeType, PacketDataBytes};
+
+    const HOP_PERIOD: ms = 10;
+
+    fn make_packet(id: IdType) -> Packet {
+        make_packet_with_lifetime(id, 1)
+    }
+
+    fn make_packet_with_lifetime(id: IdType, lifetime: LifeTimeType) -> Packet {
+        Packet::new(
+            1,
+            2,
+            id,
+            lifetime,
+            PacketState::Normal,
+            true,
+            PacketDataBytes::new(),
+        )
+    }
+
+    #[test]
+    fn sent_packet_is_remembered() {
+        let mut history = SentHistory::new();
+        history.record(&make_packet(1), 0, HOP_PERIOD);
+
+        assert!(history.has_sent(&make_packet(1), SENT_PACKET_MEMORY_PERIOD - 1));
+        assert!(!history.has_sent(&make_packet(2), SENT_PACKET_MEMORY_PERIOD - 1));
+    }
+
+    #[test]
+    fn sent_packet_is_forgotten_after_memory_period() {
+        let mut history = SentHistory::new();
+        history.record(&make_packet(1), 0, HOP_PERIOD);
+
+        assert!(!history.has_sent(&make_packet(1), SENT_PACKET_MEMORY_PERIOD));
+    }
+
+    #[test]
+    fn forgotten_packets_do_not_take_the_room() {
+        let mut history = SentHistory::new();
+        history.record(&make_packet(0), 0, HOP_PERIOD);
+        for id in 1..=SENT_PACKETS_HISTORY_SIZE as IdType {
+            history.record(
+                &make_packet(id),
+                SENT_PACKET_MEMORY_PERIOD + id as ms,
+                HOP_PERIOD,
+            );
+        }
+
+        assert!(history.has_sent(&make_packet(1), 2 * SENT_PACKET_MEMORY_PERIOD));
+    }
+
+    #[test]
+    fn heard_packets_are_treated_as_sent_while_forgotten_one_is_recent() {
+        let mut history = SentHistory::new();
+        for id in 0..=SENT_PACKETS_HISTORY_SIZE as IdType {
+            history.record(&make_packet(id), id as ms, HOP_PERIOD);
+        }
+
+        assert!(history.has_sent(&make_packet(100), SENT_PACKET_MEMORY_PERIOD - 1));
+        assert!(!history.has_sent(&make_packet(100), SENT_PACKET_MEMORY_PERIOD));
+    }
+
+    #[test]
+    fn sent_packet_is_remembered_across_u32_wraparound() {
+        let mut history = SentHistory::new();
+        history.record(&make_packet(1), ms::MAX - 10, HOP_PERIOD);
+
+        assert!(history.has_sent(&make_packet(1), 10));
+    }
+
+    #[test]
+    fn far_travelling_packet_is_remembered_till_it_can_come_back() {
+        let lifetime: LifeTimeType = 200;
+        let travel_period = lifetime as ms * HOP_PERIOD;
+        assert!(travel_period > SENT_PACKET_MEMORY_PERIOD);
+
+        let mut history = SentHistory::new();
+        history.record(&make_packet_with_lifetime(1, lifetime), 0, HOP_PERIOD);
+
+        // Neighbours forward the packet with decreased lifetime.
+        let echo = make_packet_with_lifetime(1, 1);
+        assert!(history.has_sent(&echo, travel_period - 1));
+        assert!(!history.has_sent(&echo, travel_period));
+    }
+
+    #[test]
+    fn latest_forgotten_packet_is_kept() {
+        let lifetime: LifeTimeType = 200;
+        let travel_period = lifetime as ms * HOP_PERIOD;
+
+        let mut history = SentHistory::new();
+        history.record(&make_packet_with_lifetime(0, lifetime), 0, HOP_PERIOD);
+        for id in 1..=SENT_PACKETS_HISTORY_SIZE as IdType + 1 {
+            history.record(&make_packet(id), id as ms, HOP_PERIOD);
+        }
+
+        assert!(history.has_sent(&make_packet(100), travel_period - 1));
+    }
+}
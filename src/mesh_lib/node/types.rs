@@ -5,11 +5,24 @@ use super::packet::{Packet, PACKET_BYTES_SIZE};
 use super::constants::{PACKET_QUEUE_SIZE, PACKET_START_BYTES_COUNT};
 
 pub type PacketQueue = Deque<Packet, PACKET_QUEUE_SIZE>;
+/// Received packets along with indexes of interfaces, they came from.
+#[cfg(feature = "bridge")]
+pub type ReceivedPacketQueue = Deque<(Packet, usize), PACKET_QUEUE_SIZE>;
+/// Received packets. The node without `bridge` feature has the only interface.
+#[cfg(not(feature = "bridge"))]
+pub type ReceivedPacketQueue = Deque<Packet, PACKET_QUEUE_SIZE>;
 pub type ParserBytesBuffer = Deque<u8, { PACKET_BYTES_SIZE + PACKET_START_BYTES_COUNT }>;
 
 /// Type alias for a String with fixed length, that is made
 /// to simplify messaging between nodes.
 pub type NodeString = String<{ super::packet::CONTENT_SIZE }>;
 
+/// Time in milliseconds.
+#[cfg(not(feature = "time64"))]
 #[allow(non_camel_case_types)]
 pub type ms = u32;
+
+/// Time in milliseconds.
+#[cfg(feature = "time64")]
+#[allow(non_camel_case_types)]
+pub type ms = u64;
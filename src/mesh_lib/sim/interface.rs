@@ -0,0 +1,60 @@
+use core::convert::Infallible;
+use std::collections::VecDeque;
+use std::vec::Vec;
+
+/// In-memory interface of the simulated node.
+/// Bytes, written by the node, are kept in `written_bytes` till
+/// the simulator carries them over the links. Bytes, carried to the
+/// node, are kept in `received_bytes` till the node reads them.
+pub struct SimInterface {
+    written_bytes: Vec<u8>,
+    received_bytes: VecDeque<u8>,
+}
+
+impl SimInterface {
+    pub fn new() -> SimInterface {
+        SimInterface {
+            written_bytes: Vec::new(),
+            received_bytes: VecDeque::new(),
+        }
+    }
+
+    pub fn take_written_bytes(&mut self) -> Vec<u8> {
+        core::mem::take(&mut self.written_bytes)
+    }
+
+    pub fn put_received_bytes(&mut self, bytes: &[u8]) {
+        self.received_bytes.extend(bytes);
+    }
+}
+
+impl embedded_io::ErrorType for SimInterface {
+    type Error = Infallible;
+}
+
+impl embedded_io::ReadReady for SimInterface {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.received_bytes.is_empty())
+    }
+}
+
+impl embedded_io::Read for SimInterface {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let count = buf.len().min(self.received_bytes.len());
+        for (slot, byte) in buf.iter_mut().zip(self.received_bytes.drain(..count)) {
+            *slot = byte;
+        }
+        Ok(count)
+    }
+}
+
+impl embedded_io::Write for SimInterface {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.written_bytes.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
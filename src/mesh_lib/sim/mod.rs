@@ -0,0 +1,239 @@
+mod interface;
+mod scenario;
+
+use std::vec::Vec;
+
+use crate::mesh_lib::node::{ms, Node, NodeConfig, RandomNumberGenerator, XorShiftRng};
+
+use interface::SimInterface;
+
+pub use scenario::{Scenario, ScenarioError};
+
+/// Properties of the one way link between two simulated nodes.
+/// Default link delivers every transmission immediately and intact,
+/// unless it collides with other transmission, heard by the same node.
+#[derive(Clone, Copy, Default)]
+pub struct LinkConfig {
+    /// Chance in percents, that the transmission is not heard
+    /// by the receiving node at all.
+    pub loss: u8,
+
+    /// Chance in percents, that one bit of the heard
+    /// transmission is flipped.
+    pub corruption: u8,
+
+    /// Period of time in milliseconds, after which the transmission
+    /// is heard by the receiving node.
+    pub latency: ms,
+
+    /// Period of time in milliseconds, which one byte of the
+    /// transmission takes in the air. Transmission is heard once
+    /// it is over, and takes at least one millisecond in the air.
+    pub byte_airtime: ms,
+}
+
+struct Link {
+    to: usize,
+    config: LinkConfig,
+}
+
+struct Transmission {
+    to: usize,
+    starts_at: ms,
+    airtime: ms,
+    bytes: Vec<u8>,
+    is_collided: bool,
+}
+
+impl Transmission {
+    fn deliver_at(&self) -> ms {
+        self.starts_at.wrapping_add(self.airtime)
+    }
+
+    /// Tells if both transmissions are in the air at the same time.
+    /// Wrapping comparison, which survives the overflow of time.
+    fn overlaps(&self, other: &Transmission) -> bool {
+        self.starts_at.wrapping_sub(other.starts_at) < other.airtime.max(1)
+            || other.starts_at.wrapping_sub(self.starts_at) < self.airtime.max(1)
+    }
+}
+
+/// Deterministic network simulator, which runs nodes in the virtual time.
+/// Nodes are updated one by one on every millisecond, and the bytes,
+/// written by the node, are carried to the nodes, which hear it,
+/// according to the links between them.
+///
+/// Transmissions, which are heard by the same node, and are in the air
+/// at the same time, collide - the node hears none of them, like the radio does.
+///
+/// Everything random, like losses, corruptions, and random numbers of nodes,
+/// which have no `random_seed` set, is derived from the `seed` of the simulator,
+/// so the same scenario with the same seed always runs the same way.
+///
+/// `main.rs`:
+/// ```
+/// let mut simulator = Simulator::new(42);
+/// let node_1 = simulator.add_node(NodeConfig::new(ExactAddressType::new(1).unwrap(), 10));
+/// let node_2 = simulator.add_node(NodeConfig::new(ExactAddressType::new(2).unwrap(), 20));
+/// simulator.link(node_1, node_2, LinkConfig { loss: 10, ..Default::default() });
+///
+/// let _ = simulator.node(node_1).send_to_exact(data, ExactAddressType::new(2).unwrap(), 1, true);
+/// let delivered = simulator.run_until(1000, |simulator| simulator.node(node_2).receive().is_some());
+/// ```
+pub struct Simulator {
+    current_time: ms,
+    rng: XorShiftRng,
+    nodes: Vec<Node>,
+    interfaces: Vec<SimInterface>,
+    /// Links, by index of the node, which is heard trough them.
+    links: Vec<Vec<Link>>,
+    transmissions: Vec<Transmission>,
+}
+
+impl Simulator {
+    /// Creates empty simulator, with virtual time set to zero.
+    pub fn new(seed: u32) -> Simulator {
+        Simulator {
+            current_time: 0,
+            rng: XorShiftRng::new(seed),
+            nodes: Vec::new(),
+            interfaces: Vec::new(),
+            links: Vec::new(),
+            transmissions: Vec::new(),
+        }
+    }
+
+    /// Adds the node, which hears nothing until it is linked to other nodes.
+    /// Returns the index of the node within the simulator.
+    pub fn add_node(&mut self, mut config: NodeConfig) -> usize {
+        if config.random_seed.is_none() {
+            config.random_seed = Some(self.rng.next_u32());
+        }
+        self.nodes.push(Node::new(config));
+        self.interfaces.push(SimInterface::new());
+        self.links.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    /// Makes both nodes hear each other trough the links with the same `config`.
+    pub fn link(&mut self, first: usize, second: usize, config: LinkConfig) {
+        self.link_one_way(first, second, config);
+        self.link_one_way(second, first, config);
+    }
+
+    /// Makes node `to` hear node `from`. Replaces existing link between them.
+    pub fn link_one_way(&mut self, from: usize, to: usize, config: LinkConfig) {
+        self.unlink_one_way(from, to);
+        self.links[from].push(Link { to, config });
+    }
+
+    /// Makes both nodes not hear each other anymore.
+    pub fn unlink(&mut self, first: usize, second: usize) {
+        self.unlink_one_way(first, second);
+        self.unlink_one_way(second, first);
+    }
+
+    /// Makes node `to` not hear node `from` anymore.
+    pub fn unlink_one_way(&mut self, from: usize, to: usize) {
+        self.links[from].retain(|link| link.to != to);
+    }
+
+    /// Returns the node, added under the `index`.
+    pub fn node(&mut self, index: usize) -> &mut Node {
+        &mut self.nodes[index]
+    }
+
+    /// Returns current virtual time in milliseconds.
+    pub fn current_time(&self) -> ms {
+        self.current_time
+    }
+
+    /// Advances virtual time by one millisecond:
+    /// * Transmissions, which are over, are heard by the nodes,
+    ///   unless they collided.
+    /// * Every node is updated, in order of adding.
+    /// * Bytes, written by the nodes, are carried over the links.
+    pub fn step(&mut self) {
+        self.deliver_transmissions();
+
+        for index in 0..self.nodes.len() {
+            let _ = self.nodes[index].update(&mut self.interfaces[index], self.current_time);
+            let bytes = self.interfaces[index].take_written_bytes();
+            if !bytes.is_empty() {
+                self.transmit(index, &bytes);
+            }
+        }
+
+        self.current_time = self.current_time.wrapping_add(1);
+    }
+
+    /// Runs the simulation for `duration` milliseconds of virtual time.
+    pub fn run_for(&mut self, duration: ms) {
+        for _ in 0..duration {
+            self.step();
+        }
+    }
+
+    /// Runs the simulation till the `condition` is met, but not longer
+    /// than `timeout` milliseconds of virtual time.
+    /// Returns `true` if the condition was met.
+    pub fn run_until<F>(&mut self, timeout: ms, mut condition: F) -> bool
+    where
+        F: FnMut(&mut Simulator) -> bool,
+    {
+        for _ in 0..timeout {
+            self.step();
+            if condition(self) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn deliver_transmissions(&mut self) {
+        let current_time = self.current_time;
+        let interfaces = &mut self.interfaces;
+        self.transmissions.retain(|transmission| {
+            // Wrapping comparison, which survives the overflow of time.
+            if current_time.wrapping_sub(transmission.deliver_at()) <= ms::MAX / 2 {
+                if !transmission.is_collided {
+                    interfaces[transmission.to].put_received_bytes(&transmission.bytes);
+                }
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    fn transmit(&mut self, from: usize, bytes: &[u8]) {
+        for link in self.links[from].iter() {
+            if is_chance_met(&mut self.rng, link.config.loss) {
+                continue;
+            }
+            let mut bytes = Vec::from(bytes);
+            if is_chance_met(&mut self.rng, link.config.corruption) {
+                let position = self.rng.next_u32() as usize % bytes.len();
+                bytes[position] ^= 1 << (self.rng.next_u32() % 8);
+            }
+            let mut transmission = Transmission {
+                to: link.to,
+                starts_at: self.current_time.wrapping_add(link.config.latency),
+                airtime: link.config.byte_airtime.wrapping_mul(bytes.len() as ms),
+                bytes,
+                is_collided: false,
+            };
+            for other in self.transmissions.iter_mut() {
+                if other.to == transmission.to && other.overlaps(&transmission) {
+                    other.is_collided = true;
+                    transmission.is_collided = true;
+                }
+            }
+            self.transmissions.push(transmission);
+        }
+    }
+}
+
+fn is_chance_met(rng: &mut XorShiftRng, percents: u8) -> bool {
+    percents > 0 && rng.next_u32() % 100 < percents as u32
+}
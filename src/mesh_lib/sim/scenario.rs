@@ -0,0 +1,437 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use serde::Deserialize;
+
+use crate::mesh_lib::node::{ms, ExactAddressType, NodeConfig, NodeString};
+
+use super::{LinkConfig, Simulator};
+
+/// Address of the single node, or inclusive range of addresses, like `[3, 255]`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Addresses {
+    Single(u8),
+    Range([u8; 2]),
+}
+
+fn expand(list: &[Addresses]) -> impl Iterator<Item = u8> + '_ {
+    list.iter().flat_map(|addresses| match *addresses {
+        Addresses::Single(address) => address..=address,
+        Addresses::Range([first, last]) => first..=last,
+    })
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScenarioConfig {
+    #[serde(default)]
+    seed: u32,
+    timeout: ms,
+    nodes: Vec<NodeEntry>,
+    #[serde(default)]
+    links: Vec<LinkEntry>,
+    #[serde(default)]
+    sends: Vec<SendEntry>,
+    #[serde(default)]
+    expected: Vec<ExpectedEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NodeEntry {
+    addresses: Vec<Addresses>,
+    listen_period: ms,
+    /// Is added to the listen period of each next node of the entry.
+    #[serde(default)]
+    listen_period_step: ms,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LinkEntry {
+    /// Nodes, which hear each other.
+    #[serde(default)]
+    nodes: Vec<Addresses>,
+    /// Nodes, which are heard by `to` nodes, but do not hear them.
+    #[serde(default)]
+    from: Vec<Addresses>,
+    #[serde(default)]
+    to: Vec<Addresses>,
+    #[serde(default)]
+    loss: u8,
+    #[serde(default)]
+    corruption: u8,
+    #[serde(default)]
+    latency: ms,
+    #[serde(default)]
+    byte_airtime: ms,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SendEntry {
+    #[serde(default)]
+    at: ms,
+    from: u8,
+    /// The message is broadcasted, if not set.
+    to: Option<u8>,
+    message: String,
+    lifetime: u8,
+    #[serde(default = "default_true")]
+    ignore_duplicates: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExpectedEntry {
+    node: u8,
+    from: u8,
+    message: String,
+    #[serde(default = "default_true")]
+    delivered: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Errors, that can be returned by `Scenario` methods.
+pub enum ScenarioError {
+    /// Case, when the scenario file can not be read.
+    Io(std::io::Error),
+
+    /// Case, when the scenario is not valid TOML,
+    /// or does not match the format of scenario.
+    Parse(String),
+
+    /// Case, when node has zero address, which is reserved for broadcast.
+    InvalidAddress,
+
+    /// Case, when several nodes have the same address.
+    DuplicatedNode(u8),
+
+    /// Case, when the scenario refers to the node, which is not declared.
+    UnknownNode(u8),
+
+    /// Case, when the message does not fit into the packet.
+    MessageTooLong(String),
+
+    /// Case, when the node refused to send the message.
+    SendFailed { from: u8, message: String },
+
+    /// Case, when the message was not delivered, but was expected to be,
+    /// or was delivered, but was expected not to be.
+    ExpectationNotMet {
+        node: u8,
+        from: u8,
+        message: String,
+        delivered: bool,
+    },
+}
+
+impl core::fmt::Debug for ScenarioError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ScenarioError::Io(error) => write!(f, "Io({:?})", error),
+            ScenarioError::Parse(error) => write!(f, "Parse({})", error),
+            ScenarioError::InvalidAddress => write!(f, "InvalidAddress"),
+            ScenarioError::DuplicatedNode(address) => write!(f, "DuplicatedNode({})", address),
+            ScenarioError::UnknownNode(address) => write!(f, "UnknownNode({})", address),
+            ScenarioError::MessageTooLong(message) => write!(f, "MessageTooLong({:?})", message),
+            ScenarioError::SendFailed { from, message } => {
+                write!(f, "SendFailed {{ from: {}, message: {:?} }}", from, message)
+            }
+            ScenarioError::ExpectationNotMet {
+                node,
+                from,
+                message,
+                delivered,
+            } => write!(
+                f,
+                "ExpectationNotMet {{ node: {}, from: {}, message: {:?}, delivered: {} }}",
+                node, from, message, delivered
+            ),
+        }
+    }
+}
+
+/// Message, received by the node during the run of scenario.
+#[derive(PartialEq)]
+struct Delivery {
+    node: u8,
+    from: u8,
+    message: String,
+}
+
+/// Simulation scenario, which describes the network as data:
+/// nodes, links between them, messages to send, and deliveries
+/// to expect. Scenario runs on the `Simulator`.
+///
+/// `scenario.toml`:
+/// ```toml
+/// seed = 1
+/// timeout = 1000
+///
+/// [[nodes]]
+/// addresses = [[1, 3]]
+/// listen_period = 10
+/// listen_period_step = 10
+///
+/// [[links]]
+/// nodes = [1, 2]
+///
+/// [[links]]
+/// nodes = [2, 3]
+/// loss = 10
+///
+/// [[sends]]
+/// from = 1
+/// to = 3
+/// message = "This is the message from node 1"
+/// lifetime = 2
+///
+/// [[expected]]
+/// node = 3
+/// from = 1
+/// message = "This is the message from node 1"
+/// ```
+///
+/// `main.rs`:
+/// ```
+/// Scenario::from_file("scenario.toml")?.run()?;
+/// ```
+pub struct Scenario {
+    config: ScenarioConfig,
+}
+
+impl Scenario {
+    /// Parses the scenario from TOML text.
+    pub fn from_toml(text: &str) -> Result<Scenario, ScenarioError> {
+        match toml::from_str(text) {
+            Ok(config) => Ok(Scenario { config }),
+            Err(error) => Err(ScenarioError::Parse(error.to_string())),
+        }
+    }
+
+    /// Reads and parses the scenario from TOML file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Scenario, ScenarioError> {
+        let text = std::fs::read_to_string(path).map_err(ScenarioError::Io)?;
+        Scenario::from_toml(&text)
+    }
+
+    /// Runs the scenario. Once all expected messages are delivered,
+    /// and no message is expected to be not delivered, the run is
+    /// finished before the timeout.
+    pub fn run(&self) -> Result<(), ScenarioError> {
+        let mut simulator = Simulator::new(self.config.seed);
+        let nodes = self.add_nodes(&mut simulator)?;
+        self.add_links(&mut simulator, &nodes)?;
+        self.validate(&nodes)?;
+
+        let mut sends: Vec<&SendEntry> = self.config.sends.iter().collect();
+        sends.sort_by_key(|send| send.at);
+        let mut sends = sends.into_iter().peekable();
+
+        let is_early_finish_allowed = self.config.expected.iter().all(|entry| entry.delivered);
+        let mut deliveries: Vec<Delivery> = Vec::new();
+
+        while simulator.current_time() < self.config.timeout {
+            while let Some(send) = sends.next_if(|send| send.at <= simulator.current_time()) {
+                Scenario::send(&mut simulator, &nodes, send)?;
+            }
+
+            simulator.step();
+
+            for (&address, &index) in nodes.iter() {
+                while let Some(packet) = simulator.node(index).receive() {
+                    let message: String = packet.data.iter().map(|c| *c as char).collect();
+                    deliveries.push(Delivery {
+                        node: address,
+                        from: packet.source_device_identifier,
+                        message: message.trim_end_matches('\0').to_string(),
+                    });
+                }
+            }
+
+            if is_early_finish_allowed
+                && self
+                    .config
+                    .expected
+                    .iter()
+                    .all(|entry| deliveries.contains(&Scenario::delivery_of(entry)))
+            {
+                break;
+            }
+        }
+
+        for entry in self.config.expected.iter() {
+            if deliveries.contains(&Scenario::delivery_of(entry)) != entry.delivered {
+                return Err(ScenarioError::ExpectationNotMet {
+                    node: entry.node,
+                    from: entry.from,
+                    message: entry.message.clone(),
+                    delivered: entry.delivered,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn add_nodes(&self, simulator: &mut Simulator) -> Result<BTreeMap<u8, usize>, ScenarioError> {
+        let mut nodes = BTreeMap::new();
+        for entry in self.config.nodes.iter() {
+            let mut listen_period = entry.listen_period;
+            for address in expand(&entry.addresses) {
+                let device_address =
+                    ExactAddressType::new(address).ok_or(ScenarioError::InvalidAddress)?;
+                if nodes.contains_key(&address) {
+                    return Err(ScenarioError::DuplicatedNode(address));
+                }
+                let index = simulator.add_node(NodeConfig::new(device_address, listen_period));
+                nodes.insert(address, index);
+                listen_period = listen_period.wrapping_add(entry.listen_period_step);
+            }
+        }
+        Ok(nodes)
+    }
+
+    fn add_links(
+        &self,
+        simulator: &mut Simulator,
+        nodes: &BTreeMap<u8, usize>,
+    ) -> Result<(), ScenarioError> {
+        for entry in self.config.links.iter() {
+            let config = LinkConfig {
+                loss: entry.loss,
+                corruption: entry.corruption,
+                latency: entry.latency,
+                byte_airtime: entry.byte_airtime,
+            };
+            for (from, to) in expand(&entry.nodes)
+                .flat_map(|from| expand(&entry.nodes).map(move |to| (from, to)))
+                .chain(
+                    expand(&entry.from)
+                        .flat_map(|from| expand(&entry.to).map(move |to| (from, to))),
+                )
+                .filter(|(from, to)| from != to)
+            {
+                simulator.link_one_way(node_index(nodes, from)?, node_index(nodes, to)?, config);
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks sends and expectations before the run,
+    /// so the mistake in the scenario is not reported as failed expectation.
+    fn validate(&self, nodes: &BTreeMap<u8, usize>) -> Result<(), ScenarioError> {
+        for send in self.config.sends.iter() {
+            node_index(nodes, send.from)?;
+            if let Some(to) = send.to {
+                ExactAddressType::new(to).ok_or(ScenarioError::InvalidAddress)?;
+            }
+            if NodeString::try_from(send.message.as_str()).is_err() {
+                return Err(ScenarioError::MessageTooLong(send.message.clone()));
+            }
+        }
+        for entry in self.config.expected.iter() {
+            node_index(nodes, entry.node)?;
+            node_index(nodes, entry.from)?;
+        }
+        Ok(())
+    }
+
+    fn send(
+        simulator: &mut Simulator,
+        nodes: &BTreeMap<u8, usize>,
+        send: &SendEntry,
+    ) -> Result<(), ScenarioError> {
+        let data = NodeString::try_from(send.message.as_str())
+            .map_err(|_| ScenarioError::MessageTooLong(send.message.clone()))?
+            .into_bytes();
+        let node = simulator.node(node_index(nodes, send.from)?);
+        let result = match send.to.and_then(ExactAddressType::new) {
+            Some(to) => node.send_to_exact(data, to, send.lifetime, send.ignore_duplicates),
+            None => node.broadcast(data, send.lifetime),
+        };
+        result.map_err(|_| ScenarioError::SendFailed {
+            from: send.from,
+            message: send.message.clone(),
+        })
+    }
+
+    fn delivery_of(entry: &ExpectedEntry) -> Delivery {
+        Delivery {
+            node: entry.node,
+            from: entry.from,
+            message: entry.message.clone(),
+        }
+    }
+}
+
+fn node_index(nodes: &BTreeMap<u8, usize>, address: u8) -> Result<usize, ScenarioError> {
+    nodes
+        .get(&address)
+        .copied()
+        .ok_or(ScenarioError::UnknownNode(address))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_addresses_are_expanded() {
+        let list = [Addresses::Single(7), Addresses::Range([1, 3])];
+        assert_eq!(expand(&list).collect::<Vec<u8>>(), [7, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_scenario_refers_to_unknown_node() {
+        let scenario = Scenario::from_toml(
+            r#"
+            timeout = 100
+
+            [[nodes]]
+            addresses = [1]
+            listen_period = 10
+
+            [[links]]
+            nodes = [1, 2]
+            "#,
+        )
+        .unwrap();
+        assert!(matches!(scenario.run(), Err(ScenarioError::UnknownNode(2))));
+    }
+
+    #[test]
+    fn test_scenario_expects_message_from_unknown_node() {
+        let scenario = Scenario::from_toml(
+            r#"
+            timeout = 100
+
+            [[nodes]]
+            addresses = [1, 2]
+            listen_period = 10
+
+            [[expected]]
+            node = 2
+            from = 3
+            message = "Node 3 is not declared"
+            delivered = false
+            "#,
+        )
+        .unwrap();
+        assert!(matches!(scenario.run(), Err(ScenarioError::UnknownNode(3))));
+    }
+
+    #[test]
+    fn test_scenario_with_unknown_field_is_not_parsed() {
+        assert!(matches!(
+            Scenario::from_toml("timeout = 100\nnodes = []\nseeed = 1\n"),
+            Err(ScenarioError::Parse(_))
+        ));
+    }
+}
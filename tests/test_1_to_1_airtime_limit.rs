@@ -0,0 +1,118 @@
+#[cfg(all(test, feature = "airtime-limit"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, AirtimeLimitConfig, ExactAddressType, LifeTimeType, Node, NodeConfig, NodeString,
+    };
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+
+    const SIMULATION_TIMEOUT: ms = 300;
+
+    #[test]
+    fn test_send_1_to_1_airtime_limit() {
+        let mut network_simulator = NetworkSimulator::new(1);
+        network_simulator.create_ether("1");
+        let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+
+        ether.register_driver(modem_1.clone());
+        ether.register_driver(modem_2.clone());
+
+        // 1% of 5 seconds gives 50 ms of airtime budget,
+        // which is enough to send only one packet at 9600 baud.
+        let mut config = NodeConfig::new(ExactAddressType::try_from(1).unwrap(), 10 as ms);
+        config.airtime_limit = Some(AirtimeLimitConfig {
+            baud_rate: 9600,
+            duty_cycle_permille: 10,
+            window: 5000 as ms,
+        });
+        let mut node_1 = Node::new(config);
+
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            20 as ms,
+        ));
+
+        for message in ["First message from node 1", "Second message from node 1"] {
+            let _ = node_1.send_to_exact(
+                NodeString::from_iter(message.chars()).into_bytes(),
+                ExactAddressType::try_from(2).unwrap(),
+                LifeTimeType::try_from(1).unwrap(),
+                false,
+            );
+        }
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut received_count = 0;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+
+            if let Some(message) = node_2.receive() {
+                let expected = NodeString::from_iter("First message from node 1".chars());
+                let got = NodeString::from_iter(message.data.iter().map(|c| *c as char));
+
+                assert!(got.starts_with(expected.as_str()));
+
+                received_count += 1;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        assert_eq!(received_count, 1);
+        assert!(
+            node_1
+                .stats()
+                .remaining_airtime
+                .expect("Airtime is limited")
+                < 10 as ms
+        );
+    }
+
+    #[test]
+    fn test_remaining_airtime_is_restored_between_speaks() {
+        let mut modem = WirelessModemFake::new("1");
+
+        // 10% of 500 ms gives 50 ms of airtime budget, which is
+        // restored by 0.1 ms per each millisecond.
+        let mut config = NodeConfig::new(ExactAddressType::try_from(1).unwrap(), 100 as ms);
+        config.airtime_limit = Some(AirtimeLimitConfig {
+            baud_rate: 9600,
+            duty_cycle_permille: 100,
+            window: 500 as ms,
+        });
+        let mut node = Node::new(config);
+
+        let _ = node.send_to_exact(
+            NodeString::from_iter("Message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(2).unwrap(),
+            LifeTimeType::try_from(1).unwrap(),
+            false,
+        );
+
+        // Sending of the packet takes 44.8 ms of the budget.
+        let _ = node.update(&mut modem, 100 as ms);
+        let remaining = node.stats().remaining_airtime.expect("Airtime is limited");
+        assert_eq!(remaining, 5 as ms);
+
+        // It is not the time to speak yet, but the budget is restored.
+        let _ = node.update(&mut modem, 150 as ms);
+        let remaining = node.stats().remaining_airtime.expect("Airtime is limited");
+        assert_eq!(remaining, 10 as ms);
+    }
+}
@@ -18,15 +18,15 @@ mod tests {
         ether.register_driver(modem_1.clone());
         ether.register_driver(modem_2.clone());
 
-        let mut node_1 = Node::new(NodeConfig {
-            device_address: ExactAddressType::try_from(1).unwrap(),
-            listen_period: 10 as ms,
-        });
-
-        let mut node_2 = Node::new(NodeConfig {
-            device_address: ExactAddressType::try_from(2).unwrap(),
-            listen_period: 20 as ms,
-        });
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            10 as ms,
+        ));
+
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            20 as ms,
+        ));
 
         let _ = node_1.send_to_exact(
             NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
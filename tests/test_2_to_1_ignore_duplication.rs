@@ -23,20 +23,20 @@ mod tests {
             ether.register_driver(modem_3.clone());
         }
 
-        let mut node_1 = Node::new(NodeConfig {
-            device_address: ExactAddressType::try_from(1).unwrap(),
-            listen_period: 150 as ms,
-        });
-
-        let mut node_2 = Node::new(NodeConfig {
-            device_address: ExactAddressType::try_from(2).unwrap(),
-            listen_period: 160 as ms,
-        });
-
-        let mut node_3 = Node::new(NodeConfig {
-            device_address: ExactAddressType::try_from(3).unwrap(),
-            listen_period: 170 as ms,
-        });
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            150 as ms,
+        ));
+
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            160 as ms,
+        ));
+
+        let mut node_3 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(3).unwrap(),
+            170 as ms,
+        ));
 
         let _ = node_1.send_to_exact(
             NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
@@ -0,0 +1,96 @@
+#[cfg(all(test, feature = "carrier-sense", feature = "listen-period-jitter"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, CarrierSenseConfig, ExactAddressType, LifeTimeType, Node, NodeConfig, NodeString,
+    };
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+
+    // Both nodes are configured same way, as if they were flashed with same firmware.
+    const LISTEN_PERIOD: ms = 10;
+    const LISTEN_PERIOD_JITTER: ms = 60;
+
+    #[test]
+    fn test_send_2_to_2_listen_period_jitter() {
+        let mut network_simulator = NetworkSimulator::new(1);
+        network_simulator.create_ether("1");
+        let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+
+        ether.register_driver(modem_1.clone());
+        ether.register_driver(modem_2.clone());
+
+        let mut config = NodeConfig::new(ExactAddressType::try_from(1).unwrap(), LISTEN_PERIOD);
+        config.carrier_sense = Some(CarrierSenseConfig {
+            quiet_window: 5 as ms,
+            max_backoff: 20 as ms,
+        });
+        config.listen_period_jitter = LISTEN_PERIOD_JITTER;
+        let mut node_1 = Node::new(config);
+
+        let mut config = NodeConfig::new(ExactAddressType::try_from(2).unwrap(), LISTEN_PERIOD);
+        config.carrier_sense = Some(CarrierSenseConfig {
+            quiet_window: 5 as ms,
+            max_backoff: 20 as ms,
+        });
+        config.listen_period_jitter = LISTEN_PERIOD_JITTER;
+        let mut node_2 = Node::new(config);
+
+        let _ = node_1.send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(2).unwrap(),
+            LifeTimeType::try_from(1).unwrap(),
+            false,
+        );
+
+        let _ = node_2.send_to_exact(
+            NodeString::from_iter("This is the message from node 2".chars()).into_bytes(),
+            ExactAddressType::try_from(1).unwrap(),
+            LifeTimeType::try_from(1).unwrap(),
+            false,
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let (mut is_node_1_received, mut is_node_2_received) = (false, false);
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+
+            if let Some(message) = node_1.receive() {
+                let expected = NodeString::from_iter("This is the message from node 2".chars());
+                let got = NodeString::from_iter(message.data.iter().map(|c| *c as char));
+
+                assert!(got.starts_with(expected.as_str()));
+                is_node_1_received = true;
+            }
+
+            if let Some(message) = node_2.receive() {
+                let expected = NodeString::from_iter("This is the message from node 1".chars());
+                let got = NodeString::from_iter(message.data.iter().map(|c| *c as char));
+
+                assert!(got.starts_with(expected.as_str()));
+                is_node_2_received = true;
+            }
+
+            if is_node_1_received && is_node_2_received {
+                break;
+            }
+
+            if current_time >= 400 as ms {
+                panic!("Simulation timeout");
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+    }
+}
@@ -0,0 +1,176 @@
+#[cfg(all(test, feature = "addressing"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, AddressAcquisitionConfig, AddressAcquisitionMode, AddressCoordinatorConfig,
+        ExactAddressType, Node, NodeConfig, NodeString,
+    };
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+    const NODE_3_LISTEN_PERIOD: ms = 30;
+
+    const SIMULATION_TIMEOUT: ms = 2000;
+
+    fn acquisition_config(
+        hardware_id: u32,
+        mode: AddressAcquisitionMode,
+    ) -> AddressAcquisitionConfig {
+        AddressAcquisitionConfig {
+            hardware_id,
+            mode,
+            answer_timeout: 200 as ms,
+            probes_count: 2,
+            lifetime: 2,
+        }
+    }
+
+    #[test]
+    pub fn test_address_lease_travel_2_hops() {
+        let mut network_simulator = NetworkSimulator::new(0);
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+        let mut modem_3 = WirelessModemFake::new("3");
+
+        {
+            network_simulator.create_ether("1");
+            let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(modem_2.clone());
+        }
+        {
+            network_simulator.create_ether("2");
+            let mut ether = network_simulator.get_ether("2").expect("Can not get ether");
+            ether.register_driver(modem_2.clone());
+            ether.register_driver(modem_3.clone());
+        }
+
+        let coordinator_address = ExactAddressType::try_from(1).unwrap();
+
+        let mut config = NodeConfig::new(coordinator_address, NODE_1_LISTEN_PERIOD);
+        config.address_coordinator = Some(AddressCoordinatorConfig {
+            first_address: ExactAddressType::try_from(10).unwrap(),
+            last_address: ExactAddressType::try_from(20).unwrap(),
+            lease_period: 60_000 as ms,
+        });
+        let mut node_1 = Node::new(config);
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(3).unwrap(), NODE_3_LISTEN_PERIOD);
+        config.address_acquisition = Some(acquisition_config(
+            0xC0FFEE,
+            AddressAcquisitionMode::Leased {
+                coordinator: coordinator_address,
+            },
+        ));
+        let mut node_3 = Node::new(config);
+
+        assert_eq!(node_3.address(), None);
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+        let mut is_data_sent = false;
+        let mut received_message = None;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+            let _ = node_3.update(&mut modem_3, current_time);
+
+            if let (Some(leased_address), false) = (node_3.address(), is_data_sent) {
+                is_data_sent = node_1
+                    .send_to_exact(
+                        NodeString::try_from("Leased").unwrap().into_bytes(),
+                        leased_address,
+                        2,
+                        true,
+                    )
+                    .is_ok();
+            }
+
+            if let Some(message) = node_3.receive() {
+                received_message = Some(NodeString::from_iter(
+                    message.data.iter().map(|c| *c as char),
+                ));
+                break;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        let leased_address = node_3.address().expect("Address shall be leased");
+        assert!((10..=20).contains(&leased_address.get()));
+
+        let received_message = received_message.expect("Message shall be received");
+        assert!(received_message.starts_with("Leased"));
+    }
+
+    #[test]
+    pub fn test_address_self_assigned() {
+        let mut network_simulator = NetworkSimulator::new(0);
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+
+        {
+            network_simulator.create_ether("1");
+            let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(modem_2.clone());
+        }
+
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(1).unwrap(), NODE_1_LISTEN_PERIOD);
+        config.address_acquisition = Some(acquisition_config(
+            0xC0FFEE,
+            AddressAcquisitionMode::SelfAssigned,
+        ));
+        let mut node_1 = Node::new(config);
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(2).unwrap(), NODE_2_LISTEN_PERIOD);
+        config.address_acquisition = Some(acquisition_config(
+            0xBEEF,
+            AddressAcquisitionMode::SelfAssigned,
+        ));
+        let mut node_2 = Node::new(config);
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+
+            if node_1.address().is_some() && node_2.address().is_some() {
+                break;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        let node_1_address = node_1.address().expect("Node 1 shall claim the address");
+        let node_2_address = node_2.address().expect("Node 2 shall claim the address");
+        assert_ne!(node_1_address, node_2_address);
+    }
+}
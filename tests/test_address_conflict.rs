@@ -0,0 +1,153 @@
+#[cfg(all(test, feature = "addressing"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{ms, ExactAddressType, Node, NodeConfig, NodeString};
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+    const NODE_3_LISTEN_PERIOD: ms = 30;
+
+    const PROBE_TIMEOUT: ms = 200;
+    const SIMULATION_TIMEOUT: ms = 300;
+
+    /// Node 1 and node 3 have the same address, and are connected trough node 2.
+    fn make_network() -> (NetworkSimulator, [WirelessModemFake; 3], [Node; 3]) {
+        let network_simulator = NetworkSimulator::new(0);
+
+        let modem_1 = WirelessModemFake::new("1");
+        let modem_2 = WirelessModemFake::new("2");
+        let modem_3 = WirelessModemFake::new("3");
+
+        {
+            network_simulator.create_ether("1");
+            let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(modem_2.clone());
+        }
+        {
+            network_simulator.create_ether("2");
+            let mut ether = network_simulator.get_ether("2").expect("Can not get ether");
+            ether.register_driver(modem_2.clone());
+            ether.register_driver(modem_3.clone());
+        }
+
+        let node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+        let node_3 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_3_LISTEN_PERIOD,
+        ));
+
+        (
+            network_simulator,
+            [modem_1, modem_2, modem_3],
+            [node_1, node_2, node_3],
+        )
+    }
+
+    #[test]
+    fn test_address_conflict_detected() {
+        let (
+            mut network_simulator,
+            [mut modem_1, mut modem_2, mut modem_3],
+            [mut node_1, mut node_2, mut node_3],
+        ) = make_network();
+
+        let _ = node_1.broadcast(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            2,
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+            let _ = node_3.update(&mut modem_3, current_time);
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        // Own packet forwarded back by node 2 is not a conflict.
+        assert!(!node_1.take_address_conflict());
+        assert_eq!(node_1.stats().address_conflicts, 0);
+
+        assert!(node_2.stats().address_conflicts == 0);
+
+        assert!(node_3.stats().address_conflicts > 0);
+        assert!(node_3.take_address_conflict());
+        assert!(!node_3.take_address_conflict());
+    }
+
+    #[test]
+    fn test_probe_address() {
+        let (
+            mut network_simulator,
+            [mut modem_1, mut modem_2, mut modem_3],
+            [mut node_1, mut node_2, mut node_3],
+        ) = make_network();
+
+        let start_time = Instant::now();
+        let clock = move || Instant::now().duration_since(start_time).as_millis() as ms;
+
+        let prober_thread = std::thread::spawn(move || {
+            let is_own_address_used = node_1
+                .probe_address(
+                    ExactAddressType::try_from(1).unwrap(),
+                    2,
+                    PROBE_TIMEOUT,
+                    clock,
+                    &mut modem_1,
+                )
+                .expect("Probe shall be sent");
+            let is_free_address_used = node_1
+                .probe_address(
+                    ExactAddressType::try_from(3).unwrap(),
+                    2,
+                    PROBE_TIMEOUT,
+                    clock,
+                    &mut modem_1,
+                )
+                .expect("Probe shall be sent");
+            (is_own_address_used, is_free_address_used)
+        });
+
+        network_simulator.start_simulation_thread();
+
+        loop {
+            let current_time = clock();
+
+            let _ = node_2.update(&mut modem_2, current_time);
+            let _ = node_3.update(&mut modem_3, current_time);
+
+            if prober_thread.is_finished() || current_time >= 3 * PROBE_TIMEOUT {
+                break;
+            }
+        }
+
+        let (is_own_address_used, is_free_address_used) =
+            prober_thread.join().expect("Fail to join prober thread");
+
+        network_simulator.stop_simulation_thread();
+
+        assert!(is_own_address_used);
+        assert!(!is_free_address_used);
+    }
+}
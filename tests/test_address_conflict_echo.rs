@@ -0,0 +1,78 @@
+#[cfg(all(test, feature = "sim", feature = "addressing", feature = "sniffer"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, ExactAddressType, LifeTimeType, LinkConfig, NodeConfig, NodeString, Simulator,
+        SnifferConfig,
+    };
+
+    // Node 2 speaks rarer, than node 1, so packets of node 1 wait in it's transit queue.
+    const NODE_1_LISTEN_PERIOD: ms = 900;
+    const NODE_2_LISTEN_PERIOD: ms = 1300;
+    const NODE_3_LISTEN_PERIOD: ms = 1100;
+
+    const LIFETIME: LifeTimeType = 2;
+    const MESSAGES_COUNT: usize = 3;
+    const SIMULATION_TIMEOUT: ms = NODE_2_LISTEN_PERIOD * (MESSAGES_COUNT as ms + 1);
+
+    /// Period of time, during which the duplicate filter remembers packets.
+    const DUPLICATE_IGNORE_PERIOD: ms = 1000;
+
+    /// Node 1, node 2 and node 3 are connected in chain. Node 1 sends messages
+    /// to node 3, and hears them back, once node 2 forwards them. The last
+    /// message comes back later, than the duplicate filter remembers it.
+    #[test]
+    fn test_own_packet_coming_back_late_is_not_a_conflict() {
+        let mut simulator = Simulator::new(1);
+
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(1).unwrap(), NODE_1_LISTEN_PERIOD);
+        config.sniffer = Some(SnifferConfig {
+            forward_transit: true,
+        });
+        let node_1 = simulator.add_node(config);
+        let node_2 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+        let node_3 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(3).unwrap(),
+            NODE_3_LISTEN_PERIOD,
+        ));
+        simulator.link(node_1, node_2, LinkConfig::default());
+        simulator.link(node_2, node_3, LinkConfig::default());
+
+        for _ in 0..MESSAGES_COUNT {
+            let _ = simulator.node(node_1).send_to_exact(
+                NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+                ExactAddressType::try_from(3).unwrap(),
+                LIFETIME,
+                false,
+            );
+        }
+
+        let mut last_echo_heard_at: ms = 0;
+        let mut received_count = 0;
+        simulator.run_until(SIMULATION_TIMEOUT, |simulator| {
+            while let Some(sniffed) = simulator.node(node_1).sniff() {
+                if sniffed.packet.source_device_identifier == 1 {
+                    last_echo_heard_at = sniffed.heard_at;
+                }
+            }
+            while simulator.node(node_3).receive().is_some() {
+                received_count += 1;
+            }
+            false
+        });
+
+        assert_eq!(received_count, MESSAGES_COUNT);
+
+        // The last message is sent on the last speak of node 1.
+        let last_message_sent_at = NODE_1_LISTEN_PERIOD * MESSAGES_COUNT as ms;
+        assert!(last_echo_heard_at > last_message_sent_at + DUPLICATE_IGNORE_PERIOD);
+
+        assert_eq!(simulator.node(node_1).stats().address_conflicts, 0);
+        assert!(!simulator.node(node_1).take_address_conflict());
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(all(test, feature = "sim", feature = "addressing"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, AddressAcquisitionConfig, AddressAcquisitionMode, ExactAddressType, LinkConfig,
+        NodeConfig, Simulator,
+    };
+
+    const OWNER_LISTEN_PERIOD: ms = 10;
+    const NEWCOMER_LISTEN_PERIOD: ms = 20;
+
+    const HARDWARE_ID: u32 = 0xC0FFEE;
+    const ACQUISITION_TIMEOUT: ms = 2000;
+
+    fn newcomer_config() -> NodeConfig {
+        let mut config = NodeConfig::new(ExactAddressType::MAX, NEWCOMER_LISTEN_PERIOD);
+        config.address_acquisition = Some(AddressAcquisitionConfig {
+            hardware_id: HARDWARE_ID,
+            mode: AddressAcquisitionMode::SelfAssigned,
+            answer_timeout: 200 as ms,
+            probes_count: 2,
+            lifetime: 1,
+        });
+        config
+    }
+
+    /// Address, which the newcomer claims, while nobody answers it's probes.
+    fn first_candidate_address() -> ExactAddressType {
+        let mut simulator = Simulator::new(1);
+        let newcomer = simulator.add_node(newcomer_config());
+        assert!(simulator.run_until(ACQUISITION_TIMEOUT, |simulator| {
+            simulator.node(newcomer).address().is_some()
+        }));
+        simulator.node(newcomer).address().unwrap()
+    }
+
+    #[test]
+    fn test_probe_of_used_address_is_not_a_conflict_for_owner() {
+        let used_address = first_candidate_address();
+
+        let mut simulator = Simulator::new(1);
+        let owner = simulator.add_node(NodeConfig::new(used_address, OWNER_LISTEN_PERIOD));
+        let newcomer = simulator.add_node(newcomer_config());
+        simulator.link(owner, newcomer, LinkConfig::default());
+
+        assert!(simulator.run_until(ACQUISITION_TIMEOUT, |simulator| {
+            simulator.node(newcomer).address().is_some()
+        }));
+
+        assert_ne!(simulator.node(newcomer).address(), Some(used_address));
+        assert_eq!(simulator.node(owner).stats().address_conflicts, 0);
+        assert!(!simulator.node(owner).take_address_conflict());
+    }
+}
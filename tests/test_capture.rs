@@ -0,0 +1,105 @@
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, CaptureInterface, CapturedFrame, Direction, ExactAddressType, Node, NodeConfig,
+        NodeString, PcapReader, PcapWriter,
+    };
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+    use std::vec::Vec;
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+
+    const SIMULATION_TIMEOUT: ms = 300;
+
+    #[test]
+    fn test_sent_and_received_frames_are_captured() {
+        let mut network_simulator = NetworkSimulator::new(0);
+
+        let modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+
+        network_simulator.create_ether("1");
+        {
+            let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(modem_2.clone());
+        }
+
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+
+        let _ = node_1.send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(2).unwrap(),
+            1,
+            true,
+        );
+        let _ = node_2.send_to_exact(
+            NodeString::from_iter("This is the message from node 2".chars()).into_bytes(),
+            ExactAddressType::try_from(1).unwrap(),
+            1,
+            true,
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+        let clock = move || Instant::now().duration_since(start_time).as_millis() as ms;
+
+        let mut capture_1 = CaptureInterface::new(modem_1, 0, clock);
+        let mut pcap_writer =
+            PcapWriter::new(Vec::new(), &["modem_1"]).expect("Can not write capture");
+
+        loop {
+            let current_time = clock();
+
+            let _ = node_1.update(&mut capture_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+
+            while let Some(frame) = capture_1.take_frame() {
+                pcap_writer
+                    .write_frame(&frame)
+                    .expect("Can not write frame");
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        let file = pcap_writer.into_inner();
+        let frames: Vec<CapturedFrame> = PcapReader::new(&file[..])
+            .expect("Can not read capture")
+            .collect::<Result<_, _>>()
+            .expect("Can not read frame");
+
+        assert_eq!(frames.len(), 2);
+
+        let sent = frames
+            .iter()
+            .find(|frame| frame.direction == Direction::Sent)
+            .expect("Sent frame shall be captured");
+        assert_eq!(sent.packet.source_device_identifier, 1);
+        assert_eq!(sent.packet.get_destination_device_identifier(), 2);
+
+        let received = frames
+            .iter()
+            .find(|frame| frame.direction == Direction::Received)
+            .expect("Received frame shall be captured");
+        assert_eq!(received.packet.source_device_identifier, 2);
+        assert!(received.packet.is_checksum_correct());
+        assert!(std::format!("{}", received).ends_with("\"This is the message from node 2\""));
+    }
+}
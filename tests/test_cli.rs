@@ -0,0 +1,161 @@
+#[cfg(all(test, feature = "cli", unix))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{ms, ExactAddressType, Node, NodeConfig, NodeString, SerialInterface};
+    use serialport::{SerialPort, TTYPort};
+    use std::boxed::Box;
+    use std::process::{Child, Command, Output, Stdio};
+    use std::string::String;
+    use std::time::Instant;
+    use std::vec::Vec;
+
+    const DEVICE_LISTEN_PERIOD: ms = 20;
+    const SETTLE_PERIOD: ms = 200;
+
+    /// Device, which is connected to the tool trough the pseudo terminal.
+    struct Device {
+        node: Node,
+        serial: SerialInterface,
+        started_at: Instant,
+        // Is kept open, so bytes written by the tool
+        // are still readable, once the tool exits.
+        _terminal: TTYPort,
+    }
+
+    impl Device {
+        fn now(&self) -> ms {
+            self.started_at.elapsed().as_millis() as ms
+        }
+
+        /// Updates the device till the tool exits, and for a while after that,
+        /// so the device reads bytes written by the tool right before the exit.
+        fn serve(&mut self, mut tool: Child) -> (Output, Vec<String>) {
+            let mut received = Vec::new();
+            let mut exited_at = None;
+            loop {
+                let current_time = self.now();
+                match exited_at {
+                    None if tool.try_wait().unwrap().is_some() => exited_at = Some(current_time),
+                    Some(exited_at) if current_time - exited_at > SETTLE_PERIOD => break,
+                    _ => (),
+                }
+                let _ = self.node.update(&mut self.serial, current_time);
+                while let Some(packet) = self.node.receive() {
+                    let length = packet.get_data_length();
+                    received.push(String::from_utf8_lossy(&packet.data[..length]).into_owned());
+                }
+            }
+            (tool.wait_with_output().unwrap(), received)
+        }
+    }
+
+    /// Connects the device with address 2 to the pseudo terminal,
+    /// and returns path of the terminal for the tool.
+    fn connect_device() -> (Device, String) {
+        let (master, slave) = TTYPort::pair().unwrap();
+        let path = slave.name().unwrap();
+        let device = Device {
+            node: Node::new(NodeConfig::new(
+                ExactAddressType::new(2).unwrap(),
+                DEVICE_LISTEN_PERIOD,
+            )),
+            serial: SerialInterface::from_port(Box::new(master)),
+            started_at: Instant::now(),
+            _terminal: slave,
+        };
+        (device, path)
+    }
+
+    fn run_tool(path: &str, arguments: &[&str]) -> Child {
+        Command::new(env!("CARGO_BIN_EXE_nano-mesh"))
+            .args([
+                "--port",
+                path,
+                "--address",
+                "1",
+                "--listen-period",
+                "30",
+                "--json",
+            ])
+            .args(arguments)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap()
+    }
+
+    fn stdout_of(output: &Output) -> String {
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    }
+
+    #[test]
+    fn test_cli_listen() {
+        let (mut device, path) = connect_device();
+        let _ = device.node.send_to_exact(
+            NodeString::from_iter("Hello from device".chars()).into_bytes(),
+            ExactAddressType::new(1).unwrap(),
+            1,
+            true,
+        );
+
+        let tool = run_tool(&path, &["listen", "--duration", "1000"]);
+        let (output, _) = device.serve(tool);
+
+        assert!(output.status.success(), "{:?}", output);
+        let stdout = stdout_of(&output);
+        assert!(
+            stdout.contains(r#""data":"Hello from device""#),
+            "{}",
+            stdout
+        );
+        assert!(stdout.contains(r#""event":"received""#), "{}", stdout);
+        assert!(stdout.contains(r#""from":2"#), "{}", stdout);
+    }
+
+    #[test]
+    fn test_cli_send() {
+        let (mut device, path) = connect_device();
+
+        let tool = run_tool(&path, &["send", "--to", "2", "Hello from tool"]);
+        let (output, received) = device.serve(tool);
+
+        assert!(output.status.success(), "{:?}", output);
+        assert!(stdout_of(&output).contains(r#""sent":true"#));
+        assert_eq!(received, ["Hello from tool"]);
+    }
+
+    #[test]
+    fn test_cli_ping() {
+        let (mut device, path) = connect_device();
+
+        let tool = run_tool(&path, &["ping", "--to", "2", "--timeout", "2000"]);
+        let (output, received) = device.serve(tool);
+
+        assert!(output.status.success(), "{:?}", output);
+        assert!(stdout_of(&output).contains(r#""answered":true"#));
+        assert_eq!(received, ["ping"]);
+    }
+
+    #[test]
+    fn test_cli_ping_timeout() {
+        let (_device, path) = connect_device();
+
+        let output = run_tool(&path, &["ping", "--to", "3", "--timeout", "300"])
+            .wait_with_output()
+            .unwrap();
+
+        assert!(!output.status.success());
+        assert!(stdout_of(&output).contains(r#""answered":false"#));
+    }
+
+    #[test]
+    fn test_cli_rejects_too_long_message() {
+        let output = run_tool("/dev/null", &["broadcast", &"x".repeat(33)])
+            .wait_with_output()
+            .unwrap();
+
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("keeps up to 32 bytes"));
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(all(test, feature = "diagnostics", feature = "link-ack"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, DiagnosticsReport, ExactAddressType, LifeTimeType, LinkAckConfig, Node, NodeConfig,
+        PROTOCOL_VERSION,
+    };
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+    const NODE_3_LISTEN_PERIOD: ms = 30;
+
+    const FIRMWARE_VERSION: u16 = 0x0203;
+
+    const SIMULATION_TIMEOUT: ms = 1000;
+
+    fn make_node(address: u8, listen_period: ms, link_ack: Option<LinkAckConfig>) -> Node {
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(address).unwrap(), listen_period);
+        config.firmware_version = FIRMWARE_VERSION;
+        config.link_ack = link_ack;
+        Node::new(config)
+    }
+
+    /// Updates all devices till the gateway device gets the report,
+    /// and checks, that no packets get into the queues of received packets.
+    fn run(
+        network_simulator: &mut NetworkSimulator,
+        modems: &mut [WirelessModemFake],
+        nodes: &mut [Node],
+    ) -> Option<DiagnosticsReport> {
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut report = None;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            for (node, modem) in nodes.iter_mut().zip(modems.iter_mut()) {
+                let _ = node.update(modem, current_time);
+                assert!(node.receive().is_none());
+            }
+
+            if let Some(received) = nodes[0].receive_diagnostics() {
+                report = Some(received);
+                break;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        report
+    }
+
+    #[test]
+    fn test_diagnostics_report_travels_2_hops() {
+        let mut network_simulator = NetworkSimulator::new(0);
+
+        let mut modems = [
+            WirelessModemFake::new("1"),
+            WirelessModemFake::new("2"),
+            WirelessModemFake::new("3"),
+        ];
+
+        // Devices 1 and 3 hear each other only trough device 2.
+        {
+            network_simulator.create_ether("1");
+            let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+            ether.register_driver(modems[0].clone());
+            ether.register_driver(modems[1].clone());
+        }
+        {
+            network_simulator.create_ether("2");
+            let mut ether = network_simulator.get_ether("2").expect("Can not get ether");
+            ether.register_driver(modems[1].clone());
+            ether.register_driver(modems[2].clone());
+        }
+
+        let mut nodes = [
+            make_node(1, NODE_1_LISTEN_PERIOD, None),
+            make_node(2, NODE_2_LISTEN_PERIOD, None),
+            make_node(3, NODE_3_LISTEN_PERIOD, None),
+        ];
+
+        nodes[0]
+            .send_diagnostics_request(
+                ExactAddressType::try_from(3).unwrap(),
+                // The report is sent back with the lifetime, left from the request.
+                LifeTimeType::try_from(3).unwrap(),
+            )
+            .expect("Request shall be sent");
+
+        let report =
+            run(&mut network_simulator, &mut modems, &mut nodes).expect("Report shall be received");
+
+        assert_eq!(report.source.get(), 3);
+        assert_eq!(report.protocol_version, PROTOCOL_VERSION);
+        assert_eq!(report.firmware_version, FIRMWARE_VERSION);
+        assert!(report.uptime <= SIMULATION_TIMEOUT as u32 / 1000);
+        assert_eq!(report.dropped_received_packets, 0);
+        assert_eq!(report.dropped_transit_packets, 0);
+        assert_eq!(report.neighbours, 0);
+    }
+
+    #[test]
+    fn test_neighbour_is_counted_by_link_acknowledgement() {
+        let mut network_simulator = NetworkSimulator::new(1);
+        network_simulator.create_ether("1");
+        let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+
+        let mut modems = [WirelessModemFake::new("1"), WirelessModemFake::new("2")];
+
+        ether.register_driver(modems[0].clone());
+        ether.register_driver(modems[1].clone());
+
+        let link_ack = || {
+            Some(LinkAckConfig {
+                ack_timeout: 150 as ms,
+                retries: 2,
+            })
+        };
+
+        let mut nodes = [
+            make_node(1, NODE_1_LISTEN_PERIOD, link_ack()),
+            make_node(2, NODE_2_LISTEN_PERIOD, link_ack()),
+        ];
+
+        assert_eq!(nodes[0].stats().neighbours, 0);
+
+        nodes[0]
+            .send_diagnostics_request(
+                ExactAddressType::try_from(2).unwrap(),
+                LifeTimeType::try_from(1).unwrap(),
+            )
+            .expect("Request shall be sent");
+
+        let report =
+            run(&mut network_simulator, &mut modems, &mut nodes).expect("Report shall be received");
+
+        assert_eq!(report.source.get(), 2);
+        // Asked device confirms the request, so it's neighbour hears it.
+        assert_eq!(nodes[0].stats().neighbours, 1);
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(all(test, feature = "sim", feature = "pubsub", feature = "diagnostics"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, ExactAddressType, LifeTimeType, LinkConfig, NodeConfig, NodeString, Simulator, TopicId,
+        TopicPayload,
+    };
+
+    // Broker speaks rarely, so it's send queue stays full for a while.
+    const BROKER_LISTEN_PERIOD: ms = 1000;
+    const NODE_2_LISTEN_PERIOD: ms = 10;
+
+    const TEMPERATURE: TopicId = 1;
+
+    #[test]
+    fn test_retained_answer_is_counted_once_dropped() {
+        let mut simulator = Simulator::new(1);
+
+        let broker = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            BROKER_LISTEN_PERIOD,
+        ));
+        let node_2 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+        simulator.link(broker, node_2, LinkConfig::default());
+
+        simulator
+            .node(broker)
+            .retain_topic(TEMPERATURE)
+            .expect("Topic shall be retained");
+        let _ = simulator.node(broker).publish(
+            TEMPERATURE,
+            TopicPayload::from_slice(b"21.5").unwrap(),
+            LifeTimeType::try_from(1).unwrap(),
+        );
+        simulator.run_for(1);
+
+        // Messages for the device, which does not exist, fill the send queue of the broker.
+        while simulator
+            .node(broker)
+            .send_to_exact(
+                NodeString::from_iter("Message for nobody".chars()).into_bytes(),
+                ExactAddressType::try_from(3).unwrap(),
+                1,
+                false,
+            )
+            .is_ok()
+        {}
+        assert_eq!(simulator.node(broker).stats().dropped_transit_packets, 0);
+
+        let _ = simulator
+            .node(node_2)
+            .request_retained(TEMPERATURE, LifeTimeType::try_from(1).unwrap());
+        simulator.run_for(BROKER_LISTEN_PERIOD / 2);
+
+        assert_eq!(simulator.node(broker).stats().dropped_transit_packets, 1);
+    }
+}
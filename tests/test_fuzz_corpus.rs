@@ -0,0 +1,122 @@
+#[cfg(all(test, feature = "fuzzing"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{fuzzing, RandomNumberGenerator, XorShiftRng};
+    use std::panic::AssertUnwindSafe;
+    use std::path::{Path, PathBuf};
+    use std::vec::Vec;
+
+    const RANDOM_STREAMS_COUNT: u32 = 200;
+    const RANDOM_STREAM_SIZE: usize = 300;
+
+    /// Returns contents of every file of the corpus of fuzz `target`.
+    fn corpus(target: &str) -> Vec<(PathBuf, Vec<u8>)> {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fuzz/corpus")
+            .join(target);
+        let mut files: Vec<(PathBuf, Vec<u8>)> = std::fs::read_dir(directory)
+            .expect("Can not read corpus")
+            .map(|entry| {
+                let path = entry.expect("Can not read corpus entry").path();
+                let bytes = std::fs::read(&path).expect("Can not read corpus file");
+                (path, bytes)
+            })
+            .collect();
+        files.sort();
+        assert!(!files.is_empty());
+        files
+    }
+
+    fn corpus_file(target: &str, name: &str) -> Vec<u8> {
+        corpus(target)
+            .into_iter()
+            .find(|(path, _)| path.ends_with(name))
+            .map(|(_, bytes)| bytes)
+            .expect("Can not find corpus file")
+    }
+
+    /// Makes stream of random bytes, which are often start bytes,
+    /// and often include valid frames with flipped bits.
+    fn random_stream(seed: u32) -> Vec<u8> {
+        let mut rng = XorShiftRng::new(seed);
+        let valid_frame = corpus_file("frame_parser", "valid_frame");
+        let mut stream = Vec::new();
+        while stream.len() < RANDOM_STREAM_SIZE {
+            match rng.next_u32() % 4 {
+                0 => stream.push(b'x'),
+                1 => {
+                    let mut frame = valid_frame.clone();
+                    let position = rng.next_u32() as usize % frame.len();
+                    frame[position] ^= 1 << (rng.next_u32() % 8);
+                    stream.extend_from_slice(&frame);
+                }
+                2 => stream.extend_from_slice(&valid_frame),
+                _ => stream.push(rng.next_u32() as u8),
+            }
+        }
+        stream
+    }
+
+    /// Runs `target` on every file of it's corpus, so the panic
+    /// is reported together with the file, which caused it.
+    fn run_corpus<F: Fn(&[u8])>(name: &str, target: F) {
+        for (path, bytes) in corpus(name) {
+            if let Err(panic) = std::panic::catch_unwind(AssertUnwindSafe(|| target(&bytes))) {
+                std::eprintln!("Corpus file {} failed", path.display());
+                std::panic::resume_unwind(panic);
+            }
+        }
+    }
+
+    #[test]
+    fn test_frame_parser_corpus() {
+        run_corpus("frame_parser", |bytes| {
+            fuzzing::parse_frames(bytes);
+        });
+        assert_eq!(
+            fuzzing::parse_frames(&corpus_file("frame_parser", "valid_frame")),
+            1
+        );
+        assert_eq!(
+            fuzzing::parse_frames(&corpus_file("frame_parser", "corrupted_checksum")),
+            0
+        );
+        assert_eq!(
+            fuzzing::parse_frames(&corpus_file("frame_parser", "frame_ending_with_start_byte")),
+            2
+        );
+    }
+
+    #[test]
+    fn test_packet_deserializer_corpus() {
+        run_corpus("packet_deserializer", fuzzing::deserialize_packet);
+    }
+
+    #[test]
+    fn test_node_update_corpus() {
+        // Memory of the node is checked to stay bounded after each update.
+        run_corpus("node_update", |bytes| {
+            fuzzing::update_node(bytes);
+        });
+
+        // File holds 3 valid frames of different packets, and
+        // every one of them is heard by the node, none is lost.
+        let normal = corpus_file("node_update", "normal");
+        assert_eq!(fuzzing::parse_frames(&normal), 3);
+        assert_eq!(
+            fuzzing::update_node(&normal),
+            fuzzing::parse_frames(&normal)
+        );
+    }
+
+    #[test]
+    fn test_random_streams() {
+        for seed in 0..RANDOM_STREAMS_COUNT {
+            let stream = random_stream(seed);
+            fuzzing::parse_frames(&stream);
+            fuzzing::deserialize_packet(&stream);
+            fuzzing::update_node(&stream);
+        }
+    }
+}
@@ -0,0 +1,203 @@
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, ExactAddressType, Node, NodeConfig, NodeString, TcpInterface, UdpInterface,
+    };
+    use std::io;
+    use std::net::{Ipv4Addr, SocketAddrV4, TcpListener, UdpSocket};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+
+    const SIMULATION_TIMEOUT: ms = 500;
+
+    const CONNECTION_CLOSE_TIMEOUT: Duration = Duration::from_millis(500);
+
+    /// More bytes, than the socket and the interface keep together.
+    const FILLING_CHUNKS_COUNT: u32 = 16 * 1024;
+    const FILLING_CHUNK_SIZE: usize = 1024;
+
+    /// Period, after which bytes are not expected to come anymore.
+    const DRAIN_IDLE_TIMEOUT: Duration = Duration::from_millis(200);
+
+    fn exchange_messages<I1, I2>(interface_1: &mut I1, interface_2: &mut I2)
+    where
+        I1: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
+        I2: embedded_io::ReadReady + embedded_io::Read + embedded_io::Write,
+    {
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+
+        let _ = node_1.send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(2).unwrap(),
+            1,
+            true,
+        );
+        let _ = node_2.send_to_exact(
+            NodeString::from_iter("This is the message from node 2".chars()).into_bytes(),
+            ExactAddressType::try_from(1).unwrap(),
+            1,
+            true,
+        );
+
+        let start_time = Instant::now();
+
+        let mut node_1_messages = 0;
+        let mut node_2_message = None;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(interface_1, current_time);
+            let _ = node_2.update(interface_2, current_time);
+
+            if let Some(message) = node_1.receive() {
+                let got = NodeString::from_iter(message.data.iter().map(|c| *c as char));
+                assert!(got.starts_with("This is the message from node 2"));
+                node_1_messages += 1;
+            }
+
+            if let Some(message) = node_2.receive() {
+                node_2_message.replace(message);
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        assert_eq!(node_1_messages, 1);
+
+        let node_2_message = node_2_message.expect("Node 2 shall receive the message");
+        let got = NodeString::from_iter(node_2_message.data.iter().map(|c| *c as char));
+        assert!(got.starts_with("This is the message from node 1"));
+    }
+
+    #[test]
+    fn test_nodes_exchange_messages_over_udp_multicast() {
+        // Port, which is free at the moment, so parallel runs do not hear each other.
+        let port = UdpSocket::bind(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0))
+            .and_then(|socket| socket.local_addr())
+            .expect("Can not get free port")
+            .port();
+        let group = SocketAddrV4::new(Ipv4Addr::new(239, 255, 77, 77), port);
+
+        let mut interface_1 = UdpInterface::new(group).expect("Can not join multicast group");
+        let mut interface_2 = UdpInterface::new(group).expect("Can not join multicast group");
+
+        exchange_messages(&mut interface_1, &mut interface_2);
+    }
+
+    fn connect_tcp_interfaces() -> (TcpInterface, TcpInterface) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Can not bind listener");
+        let address = listener.local_addr().expect("Can not get listener address");
+
+        let connecting = thread::spawn(move || TcpInterface::connect(address));
+        let interface_1 = TcpInterface::accept(&listener).expect("Can not accept connection");
+        let interface_2 = connecting
+            .join()
+            .unwrap()
+            .expect("Can not connect to listener");
+        (interface_1, interface_2)
+    }
+
+    #[test]
+    fn test_nodes_exchange_messages_over_tcp() {
+        let (mut interface_1, mut interface_2) = connect_tcp_interfaces();
+
+        exchange_messages(&mut interface_1, &mut interface_2);
+    }
+
+    #[test]
+    fn test_tcp_interface_reports_closed_connection() {
+        use embedded_io::{Read, ReadReady, Write};
+
+        let (mut interface_1, mut interface_2) = connect_tcp_interfaces();
+
+        interface_2.write_all(b"bye").unwrap();
+        drop(interface_2);
+
+        let start_time = Instant::now();
+        let mut received = std::vec::Vec::new();
+        let error = loop {
+            assert!(
+                start_time.elapsed() < CONNECTION_CLOSE_TIMEOUT,
+                "Closed connection shall be reported"
+            );
+            let mut buf = [0u8; 8];
+            match interface_1.read_ready() {
+                Ok(true) => {
+                    let count = interface_1.read(&mut buf).unwrap();
+                    received.extend_from_slice(&buf[..count]);
+                }
+                Ok(false) => thread::sleep(Duration::from_millis(1)),
+                Err(error) => break error,
+            }
+        };
+
+        assert_eq!(received, b"bye");
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    /// The other side does not read, so the send buffer of the socket is full.
+    /// Writes shall neither fail nor cut chunks, and kept bytes shall be sent
+    /// once the other side reads them.
+    #[test]
+    fn test_tcp_interface_keeps_whole_chunks_while_socket_is_full() {
+        use embedded_io::{Read, ReadReady, Write};
+
+        let (mut interface_1, mut interface_2) = connect_tcp_interfaces();
+
+        for index in 0..FILLING_CHUNKS_COUNT {
+            let chunk: std::vec::Vec<u8> = index
+                .to_le_bytes()
+                .iter()
+                .copied()
+                .cycle()
+                .take(FILLING_CHUNK_SIZE)
+                .collect();
+            interface_1.write_all(&chunk).unwrap();
+        }
+
+        let mut received = std::vec::Vec::new();
+        let mut last_received_at = Instant::now();
+        while last_received_at.elapsed() < DRAIN_IDLE_TIMEOUT {
+            // Kept bytes are sent on the update of the writing side.
+            interface_1.read_ready().unwrap();
+            let mut buf = [0u8; FILLING_CHUNK_SIZE];
+            if interface_2.read_ready().unwrap() {
+                let count = interface_2.read(&mut buf).unwrap();
+                received.extend_from_slice(&buf[..count]);
+                last_received_at = Instant::now();
+            }
+        }
+
+        assert!(!received.is_empty());
+        assert!(received.len() < FILLING_CHUNKS_COUNT as usize * FILLING_CHUNK_SIZE);
+        assert_eq!(received.len() % FILLING_CHUNK_SIZE, 0);
+
+        let indexes: std::vec::Vec<u32> = received
+            .chunks(FILLING_CHUNK_SIZE)
+            .map(|chunk| {
+                let index = u32::from_le_bytes(chunk[..4].try_into().unwrap());
+                assert!(chunk
+                    .chunks(4)
+                    .all(|bytes| bytes == index.to_le_bytes().as_slice()));
+                index
+            })
+            .collect();
+        assert_eq!(indexes[0], 0);
+        assert!(indexes.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
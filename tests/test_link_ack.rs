@@ -0,0 +1,222 @@
+#[cfg(all(test, feature = "link-ack", feature = "sniffer"))]
+mod tests {
+    extern crate std;
+
+    use embedded_io::{Read, ReadReady};
+    use embedded_nano_mesh::{
+        ms, ExactAddressType, LifeTimeType, LinkAckConfig, Node, NodeConfig, NodeString,
+    };
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+
+    /// Start sequence and the packet itself.
+    const FRAME_SIZE: usize = 3 + 40;
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+    const NODE_3_LISTEN_PERIOD: ms = 30;
+
+    const SIMULATION_TIMEOUT: ms = 600;
+
+    fn link_ack_config() -> LinkAckConfig {
+        LinkAckConfig {
+            ack_timeout: 150 as ms,
+            retries: 2,
+        }
+    }
+
+    fn sniff(sniffer: &mut WirelessModemFake) -> usize {
+        let mut heard_bytes = 0;
+        let mut buf = [0u8; 1];
+        while let Ok(true) = sniffer.read_ready() {
+            heard_bytes += sniffer.read(&mut buf).unwrap_or(0);
+        }
+        heard_bytes
+    }
+
+    #[test]
+    fn test_link_ack_retransmits_unconfirmed_packet() {
+        let mut network_simulator = NetworkSimulator::new(1);
+        network_simulator.create_ether("1");
+        let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut sniffer = WirelessModemFake::new("sniffer");
+
+        ether.register_driver(modem_1.clone());
+        ether.register_driver(sniffer.clone());
+
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(1).unwrap(), NODE_1_LISTEN_PERIOD);
+        config.link_ack = Some(link_ack_config());
+        let mut node_1 = Node::new(config);
+
+        let _ = node_1.send_to_exact(
+            NodeString::from_iter("Nobody confirms this message".chars()).into_bytes(),
+            ExactAddressType::try_from(2).unwrap(),
+            LifeTimeType::try_from(1).unwrap(),
+            true,
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut heard_bytes = 0;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            heard_bytes += sniff(&mut sniffer);
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        // Packet itself and two retransmissions.
+        assert_eq!(heard_bytes, 3 * FRAME_SIZE);
+    }
+
+    #[test]
+    fn test_link_ack_confirmed_packet_is_not_retransmitted() {
+        let mut network_simulator = NetworkSimulator::new(1);
+        network_simulator.create_ether("1");
+        let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+        let mut sniffer = WirelessModemFake::new("sniffer");
+
+        ether.register_driver(modem_1.clone());
+        ether.register_driver(modem_2.clone());
+        ether.register_driver(sniffer.clone());
+
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(1).unwrap(), NODE_1_LISTEN_PERIOD);
+        config.link_ack = Some(link_ack_config());
+        let mut node_1 = Node::new(config);
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(2).unwrap(), NODE_2_LISTEN_PERIOD);
+        config.link_ack = Some(link_ack_config());
+        let mut node_2 = Node::new(config);
+
+        let _ = node_1.send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(2).unwrap(),
+            LifeTimeType::try_from(1).unwrap(),
+            true,
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut heard_bytes = 0;
+        let mut received_count = 0;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+            heard_bytes += sniff(&mut sniffer);
+
+            if node_2.receive().is_some() {
+                received_count += 1;
+            }
+
+            // Link acknowledgement shall not get into the queue of received packets.
+            assert!(node_1.receive().is_none());
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        assert_eq!(received_count, 1);
+        // Packet itself and link acknowledgement.
+        assert_eq!(heard_bytes, 2 * FRAME_SIZE);
+    }
+
+    #[test]
+    fn test_link_ack_last_hop_is_confirmed_by_end_receiver() {
+        let mut network_simulator = NetworkSimulator::new(1);
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+        let mut modem_3 = WirelessModemFake::new("3");
+        let mut sniffer = WirelessModemFake::new("sniffer");
+
+        // Devices 1 and 3 hear each other only trough device 2.
+        // Sniffer hears the last hop only.
+        {
+            network_simulator.create_ether("1");
+            let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(modem_2.clone());
+        }
+        {
+            network_simulator.create_ether("2");
+            let mut ether = network_simulator.get_ether("2").expect("Can not get ether");
+            ether.register_driver(modem_2.clone());
+            ether.register_driver(modem_3.clone());
+            ether.register_driver(sniffer.clone());
+        }
+
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(1).unwrap(), NODE_1_LISTEN_PERIOD);
+        config.link_ack = Some(link_ack_config());
+        let mut node_1 = Node::new(config);
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(2).unwrap(), NODE_2_LISTEN_PERIOD);
+        config.link_ack = Some(link_ack_config());
+        let mut node_2 = Node::new(config);
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(3).unwrap(), NODE_3_LISTEN_PERIOD);
+        config.link_ack = Some(link_ack_config());
+        let mut node_3 = Node::new(config);
+
+        let _ = node_1.send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(3).unwrap(),
+            LifeTimeType::try_from(2).unwrap(),
+            true,
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut heard_bytes = 0;
+        let mut received_count = 0;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+            let _ = node_3.update(&mut modem_3, current_time);
+            heard_bytes += sniff(&mut sniffer);
+
+            if node_3.receive().is_some() {
+                received_count += 1;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        assert_eq!(received_count, 1);
+        // Forwarding of the packet by device 2, and link acknowledgement of device 3.
+        assert_eq!(heard_bytes, 2 * FRAME_SIZE);
+    }
+}
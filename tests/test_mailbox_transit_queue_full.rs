@@ -0,0 +1,87 @@
+#[cfg(all(test, feature = "sim", feature = "mailbox"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{ms, ExactAddressType, LinkConfig, NodeConfig, NodeString, Simulator};
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    // Parent device speaks rarely, so it's transit queue stays full for a while.
+    const NODE_2_LISTEN_PERIOD: ms = 1000;
+    const NODE_3_LISTEN_PERIOD: ms = 30;
+
+    // Size of the transit queue of the device.
+    const TRANSIT_QUEUE_SIZE: usize = 5;
+
+    const SIMULATION_TIMEOUT: ms = 20_000;
+
+    #[test]
+    fn test_mailbox_is_kept_while_transit_queue_is_full() {
+        let mut simulator = Simulator::new(1);
+
+        let node_1 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let node_2 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+        let node_3 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(3).unwrap(),
+            NODE_3_LISTEN_PERIOD,
+        ));
+
+        simulator.link(node_1, node_2, LinkConfig::default());
+        simulator.link(node_2, node_3, LinkConfig::default());
+
+        simulator
+            .node(node_2)
+            .register_mailbox(ExactAddressType::try_from(3).unwrap())
+            .expect("Mailbox shall be registered");
+
+        for message in ["First message for node 3", "Second message for node 3"] {
+            let _ = simulator.node(node_1).send_to_exact(
+                NodeString::from_iter(message.chars()).into_bytes(),
+                ExactAddressType::try_from(3).unwrap(),
+                2,
+                false,
+            );
+        }
+        simulator.run_for(100);
+
+        // Packets for the device, which does not exist, fill the transit queue of node 2.
+        for _ in 0..TRANSIT_QUEUE_SIZE {
+            let _ = simulator.node(node_1).send_to_exact(
+                NodeString::from_iter("Message for nobody".chars()).into_bytes(),
+                ExactAddressType::try_from(4).unwrap(),
+                2,
+                false,
+            );
+        }
+        simulator.run_for(100);
+
+        // Node 2 hears the poll, while it's transit queue is full.
+        let _ = simulator
+            .node(node_3)
+            .poll_mailbox(ExactAddressType::try_from(2).unwrap(), 1);
+        simulator.run_for(100);
+        assert!(simulator.node(node_3).receive().is_none());
+
+        // Once the transit queue is sent, the next poll releases the mailbox.
+        simulator.run_for(NODE_2_LISTEN_PERIOD * TRANSIT_QUEUE_SIZE as ms);
+        let _ = simulator
+            .node(node_3)
+            .poll_mailbox(ExactAddressType::try_from(2).unwrap(), 1);
+
+        let mut received_count = 0;
+        simulator.run_until(SIMULATION_TIMEOUT, |simulator| {
+            if let Some(message) = simulator.node(node_3).receive() {
+                let got = NodeString::from_iter(message.data.iter().map(|c| *c as char));
+                assert!(got.contains("message for node 3"));
+                received_count += 1;
+            }
+            received_count == 2
+        });
+        assert_eq!(received_count, 2);
+    }
+}
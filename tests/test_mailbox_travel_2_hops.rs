@@ -0,0 +1,103 @@
+#[cfg(all(test, feature = "mailbox"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{ms, ExactAddressType, LifeTimeType, Node, NodeConfig, NodeString};
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+
+    const NODE_1_UPDATE_PERIOD: ms = 10;
+    const NODE_2_UPDATE_PERIOD: ms = 20;
+    const NODE_3_UPDATE_PERIOD: ms = 30;
+
+    const NODE_3_POLL_TIME: ms = 150;
+
+    const SIMULATION_TIMEOUT: ms = 400;
+
+    #[test]
+    pub fn test_mailbox_travel_2_hops() {
+        let mut network_simulator = NetworkSimulator::new(0);
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+        let mut modem_3 = WirelessModemFake::new("3");
+
+        {
+            network_simulator.create_ether("1");
+            let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(modem_2.clone());
+        }
+        {
+            network_simulator.create_ether("2");
+            let mut ether = network_simulator.get_ether("2").expect("Can not get ether");
+            ether.register_driver(modem_2.clone());
+            ether.register_driver(modem_3.clone());
+        }
+
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_UPDATE_PERIOD,
+        ));
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_UPDATE_PERIOD,
+        ));
+        let mut node_3 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(3).unwrap(),
+            NODE_3_UPDATE_PERIOD,
+        ));
+
+        node_2
+            .register_mailbox(ExactAddressType::try_from(3).unwrap())
+            .expect("Mailbox shall be registered");
+
+        let _ = node_1.send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(3).unwrap(),
+            LifeTimeType::try_from(2).unwrap(),
+            false,
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut is_polled = false;
+        let mut received_at: Option<ms> = None;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+            let _ = node_3.update(&mut modem_3, current_time);
+
+            if !is_polled && current_time >= NODE_3_POLL_TIME {
+                let _ = node_3.poll_mailbox(
+                    ExactAddressType::try_from(2).unwrap(),
+                    LifeTimeType::try_from(1).unwrap(),
+                );
+                is_polled = true;
+            }
+
+            if let Some(message) = node_3.receive() {
+                let expected = NodeString::from_iter("This is the message from node 1".chars());
+                let got = NodeString::from_iter(message.data.iter().map(|c| *c as char));
+
+                assert!(got.starts_with(expected.as_str()));
+                received_at = Some(current_time);
+                break;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        let received_at = received_at.expect("Message shall be released from the mailbox");
+        assert!(received_at > NODE_3_POLL_TIME);
+    }
+}
@@ -0,0 +1,192 @@
+#[cfg(all(test, feature = "bridge"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{ms, ExactAddressType, Node, NodeConfig, NodeString};
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const GATEWAY_LISTEN_PERIOD: ms = 20;
+    const NODE_3_LISTEN_PERIOD: ms = 30;
+
+    const SIMULATION_TIMEOUT: ms = 300;
+
+    #[test]
+    fn test_gateway_bridges_interfaces() {
+        let mut network_simulator = NetworkSimulator::new(0);
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut gateway_lora = WirelessModemFake::new("gateway_lora");
+        let mut gateway_hc12 = WirelessModemFake::new("gateway_hc12");
+        let mut modem_3 = WirelessModemFake::new("3");
+
+        {
+            network_simulator.create_ether("lora");
+            let mut ether = network_simulator
+                .get_ether("lora")
+                .expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(gateway_lora.clone());
+        }
+        {
+            network_simulator.create_ether("hc12");
+            let mut ether = network_simulator
+                .get_ether("hc12")
+                .expect("Can not get ether");
+            ether.register_driver(gateway_hc12.clone());
+            ether.register_driver(modem_3.clone());
+        }
+
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let mut gateway = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            GATEWAY_LISTEN_PERIOD,
+        ));
+        let mut node_3 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(3).unwrap(),
+            NODE_3_LISTEN_PERIOD,
+        ));
+
+        let _ = node_1.send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(3).unwrap(),
+            2,
+            true,
+        );
+        let _ = node_3.send_to_exact(
+            NodeString::from_iter("This is the message from node 3".chars()).into_bytes(),
+            ExactAddressType::try_from(2).unwrap(),
+            1,
+            true,
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut node_3_message = None;
+        let mut gateway_message = None;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = gateway
+                .update_interfaces(&mut [&mut gateway_lora, &mut gateway_hc12], current_time);
+            let _ = node_3.update(&mut modem_3, current_time);
+
+            if let Some(message) = node_3.receive() {
+                node_3_message.replace(message);
+            }
+
+            if let Some(received) = gateway.receive_with_interface() {
+                gateway_message.replace(received);
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        let node_3_message = node_3_message.expect("Node 3 shall receive the message");
+        let got = NodeString::from_iter(node_3_message.data.iter().map(|c| *c as char));
+        assert!(got.starts_with("This is the message from node 1"));
+
+        let (gateway_message, interface) =
+            gateway_message.expect("Gateway shall receive the message");
+        let got = NodeString::from_iter(gateway_message.data.iter().map(|c| *c as char));
+        assert!(got.starts_with("This is the message from node 3"));
+        assert_eq!(interface, 1);
+    }
+
+    #[test]
+    fn test_packet_heard_trough_both_interfaces_is_received_once() {
+        let mut network_simulator = NetworkSimulator::new(0);
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut gateway_lora = WirelessModemFake::new("gateway_lora");
+        let mut gateway_hc12 = WirelessModemFake::new("gateway_hc12");
+
+        // Node 1 is heard trough both interfaces of the gateway.
+        {
+            network_simulator.create_ether("lora");
+            let mut ether = network_simulator
+                .get_ether("lora")
+                .expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(gateway_lora.clone());
+        }
+        {
+            network_simulator.create_ether("hc12");
+            let mut ether = network_simulator
+                .get_ether("hc12")
+                .expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(gateway_hc12.clone());
+        }
+
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let mut gateway = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            GATEWAY_LISTEN_PERIOD,
+        ));
+
+        let _ = node_1.send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(2).unwrap(),
+            1,
+            true,
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut received_count = 0;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = gateway
+                .update_interfaces(&mut [&mut gateway_lora, &mut gateway_hc12], current_time);
+
+            while gateway.receive().is_some() {
+                received_count += 1;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        assert_eq!(received_count, 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Node listens at most 2 interfaces")]
+    fn test_more_interfaces_than_listened_are_rejected() {
+        let mut lora = WirelessModemFake::new("lora");
+        let mut hc12 = WirelessModemFake::new("hc12");
+        let mut nrf = WirelessModemFake::new("nrf");
+
+        let mut gateway = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            GATEWAY_LISTEN_PERIOD,
+        ));
+
+        let _ = gateway.update_interfaces(&mut [&mut lora, &mut hc12, &mut nrf], 0);
+    }
+}
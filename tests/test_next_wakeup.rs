@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{ms, ExactAddressType, LifeTimeType, Node, NodeConfig, NodeString};
+    use proto_lab::WirelessModemFake;
+
+    const LISTEN_PERIOD: ms = 100;
+
+    #[test]
+    fn test_next_wakeup() {
+        let mut modem = WirelessModemFake::new("1");
+
+        let mut node = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            LISTEN_PERIOD,
+        ));
+
+        // Nothing to wait for.
+        assert_eq!(node.next_wakeup(10 as ms), None);
+
+        let _ = node.send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(2).unwrap(),
+            LifeTimeType::try_from(1).unwrap(),
+            false,
+        );
+
+        // Packet is going to be sent once listen period is over.
+        assert_eq!(node.next_wakeup(10 as ms), Some(LISTEN_PERIOD));
+
+        // Listen period is over, it is time to update without sleep.
+        assert_eq!(node.next_wakeup(LISTEN_PERIOD + 5), Some(LISTEN_PERIOD + 5));
+
+        let _ = node.update(&mut modem, LISTEN_PERIOD + 5);
+
+        // Packet is sent, nothing to wait for again.
+        assert_eq!(node.next_wakeup(LISTEN_PERIOD + 5), None);
+    }
+}
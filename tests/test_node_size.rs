@@ -0,0 +1,36 @@
+#[cfg(all(
+    test,
+    not(any(
+        feature = "time64",
+        feature = "mailbox",
+        feature = "reliable",
+        feature = "link-ack",
+        feature = "time-sync",
+        feature = "addressing",
+        feature = "sniffer",
+        feature = "bridge",
+        feature = "rpc",
+        feature = "pubsub",
+        feature = "parameters",
+        feature = "diagnostics",
+        feature = "carrier-sense",
+        feature = "adaptive-listen-period",
+        feature = "airtime-limit",
+        feature = "listen-period-jitter"
+    ))
+))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::Node;
+
+    /// Size of the `Node` of version 2.x on the 64-bit host.
+    /// Devices, which do not use optional subsystems, shall not
+    /// spend more memory on the `Node`, than they did before.
+    const BASELINE_NODE_SIZE: usize = 1008;
+
+    #[test]
+    fn test_node_without_optional_subsystems_fits_baseline_size() {
+        assert!(core::mem::size_of::<Node>() <= BASELINE_NODE_SIZE);
+    }
+}
@@ -33,18 +33,18 @@ mod tests {
             ether.register_driver(modem_3.clone());
         }
 
-        let mut node_1 = Node::new(NodeConfig {
-            device_address: ExactAddressType::try_from(1).unwrap(),
-            listen_period: NODE_1_UPDATE_PERIOD,
-        });
-        let mut node_2 = Node::new(NodeConfig {
-            device_address: ExactAddressType::try_from(2).unwrap(),
-            listen_period: NODE_2_UPDATE_PERIOD,
-        });
-        let mut node_3 = Node::new(NodeConfig {
-            device_address: ExactAddressType::try_from(3).unwrap(),
-            listen_period: NODE_3_UPDATE_PERIOD,
-        });
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_UPDATE_PERIOD,
+        ));
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_UPDATE_PERIOD,
+        ));
+        let mut node_3 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(3).unwrap(),
+            NODE_3_UPDATE_PERIOD,
+        ));
 
         let _ = node_1.send_to_exact(
             NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
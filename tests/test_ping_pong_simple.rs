@@ -23,15 +23,15 @@ mod tests {
         ether.register_driver(modem_1.clone());
         ether.register_driver(modem_2.clone());
 
-        let mut node_1 = Node::new(NodeConfig {
-            device_address: ExactAddressType::try_from(1).unwrap(),
-            listen_period: NODE_1_UPDATE_PERIOD as ms,
-        });
-
-        let mut node_2 = Node::new(NodeConfig {
-            device_address: ExactAddressType::try_from(2).unwrap(),
-            listen_period: NODE_2_UPDATE_PERIOD as ms,
-        });
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_UPDATE_PERIOD as ms,
+        ));
+
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_UPDATE_PERIOD as ms,
+        ));
 
         let start_time = Instant::now();
 
@@ -0,0 +1,177 @@
+#[cfg(all(test, feature = "pubsub"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, ExactAddressType, LifeTimeType, Node, NodeConfig, Publication, TopicId, TopicPayload,
+    };
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+    use std::vec::Vec;
+
+    const NODE_1_UPDATE_PERIOD: ms = 10;
+    const NODE_2_UPDATE_PERIOD: ms = 20;
+    const NODE_3_UPDATE_PERIOD: ms = 30;
+
+    const TEMPERATURE: TopicId = 1;
+    const HUMIDITY: TopicId = 2;
+
+    const NODE_3_REQUEST_TIME: ms = 200;
+
+    const SIMULATION_TIMEOUT: ms = 600;
+
+    /// Devices 1 and 3 hear each other only trough device 2.
+    fn make_chain() -> (NetworkSimulator, [WirelessModemFake; 3], [Node; 3]) {
+        let network_simulator = NetworkSimulator::new(0);
+
+        let modems = [
+            WirelessModemFake::new("1"),
+            WirelessModemFake::new("2"),
+            WirelessModemFake::new("3"),
+        ];
+
+        {
+            network_simulator.create_ether("1");
+            let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+            ether.register_driver(modems[0].clone());
+            ether.register_driver(modems[1].clone());
+        }
+        {
+            network_simulator.create_ether("2");
+            let mut ether = network_simulator.get_ether("2").expect("Can not get ether");
+            ether.register_driver(modems[1].clone());
+            ether.register_driver(modems[2].clone());
+        }
+
+        let nodes = [
+            Node::new(NodeConfig::new(
+                ExactAddressType::try_from(1).unwrap(),
+                NODE_1_UPDATE_PERIOD,
+            )),
+            Node::new(NodeConfig::new(
+                ExactAddressType::try_from(2).unwrap(),
+                NODE_2_UPDATE_PERIOD,
+            )),
+            Node::new(NodeConfig::new(
+                ExactAddressType::try_from(3).unwrap(),
+                NODE_3_UPDATE_PERIOD,
+            )),
+        ];
+
+        (network_simulator, modems, nodes)
+    }
+
+    fn update_all(modems: &mut [WirelessModemFake; 3], nodes: &mut [Node; 3], current_time: ms) {
+        for (node, modem) in nodes.iter_mut().zip(modems.iter_mut()) {
+            let _ = node.update(modem, current_time);
+        }
+    }
+
+    fn payload(value: &[u8]) -> TopicPayload {
+        TopicPayload::from_slice(value).unwrap()
+    }
+
+    #[test]
+    fn test_publication_is_delivered_to_subscribers_only() {
+        let (mut network_simulator, mut modems, mut nodes) = make_chain();
+
+        nodes[2]
+            .subscribe(TEMPERATURE)
+            .expect("Subscription shall be made");
+
+        let _ = nodes[0].publish(
+            TEMPERATURE,
+            payload(b"21.5"),
+            LifeTimeType::try_from(2).unwrap(),
+        );
+        let _ = nodes[0].publish(HUMIDITY, payload(b"40"), LifeTimeType::try_from(2).unwrap());
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut publications: Vec<Publication> = Vec::new();
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            update_all(&mut modems, &mut nodes, current_time);
+
+            // Forwarding device is not woken up by publications.
+            assert!(nodes[1].receive_publication().is_none());
+            assert!(nodes[1].receive().is_none());
+            assert!(nodes[2].receive().is_none());
+
+            while let Some(publication) = nodes[2].receive_publication() {
+                publications.push(publication);
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        assert_eq!(publications.len(), 1);
+        assert_eq!(publications[0].topic, TEMPERATURE);
+        assert_eq!(publications[0].source.get(), 1);
+        assert_eq!(publications[0].payload, b"21.5");
+        assert!(!publications[0].is_retained);
+    }
+
+    #[test]
+    fn test_retained_value_is_sent_by_broker() {
+        let (mut network_simulator, mut modems, mut nodes) = make_chain();
+
+        nodes[1]
+            .retain_topic(TEMPERATURE)
+            .expect("Topic shall be retained");
+
+        let _ = nodes[0].publish(
+            TEMPERATURE,
+            payload(b"21.5"),
+            LifeTimeType::try_from(2).unwrap(),
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut is_requested = false;
+        let mut publication: Option<Publication> = None;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            update_all(&mut modems, &mut nodes, current_time);
+
+            // Broker keeps the value without being subscribed.
+            assert!(nodes[1].receive_publication().is_none());
+
+            // Device 3 joins after the value was published.
+            if !is_requested && current_time >= NODE_3_REQUEST_TIME {
+                let _ = nodes[2].subscribe(TEMPERATURE);
+                let _ = nodes[2].request_retained(TEMPERATURE, LifeTimeType::try_from(1).unwrap());
+                is_requested = true;
+            }
+
+            if let Some(received) = nodes[2].receive_publication() {
+                publication = Some(received);
+                break;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        let publication = publication.expect("Retained value shall be received");
+        assert_eq!(publication.topic, TEMPERATURE);
+        assert_eq!(publication.source.get(), 2);
+        assert_eq!(publication.payload, b"21.5");
+        assert!(publication.is_retained);
+    }
+}
@@ -0,0 +1,142 @@
+#[cfg(all(test, feature = "reliable"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, DeliveryStatus, ExactAddressType, LifeTimeType, Node, NodeConfig, NodeString,
+    };
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+
+    const ACK_TIMEOUT: ms = 150;
+
+    const SIMULATION_TIMEOUT: ms = 1500;
+
+    #[test]
+    fn test_reliable_send_delivered() {
+        let mut network_simulator = NetworkSimulator::new(1);
+        network_simulator.create_ether("1");
+        let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+
+        ether.register_driver(modem_1.clone());
+        ether.register_driver(modem_2.clone());
+
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+
+        let delivery_id = node_1
+            .send_reliable(
+                NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+                ExactAddressType::try_from(2).unwrap(),
+                LifeTimeType::try_from(1).unwrap(),
+                ACK_TIMEOUT,
+                3,
+            )
+            .expect("Packet shall be sent");
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut received_count = 0;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+
+            if let Some(message) = node_2.receive() {
+                let expected = NodeString::from_iter("This is the message from node 1".chars());
+                let got = NodeString::from_iter(message.data.iter().map(|c| *c as char));
+
+                assert!(got.starts_with(expected.as_str()));
+                received_count += 1;
+            }
+
+            // Acknowledgement shall not get into the queue of received packets.
+            assert!(node_1.receive().is_none());
+
+            if node_1.delivery_status(delivery_id) != Some(DeliveryStatus::Pending) {
+                break;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        assert_eq!(
+            node_1.delivery_status(delivery_id),
+            Some(DeliveryStatus::Delivered)
+        );
+        assert_eq!(received_count, 1);
+    }
+
+    #[test]
+    fn test_reliable_send_failed() {
+        let mut network_simulator = NetworkSimulator::new(1);
+        network_simulator.create_ether("1");
+        let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+
+        let mut modem_1 = WirelessModemFake::new("1");
+
+        ether.register_driver(modem_1.clone());
+
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+
+        // Retransmissions wait for 50, 100 and 200 ms.
+        let delivery_id = node_1
+            .send_reliable(
+                NodeString::from_iter("Nobody hears this message".chars()).into_bytes(),
+                ExactAddressType::try_from(2).unwrap(),
+                LifeTimeType::try_from(1).unwrap(),
+                50 as ms,
+                2,
+            )
+            .expect("Packet shall be sent");
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut failed_at: Option<ms> = None;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+
+            if node_1.delivery_status(delivery_id) == Some(DeliveryStatus::Failed) {
+                failed_at = Some(current_time);
+                break;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        let failed_at = failed_at.expect("Delivery shall fail");
+        assert!(failed_at >= 350 as ms);
+    }
+}
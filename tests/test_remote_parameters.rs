@@ -0,0 +1,309 @@
+#[cfg(all(test, feature = "parameters"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, ExactAddressType, IdType, LifeTimeType, Node, NodeConfig, ParameterAccess,
+        ParameterCallStatus, ParameterId, ParameterManagers, ParameterValue, QueuePolicy,
+        RemoteParametersConfig, DEFAULT_LIFETIME_PARAMETER, LISTEN_PERIOD_PARAMETER,
+        TRANSIT_QUEUE_POLICY_PARAMETER,
+    };
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Instant;
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+
+    const CALL_TIMEOUT: ms = 300;
+
+    const SIMULATION_TIMEOUT: ms = 1500;
+
+    const THRESHOLD: ParameterId = 1;
+    const FIRMWARE_VERSION: ParameterId = 2;
+
+    /// Last value of the threshold, kept in "persistent memory" of device 2.
+    static STORED_THRESHOLD: AtomicU32 = AtomicU32::new(0);
+
+    fn store(parameter: ParameterId, value: ParameterValue) {
+        if let (THRESHOLD, ParameterValue::U32(threshold)) = (parameter, value) {
+            STORED_THRESHOLD.store(threshold, Ordering::SeqCst);
+        }
+    }
+
+    /// Device 2 is managed by device 1 only.
+    fn make_managed_node() -> Node {
+        let mut managers = ParameterManagers::new();
+        let _ = managers.push(ExactAddressType::try_from(1).unwrap());
+
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(2).unwrap(), NODE_2_LISTEN_PERIOD);
+        config.remote_parameters = Some(RemoteParametersConfig {
+            managers,
+            on_change: Some(store),
+        });
+        let mut node = Node::new(config);
+        node.register_parameter(
+            THRESHOLD,
+            ParameterValue::U32(100),
+            ParameterAccess::ReadWrite,
+        )
+        .expect("Parameter shall be registered");
+        node.register_parameter(
+            FIRMWARE_VERSION,
+            ParameterValue::U8(3),
+            ParameterAccess::ReadOnly,
+        )
+        .expect("Parameter shall be registered");
+        node
+    }
+
+    /// Makes the call by `send` on the device with `manager_address`,
+    /// and updates both devices till the call is finished.
+    fn call<F>(managed_node: &mut Node, manager_address: u8, send: F) -> Option<ParameterCallStatus>
+    where
+        F: FnOnce(&mut Node) -> IdType,
+    {
+        let mut network_simulator = NetworkSimulator::new(1);
+        network_simulator.create_ether("1");
+        let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+
+        ether.register_driver(modem_1.clone());
+        ether.register_driver(modem_2.clone());
+
+        let mut manager_node = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(manager_address).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+
+        let request_id = send(&mut manager_node);
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = manager_node.update(&mut modem_1, current_time);
+            let _ = managed_node.update(&mut modem_2, current_time);
+
+            // Requests and responses shall not get into the queue of received packets.
+            assert!(manager_node.receive().is_none());
+            assert!(managed_node.receive().is_none());
+
+            if manager_node.parameter_call_status(request_id) != Some(ParameterCallStatus::Pending)
+            {
+                break;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        manager_node.parameter_call_status(request_id)
+    }
+
+    fn device_2() -> ExactAddressType {
+        ExactAddressType::try_from(2).unwrap()
+    }
+
+    fn one_hop() -> LifeTimeType {
+        LifeTimeType::try_from(1).unwrap()
+    }
+
+    #[test]
+    fn test_listen_period_is_written_remotely() {
+        let mut managed_node = make_managed_node();
+
+        let status = call(&mut managed_node, 1, |manager| {
+            manager
+                .set_remote_parameter(
+                    LISTEN_PERIOD_PARAMETER,
+                    ParameterValue::Time(50),
+                    device_2(),
+                    one_hop(),
+                    CALL_TIMEOUT,
+                )
+                .expect("Request shall be sent")
+        });
+
+        assert_eq!(
+            status,
+            Some(ParameterCallStatus::Done(ParameterValue::Time(50)))
+        );
+        assert_eq!(
+            managed_node.parameter(LISTEN_PERIOD_PARAMETER),
+            Some(ParameterValue::Time(50))
+        );
+    }
+
+    #[test]
+    fn test_default_lifetime_is_written_remotely() {
+        let mut managed_node = make_managed_node();
+
+        let status = call(&mut managed_node, 1, |manager| {
+            manager
+                .set_remote_parameter(
+                    DEFAULT_LIFETIME_PARAMETER,
+                    ParameterValue::U8(3),
+                    device_2(),
+                    one_hop(),
+                    CALL_TIMEOUT,
+                )
+                .expect("Request shall be sent")
+        });
+
+        assert_eq!(
+            status,
+            Some(ParameterCallStatus::Done(ParameterValue::U8(3)))
+        );
+        assert_eq!(managed_node.default_lifetime(), 3);
+    }
+
+    #[test]
+    fn test_transit_queue_policy_is_written_remotely() {
+        let mut managed_node = make_managed_node();
+
+        let status = call(&mut managed_node, 1, |manager| {
+            manager
+                .set_remote_parameter(
+                    TRANSIT_QUEUE_POLICY_PARAMETER,
+                    ParameterValue::U8(QueuePolicy::DropOldest.code()),
+                    device_2(),
+                    one_hop(),
+                    CALL_TIMEOUT,
+                )
+                .expect("Request shall be sent")
+        });
+        assert_eq!(
+            status,
+            Some(ParameterCallStatus::Done(ParameterValue::U8(
+                QueuePolicy::DropOldest.code()
+            )))
+        );
+
+        let status = call(&mut managed_node, 1, |manager| {
+            manager
+                .set_remote_parameter(
+                    TRANSIT_QUEUE_POLICY_PARAMETER,
+                    ParameterValue::U8(42),
+                    device_2(),
+                    one_hop(),
+                    CALL_TIMEOUT,
+                )
+                .expect("Request shall be sent")
+        });
+        assert_eq!(status, Some(ParameterCallStatus::InvalidValue));
+        assert_eq!(
+            managed_node.parameter(TRANSIT_QUEUE_POLICY_PARAMETER),
+            Some(ParameterValue::U8(QueuePolicy::DropOldest.code()))
+        );
+    }
+
+    #[test]
+    fn test_application_parameter_is_written_and_stored() {
+        let mut managed_node = make_managed_node();
+
+        let status = call(&mut managed_node, 1, |manager| {
+            manager
+                .set_remote_parameter(
+                    THRESHOLD,
+                    ParameterValue::U32(250),
+                    device_2(),
+                    one_hop(),
+                    CALL_TIMEOUT,
+                )
+                .expect("Request shall be sent")
+        });
+
+        assert_eq!(
+            status,
+            Some(ParameterCallStatus::Done(ParameterValue::U32(250)))
+        );
+        assert_eq!(
+            managed_node.parameter(THRESHOLD),
+            Some(ParameterValue::U32(250))
+        );
+        assert_eq!(STORED_THRESHOLD.load(Ordering::SeqCst), 250);
+    }
+
+    #[test]
+    fn test_read_only_parameter_is_read_but_not_written() {
+        let mut managed_node = make_managed_node();
+
+        let status = call(&mut managed_node, 1, |manager| {
+            manager
+                .get_remote_parameter(FIRMWARE_VERSION, device_2(), one_hop(), CALL_TIMEOUT)
+                .expect("Request shall be sent")
+        });
+        assert_eq!(
+            status,
+            Some(ParameterCallStatus::Done(ParameterValue::U8(3)))
+        );
+
+        let status = call(&mut managed_node, 1, |manager| {
+            manager
+                .set_remote_parameter(
+                    FIRMWARE_VERSION,
+                    ParameterValue::U8(4),
+                    device_2(),
+                    one_hop(),
+                    CALL_TIMEOUT,
+                )
+                .expect("Request shall be sent")
+        });
+        assert_eq!(status, Some(ParameterCallStatus::ReadOnly));
+        assert_eq!(
+            managed_node.parameter(FIRMWARE_VERSION),
+            Some(ParameterValue::U8(3))
+        );
+    }
+
+    #[test]
+    fn test_not_manager_device_is_denied() {
+        let mut managed_node = make_managed_node();
+
+        let status = call(&mut managed_node, 3, |manager| {
+            manager
+                .set_remote_parameter(
+                    LISTEN_PERIOD_PARAMETER,
+                    ParameterValue::Time(50),
+                    device_2(),
+                    one_hop(),
+                    CALL_TIMEOUT,
+                )
+                .expect("Request shall be sent")
+        });
+
+        assert_eq!(status, Some(ParameterCallStatus::Denied));
+        assert_eq!(
+            managed_node.parameter(LISTEN_PERIOD_PARAMETER),
+            ParameterValue::time_of(NODE_2_LISTEN_PERIOD)
+        );
+    }
+
+    #[test]
+    fn test_parameter_call_is_not_told_as_rpc_call() {
+        let mut manager_node = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+
+        let request_id = manager_node
+            .get_remote_parameter(THRESHOLD, device_2(), one_hop(), CALL_TIMEOUT)
+            .expect("Call shall be made");
+
+        assert_eq!(
+            manager_node.parameter_call_status(request_id),
+            Some(ParameterCallStatus::Pending)
+        );
+        assert!(manager_node.rpc_call_status(request_id).is_none());
+    }
+}
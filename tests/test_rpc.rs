@@ -0,0 +1,113 @@
+#[cfg(all(test, feature = "rpc"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, ExactAddressType, IdType, LifeTimeType, Node, NodeConfig, RpcArguments, RpcCallStatus,
+        RpcMethodId, RpcPayload,
+    };
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+
+    const RPC_TIMEOUT: ms = 300;
+
+    const SIMULATION_TIMEOUT: ms = 1500;
+
+    const SUM: RpcMethodId = 1;
+    const MISSING: RpcMethodId = 2;
+
+    fn sum(arguments: &[u8]) -> Option<RpcPayload> {
+        let sum = arguments
+            .iter()
+            .fold(0u16, |sum, argument| sum + *argument as u16);
+        RpcPayload::from_slice(&sum.to_be_bytes()).ok()
+    }
+
+    /// Calls the `method` of the node with `server_address`, and
+    /// updates both nodes till the call is finished.
+    fn call(method: RpcMethodId, arguments: &[u8], server_address: u8) -> Option<RpcCallStatus> {
+        let mut network_simulator = NetworkSimulator::new(1);
+        network_simulator.create_ether("1");
+        let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+
+        ether.register_driver(modem_1.clone());
+        ether.register_driver(modem_2.clone());
+
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+
+        node_2
+            .register_rpc_handler(SUM, sum)
+            .expect("Handler shall be registered");
+
+        let request_id: IdType = node_1
+            .send_rpc_request(
+                method,
+                RpcArguments::from_slice(arguments).unwrap(),
+                ExactAddressType::try_from(server_address).unwrap(),
+                LifeTimeType::try_from(1).unwrap(),
+                RPC_TIMEOUT,
+            )
+            .expect("Request shall be sent");
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+
+            // Requests and responses shall not get into the queue of received packets.
+            assert!(node_1.receive().is_none());
+            assert!(node_2.receive().is_none());
+
+            if node_1.rpc_call_status(request_id) != Some(RpcCallStatus::Pending) {
+                break;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        node_1.rpc_call_status(request_id)
+    }
+
+    #[test]
+    fn test_rpc_call_answered() {
+        assert_eq!(
+            call(SUM, &[200, 100, 3], 2),
+            Some(RpcCallStatus::Answered(
+                RpcPayload::from_slice(&303u16.to_be_bytes()).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_rpc_call_of_unknown_method() {
+        assert_eq!(call(MISSING, &[], 2), Some(RpcCallStatus::UnknownMethod));
+    }
+
+    #[test]
+    fn test_rpc_call_timed_out() {
+        // No device has address 3.
+        assert_eq!(call(SUM, &[1, 2], 3), Some(RpcCallStatus::TimedOut));
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(all(test, feature = "sim"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::Scenario;
+    use std::path::Path;
+
+    /// Runs the scenario from `tests/scenarios` directory.
+    fn run(name: &str) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/scenarios")
+            .join(name);
+        let scenario = Scenario::from_file(&path).expect("Can not load scenario");
+        if let Err(error) = scenario.run() {
+            panic!("Scenario {} failed: {:?}", name, error);
+        }
+    }
+
+    #[test]
+    fn test_1_to_1_with_255_other_devices() {
+        run("1_to_1_with_255_other_devices.toml");
+    }
+
+    #[test]
+    fn test_packet_travel_2_hops() {
+        run("packet_travel_2_hops.toml");
+    }
+
+    #[test]
+    fn test_packet_travel_2_hops_lifetime_ended() {
+        run("packet_travel_2_hops_lifetime_ended.toml");
+    }
+
+    #[test]
+    fn test_broadcast_over_lossy_one_way_links() {
+        run("broadcast_over_lossy_one_way_links.toml");
+    }
+
+    #[test]
+    fn test_not_delivered_message_fails_scenario() {
+        let scenario = Scenario::from_toml(
+            r#"
+            timeout = 500
+
+            [[nodes]]
+            addresses = [1, 2]
+            listen_period = 10
+
+            [[sends]]
+            from = 1
+            to = 2
+            message = "Nobody hears node 1"
+            lifetime = 1
+
+            [[expected]]
+            node = 2
+            from = 1
+            message = "Nobody hears node 1"
+            "#,
+        )
+        .expect("Can not parse scenario");
+        assert!(scenario.run().is_err());
+    }
+}
@@ -0,0 +1,213 @@
+#[cfg(all(test, feature = "sim"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, ExactAddressType, LinkConfig, NodeConfig, NodeString, Packet, Simulator,
+    };
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+    const NODE_3_LISTEN_PERIOD: ms = 30;
+
+    const SIMULATION_TIMEOUT: ms = 1000;
+
+    /// Makes the line of nodes 1 <-> 2 <-> 3, where node 1 sends
+    /// the message to node 3. Returns the time of receiving.
+    fn run_2_hops(seed: u32, link: LinkConfig) -> Option<(ms, Packet)> {
+        let mut simulator = Simulator::new(seed);
+
+        let node_1 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let node_2 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+        let node_3 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(3).unwrap(),
+            NODE_3_LISTEN_PERIOD,
+        ));
+
+        simulator.link(node_1, node_2, link);
+        simulator.link(node_2, node_3, link);
+
+        let _ = simulator.node(node_1).send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(3).unwrap(),
+            2,
+            true,
+        );
+
+        let mut received = None;
+        simulator.run_until(SIMULATION_TIMEOUT, |simulator| {
+            received = simulator.node(node_3).receive();
+            received.is_some()
+        });
+        received.map(|packet| (simulator.current_time(), packet))
+    }
+
+    #[test]
+    fn test_packet_travels_2_hops() {
+        let (_, packet) =
+            run_2_hops(1, LinkConfig::default()).expect("Node 3 shall receive the message");
+        let got = NodeString::from_iter(packet.data.iter().map(|c| *c as char));
+        assert!(got.starts_with("This is the message from node 1"));
+    }
+
+    #[test]
+    fn test_same_seed_runs_same_way() {
+        let link = LinkConfig {
+            loss: 30,
+            corruption: 10,
+            latency: 3,
+            byte_airtime: 1,
+        };
+        let received_at: std::vec::Vec<_> = (0..10)
+            .map(|seed| run_2_hops(seed, link).map(|(time, _)| time))
+            .collect();
+        for (seed, expected) in received_at.iter().enumerate() {
+            assert_eq!(
+                run_2_hops(seed as u32, link).map(|(time, _)| time),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_packet_is_not_delivered_over_lost_link() {
+        let link = LinkConfig {
+            loss: 100,
+            ..Default::default()
+        };
+        assert!(run_2_hops(1, link).is_none());
+    }
+
+    #[test]
+    fn test_corrupted_packet_is_not_delivered() {
+        let link = LinkConfig {
+            corruption: 100,
+            ..Default::default()
+        };
+        assert!(run_2_hops(1, link).is_none());
+    }
+
+    #[test]
+    fn test_latency_delays_delivery() {
+        let (immediate, _) =
+            run_2_hops(1, LinkConfig::default()).expect("Node 3 shall receive the message");
+        let link = LinkConfig {
+            latency: 100,
+            ..Default::default()
+        };
+        let (delayed, _) = run_2_hops(1, link).expect("Node 3 shall receive the message");
+        // Message travels 2 hops, each of them is delayed.
+        assert!(immediate < 2 * link.latency);
+        assert!(delayed >= 2 * link.latency);
+    }
+
+    #[test]
+    fn test_one_way_link_is_heard_one_way() {
+        let mut simulator = Simulator::new(1);
+
+        let node_1 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let node_2 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+
+        simulator.link_one_way(node_1, node_2, LinkConfig::default());
+
+        let _ = simulator.node(node_1).send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(2).unwrap(),
+            1,
+            true,
+        );
+        let _ = simulator.node(node_2).send_to_exact(
+            NodeString::from_iter("This is the message from node 2".chars()).into_bytes(),
+            ExactAddressType::try_from(1).unwrap(),
+            1,
+            true,
+        );
+
+        simulator.run_for(SIMULATION_TIMEOUT);
+
+        assert!(simulator.node(node_2).receive().is_some());
+        assert!(simulator.node(node_1).receive().is_none());
+    }
+
+    /// Makes nodes 1 and 2, which do not hear each other, send
+    /// the message to node 3 at the same time.
+    /// Returns the count of messages, received by node 3.
+    fn run_hidden_nodes(link: LinkConfig, send_interval: ms) -> usize {
+        let mut simulator = Simulator::new(1);
+
+        let node_1 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let node_2 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let node_3 = simulator.add_node(NodeConfig::new(
+            ExactAddressType::try_from(3).unwrap(),
+            NODE_3_LISTEN_PERIOD,
+        ));
+
+        simulator.link_one_way(node_1, node_3, link);
+        simulator.link_one_way(node_2, node_3, link);
+
+        let _ = simulator.node(node_1).send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(3).unwrap(),
+            1,
+            true,
+        );
+        simulator.run_for(send_interval);
+        let _ = simulator.node(node_2).send_to_exact(
+            NodeString::from_iter("This is the message from node 2".chars()).into_bytes(),
+            ExactAddressType::try_from(3).unwrap(),
+            1,
+            true,
+        );
+
+        simulator.run_for(SIMULATION_TIMEOUT);
+
+        let mut received_count = 0;
+        while simulator.node(node_3).receive().is_some() {
+            received_count += 1;
+        }
+        received_count
+    }
+
+    #[test]
+    fn test_transmissions_at_the_same_time_collide() {
+        assert_eq!(run_hidden_nodes(LinkConfig::default(), 0), 0);
+    }
+
+    #[test]
+    fn test_overlapping_transmissions_collide() {
+        let link = LinkConfig {
+            byte_airtime: 1,
+            ..Default::default()
+        };
+        // Second transmission starts, while the first one is still in the air.
+        assert_eq!(run_hidden_nodes(link, 5), 0);
+    }
+
+    #[test]
+    fn test_transmissions_one_after_another_do_not_collide() {
+        let link = LinkConfig {
+            byte_airtime: 1,
+            ..Default::default()
+        };
+        // Both listen periods pass, so node 2 speaks after node 1 finished.
+        assert_eq!(run_hidden_nodes(link, 200), 2);
+    }
+}
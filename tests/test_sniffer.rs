@@ -0,0 +1,265 @@
+#[cfg(all(test, feature = "sniffer", feature = "reliable"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{
+        ms, DeliveryStatus, ExactAddressType, GeneralAddressType, Node, NodeConfig, NodeString,
+        SnifferConfig,
+    };
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+    use std::vec::Vec;
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+    const NODE_3_LISTEN_PERIOD: ms = 30;
+    const SNIFFER_LISTEN_PERIOD: ms = 15;
+
+    const SIMULATION_TIMEOUT: ms = 300;
+
+    #[test]
+    fn test_sniffer_hears_transit_and_duplicates() {
+        let mut network_simulator = NetworkSimulator::new(0);
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+        let mut modem_3 = WirelessModemFake::new("3");
+        let mut modem_sniffer = WirelessModemFake::new("sniffer");
+
+        {
+            network_simulator.create_ether("1");
+            let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(modem_2.clone());
+            ether.register_driver(modem_sniffer.clone());
+        }
+        {
+            network_simulator.create_ether("2");
+            let mut ether = network_simulator.get_ether("2").expect("Can not get ether");
+            ether.register_driver(modem_2.clone());
+            ether.register_driver(modem_3.clone());
+        }
+
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let mut node_2 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            NODE_2_LISTEN_PERIOD,
+        ));
+        let mut node_3 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(3).unwrap(),
+            NODE_3_LISTEN_PERIOD,
+        ));
+        let mut config = NodeConfig::new(
+            ExactAddressType::try_from(4).unwrap(),
+            SNIFFER_LISTEN_PERIOD,
+        );
+        config.sniffer = Some(SnifferConfig {
+            forward_transit: false,
+        });
+        let mut sniffer = Node::new(config);
+
+        let _ = node_1.send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(3).unwrap(),
+            2,
+            true,
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut sniffed_packets = Vec::new();
+        let mut is_node_3_received = false;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time);
+            let _ = node_3.update(&mut modem_3, current_time);
+            let _ = sniffer.update(&mut modem_sniffer, current_time);
+
+            while let Some(sniffed) = sniffer.sniff() {
+                sniffed_packets.push(sniffed);
+            }
+
+            if node_3.receive().is_some() {
+                is_node_3_received = true;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        assert!(is_node_3_received);
+
+        // Packet sent by node 1, and the same packet forwarded by node 2.
+        assert_eq!(sniffed_packets.len(), 2);
+        for sniffed in sniffed_packets.iter() {
+            assert_eq!(sniffed.packet.source_device_identifier, 1);
+            assert!(sniffed
+                .packet
+                .is_destination_reached(GeneralAddressType::Exact(
+                    ExactAddressType::try_from(3).unwrap()
+                )));
+        }
+        assert!(!sniffed_packets[0].is_duplicate);
+        assert_eq!(sniffed_packets[0].packet.get_lifetime(), 2);
+        assert!(sniffed_packets[1].is_duplicate);
+        assert_eq!(sniffed_packets[1].packet.get_lifetime(), 1);
+        assert!(sniffed_packets[0].heard_at <= sniffed_packets[1].heard_at);
+
+        assert!(sniffer.receive().is_none());
+    }
+
+    #[test]
+    fn test_sniffer_does_not_forward_transit() {
+        let mut network_simulator = NetworkSimulator::new(0);
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_sniffer = WirelessModemFake::new("sniffer");
+        let mut modem_3 = WirelessModemFake::new("3");
+
+        {
+            network_simulator.create_ether("1");
+            let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(modem_sniffer.clone());
+        }
+        {
+            network_simulator.create_ether("2");
+            let mut ether = network_simulator.get_ether("2").expect("Can not get ether");
+            ether.register_driver(modem_sniffer.clone());
+            ether.register_driver(modem_3.clone());
+        }
+
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let mut node_3 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(3).unwrap(),
+            NODE_3_LISTEN_PERIOD,
+        ));
+        let mut config = NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            SNIFFER_LISTEN_PERIOD,
+        );
+        config.sniffer = Some(SnifferConfig {
+            forward_transit: false,
+        });
+        let mut sniffer = Node::new(config);
+
+        let _ = node_1.send_to_exact(
+            NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+            ExactAddressType::try_from(3).unwrap(),
+            2,
+            true,
+        );
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        let mut sniffed_count = 0;
+        let mut is_node_3_received = false;
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = sniffer.update(&mut modem_sniffer, current_time);
+            let _ = node_3.update(&mut modem_3, current_time);
+
+            while sniffer.sniff().is_some() {
+                sniffed_count += 1;
+            }
+
+            if node_3.receive().is_some() {
+                is_node_3_received = true;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        assert_eq!(sniffed_count, 1);
+        assert!(!is_node_3_received);
+    }
+
+    #[test]
+    fn test_sniffer_answers_packets_addressed_to_it() {
+        let mut network_simulator = NetworkSimulator::new(0);
+        network_simulator.create_ether("1");
+        let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_sniffer = WirelessModemFake::new("sniffer");
+
+        ether.register_driver(modem_1.clone());
+        ether.register_driver(modem_sniffer.clone());
+
+        let mut node_1 = Node::new(NodeConfig::new(
+            ExactAddressType::try_from(1).unwrap(),
+            NODE_1_LISTEN_PERIOD,
+        ));
+        let mut config = NodeConfig::new(
+            ExactAddressType::try_from(2).unwrap(),
+            SNIFFER_LISTEN_PERIOD,
+        );
+        config.sniffer = Some(SnifferConfig {
+            forward_transit: false,
+        });
+        let mut sniffer = Node::new(config);
+
+        // Acknowledgement is made of the packet, as answers to pings and transactions are.
+        let delivery_id = node_1
+            .send_reliable(
+                NodeString::from_iter("This is the message from node 1".chars()).into_bytes(),
+                ExactAddressType::try_from(2).unwrap(),
+                2,
+                200 as ms,
+                0,
+            )
+            .expect("Packet shall be sent");
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = sniffer.update(&mut modem_sniffer, current_time);
+
+            while sniffer.sniff().is_some() {}
+
+            if node_1.delivery_status(delivery_id) != Some(DeliveryStatus::Pending) {
+                break;
+            }
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        assert_eq!(
+            node_1.delivery_status(delivery_id),
+            Some(DeliveryStatus::Delivered)
+        );
+        assert!(sniffer.receive().is_some());
+    }
+}
@@ -0,0 +1,122 @@
+#[cfg(all(test, feature = "time-sync"))]
+mod tests {
+    extern crate std;
+
+    use embedded_nano_mesh::{ms, ExactAddressType, Node, NodeConfig, TimeSyncConfig};
+    use proto_lab::{NetworkSimulator, WirelessModemFake};
+    use std::time::Instant;
+
+    const NODE_1_LISTEN_PERIOD: ms = 10;
+    const NODE_2_LISTEN_PERIOD: ms = 20;
+    const NODE_3_LISTEN_PERIOD: ms = 30;
+
+    /// Local clocks of devices are started at different moments.
+    const NODE_2_CLOCK_OFFSET: ms = 5_000;
+    const NODE_3_CLOCK_OFFSET: ms = 70_000;
+
+    /// The ether of simulator transfers one byte per millisecond.
+    const TRANSMISSION_DELAY: ms = 3 + 40;
+
+    /// Simulator thread does not keep exactly one byte per millisecond.
+    const ALLOWED_ERROR: ms = 30;
+
+    const SIMULATION_TIMEOUT: ms = 500;
+
+    fn time_sync_config(is_reference: bool) -> TimeSyncConfig {
+        TimeSyncConfig {
+            is_reference,
+            sync_period: 200 as ms,
+            lifetime: 2,
+            transmission_delay: TRANSMISSION_DELAY,
+            estimate_drift: false,
+        }
+    }
+
+    /// Runs the line of devices 1 <-> 2 <-> 3, where device 1 is the reference one.
+    /// Returns the devices along with the time of the end of the simulation.
+    fn run_2_hops(node_2_time_sync: Option<TimeSyncConfig>) -> ([Node; 3], ms) {
+        let mut network_simulator = NetworkSimulator::new(1);
+
+        let mut modem_1 = WirelessModemFake::new("1");
+        let mut modem_2 = WirelessModemFake::new("2");
+        let mut modem_3 = WirelessModemFake::new("3");
+
+        {
+            network_simulator.create_ether("1");
+            let mut ether = network_simulator.get_ether("1").expect("Can not get ether");
+            ether.register_driver(modem_1.clone());
+            ether.register_driver(modem_2.clone());
+        }
+        {
+            network_simulator.create_ether("2");
+            let mut ether = network_simulator.get_ether("2").expect("Can not get ether");
+            ether.register_driver(modem_2.clone());
+            ether.register_driver(modem_3.clone());
+        }
+
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(1).unwrap(), NODE_1_LISTEN_PERIOD);
+        config.time_sync = Some(time_sync_config(true));
+        let mut node_1 = Node::new(config);
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(2).unwrap(), NODE_2_LISTEN_PERIOD);
+        config.time_sync = node_2_time_sync;
+        let mut node_2 = Node::new(config);
+        let mut config =
+            NodeConfig::new(ExactAddressType::try_from(3).unwrap(), NODE_3_LISTEN_PERIOD);
+        config.time_sync = Some(time_sync_config(false));
+        let mut node_3 = Node::new(config);
+
+        network_simulator.start_simulation_thread();
+
+        let start_time = Instant::now();
+
+        loop {
+            let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+            let _ = node_1.update(&mut modem_1, current_time);
+            let _ = node_2.update(&mut modem_2, current_time + NODE_2_CLOCK_OFFSET);
+            let _ = node_3.update(&mut modem_3, current_time + NODE_3_CLOCK_OFFSET);
+
+            if current_time >= SIMULATION_TIMEOUT {
+                break;
+            }
+        }
+
+        network_simulator.stop_simulation_thread();
+
+        let current_time = Instant::now().duration_since(start_time).as_millis() as ms;
+
+        ([node_1, node_2, node_3], current_time)
+    }
+
+    #[test]
+    pub fn test_time_sync_travel_2_hops() {
+        let ([node_1, node_2, node_3], current_time) = run_2_hops(Some(time_sync_config(false)));
+
+        assert_eq!(node_1.network_time(current_time), Some(current_time));
+
+        let node_2_time = node_2
+            .network_time(current_time + NODE_2_CLOCK_OFFSET)
+            .expect("Node 2 shall be synchronised");
+        assert!(node_2_time.abs_diff(current_time) <= ALLOWED_ERROR);
+
+        let node_3_time = node_3
+            .network_time(current_time + NODE_3_CLOCK_OFFSET)
+            .expect("Node 3 shall be synchronised");
+        assert!(node_3_time.abs_diff(current_time) <= ALLOWED_ERROR);
+    }
+
+    #[test]
+    pub fn test_time_sync_is_not_forwarded_by_not_synchronised_device() {
+        // Device 2 can not update the reference time, so it does not forward it.
+        let ([_, node_2, node_3], current_time) = run_2_hops(None);
+
+        assert!(node_2
+            .network_time(current_time + NODE_2_CLOCK_OFFSET)
+            .is_none());
+        assert!(node_3
+            .network_time(current_time + NODE_3_CLOCK_OFFSET)
+            .is_none());
+    }
+}